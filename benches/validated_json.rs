@@ -0,0 +1,174 @@
+#[macro_use]
+extern crate rocket;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+    serde::{json::Json, Deserialize},
+};
+use rocket_validation::{Validate, Validated};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+///  System allocator counting the allocations made through it
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Item {
+    #[validate(length(min = 1, max = 32))]
+    name: String,
+    #[validate(range(min = 1, max = 1000))]
+    quantity: u32,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Order {
+    #[validate(email)]
+    email: String,
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[post("/plain/orders", data = "<data>")]
+fn create_plain(data: Json<Order>) -> Status {
+    let _ = data.into_inner();
+    Status::NoContent
+}
+
+#[post("/orders", data = "<data>")]
+fn create(data: Validated<Json<Order>>) -> Status {
+    let _ = data.into_deep_inner();
+    Status::NoContent
+}
+
+///  Valid order of about 1KB
+fn payload() -> String {
+    let items: Vec<String> = (0..24)
+        .map(|i| format!(r#"{{"name":"item number {i:02}","quantity":{}}}"#, i + 1))
+        .collect();
+
+    format!(
+        r#"{{"email":"alice@example.com","items":[{}]}}"#,
+        items.join(",")
+    )
+}
+
+///  Most allocations `Validated<Json<T>>` may add to a plain `Json<T>` per request
+const VALIDATED_BUDGET: usize = 64;
+
+///  Most allocations the body checks may add to `Validated<Json<T>>` per request, the body being parsed into a
+///  `Value` once for the fingerprint
+const CHECKED_BUDGET: usize = 256;
+
+///  Allocations made to dispatch `payload` to `uri` once, averaged over a few requests
+fn allocations_per_request(client: &Client, uri: &str, payload: &str) -> usize {
+    const REQUESTS: usize = 100;
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..REQUESTS {
+        let response = client
+            .post(uri)
+            .header(ContentType::JSON)
+            .body(payload)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::NoContent);
+    }
+
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) / REQUESTS
+}
+
+fn validated_json(c: &mut Criterion) {
+    let client =
+        Client::untracked(rocket::build().mount("/", routes![create_plain, create])).unwrap();
+    let payload = payload();
+
+    let plain = allocations_per_request(&client, "/plain/orders", &payload);
+    let validated = allocations_per_request(&client, "/orders", &payload);
+
+    let checked = Client::untracked(
+        rocket::build()
+            .mount("/", routes![create])
+            .manage(rocket_validation::MaxBodySize(64 * 1024))
+            .manage(rocket_validation::SchemaFingerprints),
+    )
+    .unwrap();
+    let checked = allocations_per_request(&checked, "/orders", &payload);
+
+    println!(
+        "json 1KB allocations per request: {} plain, {} validated (+{}), {} with body checks (+{})",
+        plain,
+        validated,
+        validated.saturating_sub(plain),
+        checked,
+        checked.saturating_sub(validated)
+    );
+
+    assert!(
+        validated <= plain + VALIDATED_BUDGET,
+        "Validated<Json<T>> made {} allocations per request, over the {} of Json<T> plus {}",
+        validated,
+        plain,
+        VALIDATED_BUDGET
+    );
+    assert!(
+        checked <= validated + CHECKED_BUDGET,
+        "the body checks made {} allocations per request, over the {} of Validated<Json<T>> plus {}",
+        checked,
+        validated,
+        CHECKED_BUDGET
+    );
+
+    c.bench_function("plain json 1KB", |b| {
+        b.iter(|| {
+            let response = client
+                .post("/plain/orders")
+                .header(ContentType::JSON)
+                .body(&payload)
+                .dispatch();
+
+            assert_eq!(response.status(), Status::NoContent);
+        })
+    });
+
+    c.bench_function("validated json 1KB", |b| {
+        b.iter(|| {
+            let response = client
+                .post("/orders")
+                .header(ContentType::JSON)
+                .body(&payload)
+                .dispatch();
+
+            assert_eq!(response.status(), Status::NoContent);
+        })
+    });
+}
+
+criterion_group!(benches, validated_json);
+criterion_main!(benches);
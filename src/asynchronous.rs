@@ -0,0 +1,97 @@
+//! Validation which needs to await IO, e.g. a uniqueness check against a database
+
+use crate::{
+    body::{parse_body, read_body},
+    validation_outcome, ValidationErrors, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+
+///  Asynchronous counterpart of `Validate`
+///  ```rust
+///  use rocket_validation::{ValidateAsync, ValidationErrors};
+///  use validator::ValidationError;
+///
+///  pub struct Redeem {
+///      coupon: String,
+///  }
+///
+///  #[rocket::async_trait]
+///  impl ValidateAsync for Redeem {
+///      async fn validate_async(&self) -> Result<(), ValidationErrors> {
+///          // ask the coupon service
+///          let valid = self.coupon.starts_with("SUMMER");
+///
+///          if valid {
+///              return Ok(());
+///          }
+///
+///          let mut errors = ValidationErrors::new();
+///          errors.add("coupon", ValidationError::new("unknown_coupon"));
+///          Err(errors)
+///      }
+///  }
+///  ```
+#[rocket::async_trait]
+pub trait ValidateAsync {
+    async fn validate_async(&self) -> Result<(), ValidationErrors>;
+}
+
+///  Struct used for Request Guards awaiting `ValidateAsync`
+///
+///  Failures are cached and rendered by the catchers the same way as the ones of `Validated`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  # use rocket_validation::{ValidateAsync, ValidationErrors};
+///  # #[derive(Debug, rocket::serde::Deserialize)]
+///  # #[serde(crate = "rocket::serde")]
+///  # pub struct Redeem {
+///  #     coupon: String,
+///  # }
+///  # #[rocket::async_trait]
+///  # impl ValidateAsync for Redeem {
+///  #     async fn validate_async(&self) -> Result<(), ValidationErrors> {
+///  #         Ok(())
+///  #     }
+///  # }
+///  use rocket::serde::json::Json;
+///  use rocket_validation::ValidatedAsync;
+///
+///  #[post("/redeem", data = "<data>")]
+///  fn redeem(data: ValidatedAsync<Json<Redeem>>) -> String {
+///      data.into_deep_inner().coupon
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedAsync<T>(pub T);
+
+impl_into_inner!(ValidatedAsync);
+
+///  Implementation of `ValidatedAsync` for `Json`
+#[rocket::async_trait]
+impl<'r, D: ValidateAsync + Deserialize<'r> + Send + Sync> FromData<'r>
+    for ValidatedAsync<Json<D>>
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let data = match parse_body::<D>(req, body) {
+            Ok(data) => data,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        validation_outcome::<D, _, _, _>(req, data.validate_async().await, ValidatedAsync(data))
+    }
+}
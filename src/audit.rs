@@ -0,0 +1,127 @@
+//! Audit log of the validations made during requests
+
+use crate::{
+    errors::{dotted, walk},
+    ValidationErrors,
+};
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    request::Request,
+    serde::Serialize,
+    Response,
+};
+use std::sync::{Mutex, PoisonError};
+
+///  Fields which failed validation during the request, `None` if no guard validated anything
+struct CachedOutcome(Mutex<Option<Vec<String>>>);
+
+///  Records the outcome of a guard, `errors` being `None` when it succeeded
+pub(crate) fn record(req: &Request<'_>, errors: Option<&ValidationErrors>) {
+    let mut outcome = req
+        .local_cache(|| CachedOutcome(Mutex::new(None)))
+        .0
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let failed_fields = outcome.get_or_insert_with(Vec::new);
+
+    if let Some(errors) = errors {
+        walk(errors, &mut |path, _| {
+            let field = dotted(path);
+
+            if !failed_fields.contains(&field) {
+                failed_fields.push(field);
+            }
+        });
+        failed_fields.sort();
+    }
+}
+
+///  Structured audit entry of a request which went through validation
+///
+///  Only names of failed fields are recorded, never their values
+#[derive(Clone, Debug, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AuditEntry {
+    ///  Caller as resolved by `AuditLog::actor`
+    pub actor: Option<String>,
+    pub method: String,
+    ///  Uri of the matched route, e.g. `/users/<id>`
+    pub route: Option<String>,
+    pub valid: bool,
+    pub failed_fields: Vec<String>,
+    pub status: u16,
+}
+
+///  Destination of the entries of `AuditLog`
+pub trait AuditSink: Send + Sync + 'static {
+    fn emit(&self, entry: AuditEntry);
+}
+
+impl<F: Fn(AuditEntry) + Send + Sync + 'static> AuditSink for F {
+    fn emit(&self, entry: AuditEntry) {
+        self(entry)
+    }
+}
+
+///  Fairing emitting an `AuditEntry` for every request in which a `Validated` guard ran, successfully or not
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::{AuditEntry, AuditLog};
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().attach(
+///          AuditLog::new(|entry: AuditEntry| println!("{:?}", entry))
+///              .actor(|req| req.headers().get_one("X-User-Id").map(str::to_owned)),
+///      )
+///  }
+///  ```
+pub struct AuditLog {
+    sink: Box<dyn AuditSink>,
+    actor: fn(&Request<'_>) -> Option<String>,
+}
+
+impl AuditLog {
+    pub fn new(sink: impl AuditSink) -> Self {
+        AuditLog {
+            sink: Box::new(sink),
+            actor: |_| None,
+        }
+    }
+
+    ///  Resolves the caller recorded as `AuditEntry::actor`
+    pub fn actor(mut self, actor: fn(&Request<'_>) -> Option<String>) -> Self {
+        self.actor = actor;
+        self
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for AuditLog {
+    fn info(&self) -> Info {
+        Info {
+            name: "Validation Audit Log",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let failed_fields = req
+            .local_cache(|| CachedOutcome(Mutex::new(None)))
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+
+        if let Some(failed_fields) = failed_fields {
+            self.sink.emit(AuditEntry {
+                actor: (self.actor)(req),
+                method: req.method().as_str().to_owned(),
+                route: req.route().map(|route| route.uri.to_string()),
+                valid: failed_fields.is_empty(),
+                failed_fields,
+                status: res.status().code,
+            });
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Helpers for guards which need the raw body before deserializing it
+
+#[cfg(feature = "ref_cycles")]
+use crate::cycles;
+#[cfg(feature = "echo_input")]
+use crate::echo;
+#[cfg(feature = "unicode")]
+use crate::unicode;
+use crate::{cache_errors, fingerprint, limits, validation_failure, ValidationGuardError};
+use rocket::{
+    data::{Data, ToByteUnit},
+    http::Status,
+    request::{local_cache, Request},
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+        Deserialize,
+    },
+};
+use std::io;
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+///  Code of the errors cached for bodies which couldn't be deserialized
+pub(crate) const PARSE_CODE: &str = "parse";
+
+///  Failure of a json guard, the status it fails with along its error
+pub(crate) type JsonFailure<'r> = (Status, ValidationGuardError<json::Error<'r>>);
+
+///  Reads the body within the `json` data limit, the same way `Json` does
+pub(crate) async fn read_json<'r>(
+    req: &'r Request<'_>,
+    data: Data<'r>,
+) -> Result<&'r str, json::Error<'r>> {
+    let limit = req.limits().get("json").unwrap_or_else(|| 1.mebibytes());
+
+    let string = match data.open(limit).into_string().await {
+        Ok(s) if s.is_complete() => s.into_inner(),
+        Ok(_) => {
+            let eof = io::ErrorKind::UnexpectedEof;
+            return Err(json::Error::Io(io::Error::new(eof, "data limit exceeded")));
+        }
+        Err(e) => return Err(json::Error::Io(e)),
+    };
+
+    let cached: &'r String = local_cache!(req, string);
+    Ok(cached.as_str())
+}
+
+///  Reads the body of a guard validating `D` like `read_json`, caching a `payload_too_large` error for bodies exceeding
+///  the `json` data limit
+///
+///  The body is returned as received, guards should rather use `read_body` unless they need its exact bytes
+pub(crate) async fn read_raw_body<'r, D: ?Sized>(
+    req: &'r Request<'_>,
+    data: Data<'r>,
+) -> Result<&'r str, JsonFailure<'r>> {
+    read_json(req, data).await.map_err(|err| {
+        let status = json_error_status(&err);
+        if status == Status::PayloadTooLarge {
+            cache_errors::<D>(req, &limits::payload_too_large(req));
+        }
+
+        (status, ValidationGuardError::Parse(err))
+    })
+}
+
+///  Reads the body of a guard validating `D` with `read_raw_body` and hands it to `check_body`
+pub(crate) async fn read_body<'r, D: ?Sized>(
+    req: &'r Request<'_>,
+    data: Data<'r>,
+) -> Result<&'r str, JsonFailure<'r>> {
+    let body = read_raw_body::<D>(req, data).await?;
+    check_body::<D>(req, body)
+}
+
+///  Records a body read by a guard validating `D` for the catchers and checks it against the limits managed by the
+///  application (`MaxStringLen` and, with `ref_cycles`, cyclic references), caching the errors of
+///  bodies exceeding them
+///
+///  Checked bodies are NFC normalized when `NormalizeNfc` is managed, the returned body is the one to deserialize
+pub(crate) fn check_body<'r, D: ?Sized>(
+    req: &'r Request<'_>,
+    body: &'r str,
+) -> Result<&'r str, JsonFailure<'r>> {
+    let mut value = LazyValue::new(body);
+
+    fingerprint::record(req, &mut value);
+    #[cfg(feature = "echo_input")]
+    echo::record(req, body);
+
+    let checked = limits::check_string_len(req, body);
+    #[cfg(feature = "ref_cycles")]
+    let checked = checked.and_then(|_| cycles::check_ref_cycles(&mut value));
+
+    if let Err(err) = checked {
+        return Err(validation_failure::<D, _>(req, err));
+    }
+
+    #[cfg(feature = "unicode")]
+    let body = match unicode::normalize(req, &mut value) {
+        Ok(body) => body,
+        Err(err) => return Err(validation_failure::<D, _>(req, err)),
+    };
+    limits::record_body_size(req, &mut value);
+
+    Ok(body)
+}
+
+///  Json value of a body, parsed on first use and shared by the checks which need it so the body is parsed once at most
+pub(crate) struct LazyValue<'r> {
+    body: &'r str,
+    value: Option<Option<Value>>,
+}
+
+impl<'r> LazyValue<'r> {
+    pub(crate) fn new(body: &'r str) -> Self {
+        LazyValue { body, value: None }
+    }
+
+    ///  Body the value is parsed from
+    pub(crate) fn body(&self) -> &'r str {
+        self.body
+    }
+
+    ///  Value of the body, `None` if it isn't valid json
+    pub(crate) fn get(&mut self) -> Option<&mut Value> {
+        let body = self.body;
+        self.value
+            .get_or_insert_with(|| json::from_str(body).ok())
+            .as_mut()
+    }
+}
+
+///  Reads an url-encoded form body within the `form` data limit, the same way `Form` does
+pub(crate) async fn read_form<'r>(req: &'r Request<'_>, data: Data<'r>) -> io::Result<&'r str> {
+    let limit = req.limits().get("form").unwrap_or_else(|| 32.kibibytes());
+
+    let string = match data.open(limit).into_string().await {
+        Ok(s) if s.is_complete() => s.into_inner(),
+        Ok(_) => {
+            let eof = io::ErrorKind::UnexpectedEof;
+            return Err(io::Error::new(eof, "data limit exceeded"));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let cached: &'r String = local_cache!(req, string);
+    Ok(cached.as_str())
+}
+
+///  Deserializes a body previously read by `read_json`
+pub(crate) fn parse_json<'r, D: Deserialize<'r>>(
+    body: &'r str,
+) -> Result<Json<D>, json::Error<'r>> {
+    json::from_str(body)
+        .map(Json)
+        .map_err(|e| json::Error::Parse(body, e))
+}
+
+///  Deserializes a body previously read by `read_json` like `parse_json`, failures coming along the path of the field
+///  serde failed at, e.g. `address.zip`
+#[cfg(feature = "parser_errors")]
+pub(crate) fn parse_json_at<'r, D: Deserialize<'r>>(
+    body: &'r str,
+) -> Result<Json<D>, (json::Error<'r>, Option<String>)> {
+    let mut deserializer = serde_json::Deserializer::from_str(body);
+
+    let data = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        (json::Error::Parse(body, err.into_inner()), Some(path))
+    })?;
+
+    match deserializer.end() {
+        Ok(_) => Ok(Json(data)),
+        Err(err) => Err((json::Error::Parse(body, err), None)),
+    }
+}
+
+///  Errors of a body which couldn't be deserialized, a `parse` error under `__all__` with the message of serde, the
+///  `line` and `column` it failed at and the `path` of the failing field if known
+pub(crate) fn parse_errors(err: &serde_json::Error, path: Option<&str>) -> ValidationErrors {
+    let mut error = ValidationError::new(PARSE_CODE);
+    error.message = Some(err.to_string().into());
+    error.add_param("line".into(), &err.line());
+    error.add_param("column".into(), &err.column());
+    if let Some(path) = path {
+        error.add_param("path".into(), &path);
+    }
+
+    let mut errors = ValidationErrors::new();
+    errors.add("__all__", error);
+    errors
+}
+
+///  Failure of a guard validating `D` which couldn't deserialize `body`, caching a `parse` error for the catchers
+pub(crate) fn parse_failure<'r, D: ?Sized>(
+    req: &Request<'_>,
+    err: json::Error<'r>,
+    path: Option<&str>,
+) -> JsonFailure<'r> {
+    if let json::Error::Parse(_, e) = &err {
+        cache_errors::<D>(req, &parse_errors(e, path));
+    }
+
+    (json_error_status(&err), ValidationGuardError::Parse(err))
+}
+
+///  Deserializes a body read by `read_body` into `D`, caching a `parse` error if it can't
+pub(crate) fn parse_body<'r, D: Deserialize<'r>>(
+    req: &Request<'_>,
+    body: &'r str,
+) -> Result<Json<D>, JsonFailure<'r>> {
+    #[cfg(feature = "parser_errors")]
+    let parsed = parse_json_at::<D>(body);
+    #[cfg(not(feature = "parser_errors"))]
+    let parsed = parse_json::<D>(body).map_err(|err| (err, None::<String>));
+
+    parsed.map_err(|(err, path)| parse_failure::<D>(req, err, path.as_deref()))
+}
+
+///  Parses a body read by `read_body` for a guard validating `D` into a `Value`, caching a `parse` error if it can't
+pub(crate) fn parse_body_value<'r, D: ?Sized>(
+    req: &Request<'_>,
+    body: &'r str,
+) -> Result<Value, JsonFailure<'r>> {
+    parse_value(body).map_err(|err| parse_failure::<D>(req, err, None))
+}
+
+///  Deserializes a `Value` parsed from `body` into `D`, caching a `parse` error if it can't
+pub(crate) fn body_from_value<'r, D: DeserializeOwned>(
+    req: &Request<'_>,
+    body: &'r str,
+    value: Value,
+) -> Result<Json<D>, JsonFailure<'r>> {
+    from_value::<D>(body, value).map_err(|err| parse_failure::<D>(req, err, None))
+}
+
+///  Whether `errors` only describe bodies which couldn't be read or deserialized, rather than invalid data
+pub(crate) fn is_unreadable(errors: &ValidationErrors) -> bool {
+    errors.errors().iter().all(|(field, kind)| match kind {
+        ValidationErrorsKind::Field(errors) if *field == "__all__" => errors
+            .iter()
+            .all(|error| error.code == PARSE_CODE || error.code == limits::PAYLOAD_TOO_LARGE_CODE),
+        _ => false,
+    })
+}
+
+///  Status of the given error, `413 Payload Too Large` for bodies over the limit and `400 Bad Request` for malformed
+///  ones and ones not matching the type, leaving `422 Unprocessable Entity` to bodies failing validation
+pub(crate) fn json_error_status(err: &json::Error<'_>) -> Status {
+    match err {
+        json::Error::Io(e) if e.kind() == io::ErrorKind::UnexpectedEof => Status::PayloadTooLarge,
+        _ => Status::BadRequest,
+    }
+}
+
+///  Parses a body previously read by `read_json` into a `Value` for inspection before deserializing it
+pub(crate) fn parse_value(body: &str) -> Result<Value, json::Error<'_>> {
+    json::from_str(body).map_err(|e| json::Error::Parse(body, e))
+}
+
+///  Deserializes a `Value` parsed from `body`
+pub(crate) fn from_value<D: DeserializeOwned>(
+    body: &str,
+    value: Value,
+) -> Result<Json<D>, json::Error<'_>> {
+    serde_json::from_value(value)
+        .map(Json)
+        .map_err(|e| json::Error::Parse(body, e))
+}
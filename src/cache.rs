@@ -0,0 +1,144 @@
+//! Cache of validation outcomes for bodies sent again unchanged
+
+use crate::{instrument, transient::is_transient, Validate, ValidationErrors};
+use rocket::request::Request;
+use std::{
+    any::type_name,
+    collections::{hash_map::RandomState, BTreeMap, HashMap},
+    hash::{BuildHasher, Hash, Hasher},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+///  Type of the body along with the hash of its content
+type Key = (&'static str, u64);
+
+struct Entries {
+    outcomes: HashMap<Key, (Result<(), ValidationErrors>, u64)>,
+    ///  Keys by last use, the first one being the least recently used
+    recency: BTreeMap<u64, Key>,
+    tick: u64,
+}
+
+///  Size-bounded LRU cache of validation outcomes, consulted by `Validated<Json<T>>` once managed
+///
+///  Bodies are still deserialized, only `Validate::validate` is skipped for a body already validated as the same type.
+///  Bodies are keyed by a 64 bit hash, randomly seeded per process.
+///
+///  Only cache pure validation: the outcome of validators depending on anything but the body (the time, a database,
+///  ...) would be replayed as it was when first computed. Transient failures are never cached
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .manage(rocket_validation::ValidationCache::new(1024))
+///  }
+///  ```
+pub struct ValidationCache {
+    capacity: usize,
+    hasher: RandomState,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+}
+
+impl ValidationCache {
+    ///  Keeps the outcomes of the `capacity` most recently validated bodies
+    pub fn new(capacity: usize) -> Self {
+        ValidationCache {
+            capacity,
+            hasher: RandomState::new(),
+            entries: Mutex::new(Entries {
+                outcomes: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    ///  Number of validations skipped thanks to the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn key<D>(&self, body: &str) -> Key {
+        let mut hasher = self.hasher.build_hasher();
+        body.hash(&mut hasher);
+        (type_name::<D>(), hasher.finish())
+    }
+
+    fn get(&self, key: &Key) -> Option<Result<(), ValidationErrors>> {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let Entries {
+            outcomes,
+            recency,
+            tick,
+        } = &mut *entries;
+
+        let (outcome, last_use) = outcomes.get_mut(key)?;
+        recency.remove(last_use);
+        *tick += 1;
+        *last_use = *tick;
+        recency.insert(*tick, *key);
+
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(outcome.clone())
+    }
+
+    fn insert(&self, key: Key, outcome: Result<(), ValidationErrors>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let Entries {
+            outcomes,
+            recency,
+            tick,
+        } = &mut *entries;
+
+        *tick += 1;
+        if let Some((_, last_use)) = outcomes.insert(key, (outcome, *tick)) {
+            recency.remove(&last_use);
+        }
+        recency.insert(*tick, key);
+
+        while outcomes.len() > self.capacity {
+            let oldest = match recency.keys().next() {
+                Some(oldest) => *oldest,
+                None => break,
+            };
+            if let Some(evicted) = recency.remove(&oldest) {
+                outcomes.remove(&evicted);
+            }
+        }
+    }
+}
+
+///  Validates `data` parsed from `body`, through the `ValidationCache` managed by the application, if any
+pub(crate) fn validate<D: Validate>(
+    req: &Request<'_>,
+    body: &str,
+    data: &D,
+) -> Result<(), ValidationErrors> {
+    let cache = match req.rocket().state::<ValidationCache>() {
+        Some(cache) => cache,
+        None => return instrument::validate(data),
+    };
+
+    let key = cache.key::<D>(body);
+    if let Some(outcome) = cache.get(&key) {
+        return outcome;
+    }
+
+    let outcome = instrument::validate(data);
+    if !matches!(&outcome, Err(errors) if is_transient(errors)) {
+        cache.insert(key, outcome.clone());
+    }
+
+    outcome
+}
@@ -0,0 +1,553 @@
+//! Alternative catchers rendering the cached `ValidationErrors` in other shapes
+
+use crate::{
+    cached_by_type, cached_errors,
+    config::{self, validation_message},
+    errors::{dotted, flatten_errors, message, pointer, walk, Segment},
+    transient::{retry_after, RetryAfter},
+    validation_catcher, Error, TraceId, ValidationErrors,
+};
+use rocket::{
+    http::ContentType,
+    request::Request,
+    response::Response,
+    serde::{
+        json::{Json, Value},
+        Serialize,
+    },
+    Catcher,
+};
+use serde_json::Map;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, PoisonError},
+};
+use validator::ValidationError;
+
+///  Message sent along transient validation errors
+const UNAVAILABLE_MESSAGE: &str =
+    "Service Unavailable. The request couldn't be validated right now, retry later.";
+
+///  Message sent along errors of outdated clients
+const UPGRADE_MESSAGE: &str =
+    "Upgrade Required. The client is too old to send this request, upgrade it and retry.";
+
+///  Struct representing errors without the human readable message of `Error`
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct StructuredError<'a> {
+    code: u128,
+    errors: Option<Cow<'a, ValidationErrors>>,
+}
+
+///  Catcher returning only the status code and the validation errors, for clients which don't show messages
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_structured])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_structured<'a>(req: &'a Request) -> Json<StructuredError<'a>> {
+    Json(StructuredError {
+        code: 422,
+        errors: cached_errors(req),
+    })
+}
+
+///  Struct representing errors as the messages of every failed field
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct FlatError<'a> {
+    code: u128,
+    message: &'a str,
+    errors: Option<HashMap<String, Vec<String>>>,
+}
+
+///  Catcher returning the messages of the failed fields by path, e.g. `{ "address.zip": ["zip must have 4 digits"] }`
+///
+///  Errors without a message are rendered like `address.zip failed the length validation`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_flat])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_flat<'a>(req: &'a Request) -> Json<FlatError<'a>> {
+    Json(FlatError {
+        code: 422,
+        message: validation_message(req),
+        errors: cached_errors(req).as_deref().map(flatten_errors),
+    })
+}
+
+///  Struct representing errors grouped by the rule which failed
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct GroupedError<'a> {
+    code: u128,
+    message: &'a str,
+    errors: Option<BTreeMap<String, Vec<String>>>,
+}
+
+///  Groups the paths of failed fields by the code of the rule they failed
+fn group_by_code(errors: &crate::ValidationErrors) -> BTreeMap<String, Vec<String>> {
+    let mut grouped = BTreeMap::<String, Vec<String>>::new();
+
+    walk(errors, &mut |path, error| {
+        let fields = grouped.entry(error.code.to_string()).or_default();
+        let field = dotted(path);
+
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    });
+
+    grouped.values_mut().for_each(|fields| fields.sort());
+    grouped
+}
+
+///  Catcher returning the failed fields grouped by rule, e.g. `{ "length": ["bio", "name"], "range": ["age"] }`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_grouped])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_grouped<'a>(req: &'a Request) -> Json<GroupedError<'a>> {
+    Json(GroupedError {
+        code: 422,
+        message: validation_message(req),
+        errors: cached_errors(req).as_deref().map(group_by_code),
+    })
+}
+
+///  Struct representing a JSON:API document holding only errors
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JsonApiErrors {
+    errors: Vec<JsonApiError>,
+}
+
+///  Struct representing a JSON:API error object
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JsonApiError {
+    status: &'static str,
+    code: String,
+    title: &'static str,
+    detail: String,
+    source: JsonApiSource,
+}
+
+///  Struct representing the member of the request document which caused an error
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JsonApiSource {
+    pointer: String,
+}
+
+///  Catcher returning validation errors as JSON:API error objects, pointing at the failed fields with JSON Pointers
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_json_api])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_json_api(req: &Request) -> (ContentType, Json<JsonApiErrors>) {
+    let mut errors = Vec::new();
+
+    if let Some(cached) = cached_errors(req) {
+        walk(&cached, &mut |path, error| {
+            errors.push(JsonApiError {
+                status: "422",
+                code: error.code.to_string(),
+                title: "Invalid Attribute",
+                detail: message(path, error),
+                source: JsonApiSource {
+                    pointer: pointer(path),
+                },
+            })
+        });
+    }
+
+    errors.sort_by(|a, b| (&a.source.pointer, &a.code).cmp(&(&b.source.pointer, &b.code)));
+
+    (
+        ContentType::new("application", "vnd.api+json"),
+        Json(JsonApiErrors { errors }),
+    )
+}
+
+///  Catcher returning transient validation errors with a `Retry-After` header
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_unavailable])
+///  }
+///  ```
+#[catch(503)]
+pub fn validation_catcher_unavailable<'a>(req: &'a Request) -> RetryAfter<Json<Error<'a>>> {
+    let errors = cached_errors(req);
+    let seconds = errors.as_deref().and_then(retry_after);
+
+    RetryAfter(
+        Json(Error {
+            code: 503,
+            message: UNAVAILABLE_MESSAGE,
+            errors,
+            by_type: cached_by_type(req),
+            trace_id: TraceId::of(req).map(|trace_id| trace_id.0.as_str()),
+        }),
+        seconds,
+    )
+}
+
+///  Catcher returning the error of clients rejected by `ValidatedVersion`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_upgrade_required])
+///  }
+///  ```
+#[catch(426)]
+pub fn validation_catcher_upgrade_required<'a>(req: &'a Request) -> Json<Error<'a>> {
+    Json(Error {
+        code: 426,
+        message: UPGRADE_MESSAGE,
+        errors: cached_errors(req),
+        by_type: cached_by_type(req),
+        trace_id: TraceId::of(req).map(|trace_id| trace_id.0.as_str()),
+    })
+}
+
+///  Catchers for every status the guards of the crate fail with, rendering the cached errors like
+///  `validation_catcher`
+///
+///  Covers `400 Bad Request` and `413 Payload Too Large` of bodies which couldn't be read or parsed, `422 Unprocessable
+///  Entity` of invalid ones, `426 Upgrade Required` of outdated clients and `503 Service Unavailable` of transient
+///  failures. A status set with `ValidationConfig::failure_status` outside of these still needs
+///  `ValidationConfig::catcher`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", rocket_validation::validation_catchers())
+///  }
+///  ```
+pub fn validation_catchers() -> Vec<Catcher> {
+    let mut catchers = catchers![
+        validation_catcher,
+        validation_catcher_upgrade_required,
+        validation_catcher_unavailable
+    ];
+
+    catchers.extend([400, 413].map(|code| Catcher::new(code, config::render)));
+    catchers
+}
+
+///  Closure turning validation errors into a response, invoked by `validation_catcher_handler`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::{http::Status, response::Response};
+///  use rocket_validation::ErrorHandler;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_handler])
+///          .manage(ErrorHandler::new(|errors| {
+///              Response::build()
+///                  .status(Status::BadRequest)
+///                  .raw_header("X-Invalid-Fields", errors.errors().len().to_string())
+///                  .finalize()
+///          }))
+///  }
+///  ```
+#[allow(clippy::type_complexity)]
+pub struct ErrorHandler(Mutex<Box<dyn FnMut(ValidationErrors) -> Response<'static> + Send>>);
+
+impl ErrorHandler {
+    ///  Wraps `handler`, run by `validation_catcher_handler` for each request whose guards cached errors, with the
+    ///  merged errors of every failed guard, the ones `validation_catcher` would send
+    pub fn new(
+        handler: impl FnMut(ValidationErrors) -> Response<'static> + Send + 'static,
+    ) -> Self {
+        ErrorHandler(Mutex::new(Box::new(handler)))
+    }
+}
+
+///  Catcher handing the cached errors to the managed `ErrorHandler`
+///
+///  Falls back to the response of `validation_catcher` when no `ErrorHandler` is managed or no errors were cached
+#[catch(422)]
+pub fn validation_catcher_handler<'a>(
+    req: &'a Request,
+) -> Result<Response<'static>, Json<Error<'a>>> {
+    let handler = req.rocket().state::<ErrorHandler>();
+
+    match (handler, cached_errors(req)) {
+        (Some(handler), Some(errors)) => {
+            let mut handler = handler.0.lock().unwrap_or_else(PoisonError::into_inner);
+            Ok((*handler)(errors.into_owned()))
+        }
+        _ => Err(validation_catcher(req)),
+    }
+}
+
+///  Transformer shaping the body returned by `validation_catcher_responder`
+///
+///  Unlike `ErrorHandler` it is also handed the request, e.g. to read headers or managed state, and called when no
+///  errors were cached
+///  ```rust
+///  use rocket::{
+///      request::Request,
+///      serde::json::{json, Json, Value},
+///  };
+///  use rocket_validation::{ValidationErrorResponder, ValidationErrors};
+///
+///  struct MyTransformer;
+///
+///  impl ValidationErrorResponder for MyTransformer {
+///      fn to_response(&self, errors: Option<&ValidationErrors>, req: &Request<'_>) -> Json<Value> {
+///          Json(json!({
+///              "path": req.uri().path().as_str(),
+///              "fields": errors.map_or(0, |errors| errors.errors().len()),
+///          }))
+///      }
+///  }
+///  ```
+pub trait ValidationErrorResponder {
+    ///  Body of the response to `req`, `errors` being the cached ones, `None` when no guard cached any, e.g. for
+    ///  bodies which couldn't be read
+    fn to_response(&self, errors: Option<&ValidationErrors>, req: &Request<'_>) -> Json<Value>;
+}
+
+///  Managed `ValidationErrorResponder` invoked by `validation_catcher_responder`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  # use rocket::{request::Request, serde::json::{Json, Value}};
+///  use rocket_validation::{ErrorResponder, ValidationErrorResponder, ValidationErrors};
+///
+///  # struct MyTransformer;
+///  # impl ValidationErrorResponder for MyTransformer {
+///  #     fn to_response(&self, _: Option<&ValidationErrors>, _: &Request<'_>) -> Json<Value> {
+///  #         Json(Value::Null)
+///  #     }
+///  # }
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_responder])
+///          .manage(ErrorResponder::new(MyTransformer))
+///  }
+///  ```
+pub struct ErrorResponder(Box<dyn ValidationErrorResponder + Send + Sync>);
+
+impl ErrorResponder {
+    pub fn new(responder: impl ValidationErrorResponder + Send + Sync + 'static) -> Self {
+        ErrorResponder(Box::new(responder))
+    }
+}
+
+///  Catcher handing the cached errors to the managed `ErrorResponder`
+///
+///  Falls back to the response of `validation_catcher` when no `ErrorResponder` is managed
+#[catch(422)]
+pub fn validation_catcher_responder<'a>(req: &'a Request) -> Result<Json<Value>, Json<Error<'a>>> {
+    match req.rocket().state::<ErrorResponder>() {
+        Some(responder) => Ok(responder.0.to_response(cached_errors(req).as_deref(), req)),
+        None => Err(validation_catcher(req)),
+    }
+}
+
+///  Struct representing errors in the shape of ajv's `validate.errors`
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AjvErrors {
+    errors: Vec<AjvError>,
+}
+
+///  Struct representing an ajv error object
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde", rename_all = "camelCase")]
+pub struct AjvError {
+    instance_path: String,
+    keyword: String,
+    message: String,
+    params: Map<String, Value>,
+}
+
+///  Number of chars, items or keys of a value failing a `length` rule
+fn length_of(value: &Value) -> Option<u64> {
+    match value {
+        Value::String(string) => Some(string.chars().count() as u64),
+        Value::Array(items) => Some(items.len() as u64),
+        Value::Object(object) => Some(object.len() as u64),
+        _ => None,
+    }
+}
+
+///  Bound of a `length` or `range` error the value fell outside of, whether it is the lower bound, and the bound
+fn violated_bound(error: &ValidationError, actual: Option<f64>) -> Option<(bool, Value)> {
+    let lower = error
+        .params
+        .get("min")
+        .or_else(|| error.params.get("equal"));
+    let upper = error
+        .params
+        .get("max")
+        .or_else(|| error.params.get("equal"));
+
+    match (lower, upper, actual) {
+        (Some(min), _, Some(actual)) if min.as_f64().map_or(false, |min| actual < min) => {
+            Some((true, min.clone()))
+        }
+        (_, Some(max), _) => Some((false, max.clone())),
+        (Some(min), None, _) => Some((true, min.clone())),
+        (None, None, _) => None,
+    }
+}
+
+///  Maps an error of `validator` to the keyword ajv would have reported
+fn ajv_error(path: &[Segment<'_>], error: &ValidationError) -> AjvError {
+    let mut instance_path = pointer(path);
+    let mut params = Map::new();
+
+    let value = error.params.get("value");
+    let bound = match &*error.code {
+        "length" => violated_bound(error, value.and_then(length_of).map(|len| len as f64)),
+        "range" => violated_bound(error, value.and_then(Value::as_f64)),
+        _ => None,
+    };
+
+    let (keyword, message) = match (&*error.code, bound) {
+        ("length", Some((lower, limit))) => {
+            let (keyword, message) = if lower {
+                (
+                    "minLength",
+                    format!("must NOT have fewer than {limit} characters"),
+                )
+            } else {
+                (
+                    "maxLength",
+                    format!("must NOT have more than {limit} characters"),
+                )
+            };
+            params.insert("limit".into(), limit);
+            (keyword.to_owned(), message)
+        }
+        ("range", Some((lower, limit))) => {
+            let (keyword, message) = if lower {
+                ("minimum", format!("must be >= {limit}"))
+            } else {
+                ("maximum", format!("must be <= {limit}"))
+            };
+            params.insert(
+                "comparison".into(),
+                Value::from(if lower { ">=" } else { "<=" }),
+            );
+            params.insert("limit".into(), limit);
+            (keyword.to_owned(), message)
+        }
+        ("email" | "url", _) => {
+            let format = if error.code == "email" {
+                "email"
+            } else {
+                "uri"
+            };
+            params.insert("format".into(), Value::from(format));
+            (
+                "format".to_owned(),
+                format!("must match format \"{format}\""),
+            )
+        }
+        ("required", _) => {
+            let property = match path.last() {
+                Some(Segment::Field(field)) => field.to_string(),
+                _ => String::new(),
+            };
+            instance_path = pointer(&path[..path.len().saturating_sub(1)]);
+            params.insert("missingProperty".into(), Value::from(property.as_str()));
+            (
+                "required".to_owned(),
+                format!("must have required property '{property}'"),
+            )
+        }
+        (code, _) => {
+            for (name, param) in &error.params {
+                if name != "value" {
+                    params.insert(name.to_string(), param.clone());
+                }
+            }
+            (
+                code.to_owned(),
+                format!("must pass \"{code}\" keyword validation"),
+            )
+        }
+    };
+
+    AjvError {
+        instance_path,
+        keyword,
+        message: error
+            .message
+            .as_ref()
+            .map_or(message, |message| message.to_string()),
+        params,
+    }
+}
+
+///  Catcher returning validation errors shaped like ajv's, for clients sharing error handling with ajv
+///
+///  Rules of `validator` are mapped to the matching Json Schema keyword (`length` to `minLength`/`maxLength`, `range`
+///  to `minimum`/`maximum`, `email` and `url` to `format`), other rules keep their code as keyword
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_ajv])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_ajv(req: &Request) -> Json<AjvErrors> {
+    let mut errors = Vec::new();
+
+    if let Some(cached) = cached_errors(req) {
+        walk(&cached, &mut |path, error| {
+            errors.push(ajv_error(path, error))
+        });
+    }
+
+    errors.sort_by(|a, b| (&a.instance_path, &a.keyword).cmp(&(&b.instance_path, &b.keyword)));
+
+    Json(AjvErrors { errors })
+}
@@ -0,0 +1,135 @@
+//! CBOR bodies, validated like json ones
+
+use crate::{instrument, validation_outcome, Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome, ToByteUnit},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::de::DeserializeOwned,
+};
+use std::{
+    io,
+    ops::{Deref, DerefMut},
+};
+
+///  CBOR body deserialized into `T`, read within the `cbor` data limit, 1 MiB by default
+///
+///  Bodies exceeding the limit fail with `413 Payload Too Large`, malformed ones with `400 Bad Request` and ones not
+///  matching `T` with `422 Unprocessable Entity`, like `Json` does
+#[derive(Clone, Debug)]
+pub struct Cbor<T>(pub T);
+
+impl<T> Cbor<T> {
+    ///  Consumes the wrapper, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Cbor<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Cbor<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+///  Errors reading a `Cbor` body
+#[derive(Debug)]
+pub enum CborError {
+    ///  The body couldn't be read, or exceeded the `cbor` data limit
+    Io(io::Error),
+    ///  The body isn't CBOR or doesn't match the type
+    Parse(ciborium::de::Error<io::Error>),
+}
+
+impl CborError {
+    fn status(&self) -> Status {
+        match self {
+            CborError::Io(e) if e.kind() == io::ErrorKind::UnexpectedEof => Status::PayloadTooLarge,
+            CborError::Parse(ciborium::de::Error::Semantic(..)) => Status::UnprocessableEntity,
+            _ => Status::BadRequest,
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for Cbor<T> {
+    type Error = CborError;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let limit = req.limits().get("cbor").unwrap_or_else(|| 1.mebibytes());
+
+        let bytes = match data.open(limit).into_bytes().await {
+            Ok(bytes) if bytes.is_complete() => bytes.into_inner(),
+            Ok(_) => {
+                let eof = io::ErrorKind::UnexpectedEof;
+                let err = CborError::Io(io::Error::new(eof, "data limit exceeded"));
+                return Outcome::Error((err.status(), err));
+            }
+            Err(e) => return Outcome::Error((Status::BadRequest, CborError::Io(e))),
+        };
+
+        match ciborium::de::from_reader(bytes.as_slice()) {
+            Ok(value) => Outcome::Success(Cbor(value)),
+            Err(e) => {
+                let err = CborError::Parse(e);
+                Outcome::Error((err.status(), err))
+            }
+        }
+    }
+}
+
+///  Impl to get type T of `Cbor`
+impl<T> Validated<Cbor<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Implementation of `Validated` for `Cbor`, behind the `cbor` feature
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{Cbor, Validate, Validated};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Reading {
+///      #[validate(range(min = -40.0, max = 125.0))]
+///      celsius: f64,
+///  }
+///
+///  #[post("/readings", data = "<data>")]
+///  fn reading(data: Validated<Cbor<Reading>>) -> String {
+///      data.into_deep_inner().celsius.to_string()
+///  }
+///  ```
+#[rocket::async_trait]
+impl<'r, D: Validate + DeserializeOwned> FromData<'r> for Validated<Cbor<D>> {
+    type Error = ValidationGuardError<CborError>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        match <Cbor<D> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, err)) => {
+                Outcome::Error((status, ValidationGuardError::Parse(err)))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(data) => {
+                validation_outcome::<D, _, _, _>(req, instrument::validate(&*data), Validated(data))
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Opt-in coercion of single values sent for array fields
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+
+///  Bodies with array fields clients may send a single value for
+///  ```rust
+///  use rocket_validation::Coerced;
+///
+///  pub struct Post {
+///      tags: Vec<String>,
+///  }
+///
+///  impl Coerced for Post {
+///      const ARRAYS: &'static [&'static str] = &["tags"];
+///  }
+///  ```
+pub trait Coerced {
+    ///  Top level fields holding arrays, a single value sent for them is handed to serde as a one-element array
+    const ARRAYS: &'static [&'static str];
+}
+
+///  Struct used for Request Guards wrapping single values of `Coerced` array fields into arrays before validating the
+///  body, e.g. `"tags": "x"` into `"tags": ["x"]`
+///
+///  Arrays and `null` are left untouched, so optional arrays stay absent
+#[derive(Clone, Debug)]
+pub struct ValidatedCoerced<T>(pub T);
+
+impl_into_inner!(ValidatedCoerced);
+
+///  Implementation of `ValidatedCoerced` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Coerced + DeserializeOwned> FromData<'r> for ValidatedCoerced<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let mut value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        if let Some(object) = value.as_object_mut() {
+            for field in D::ARRAYS {
+                if let Some(entry) = object.get_mut(*field) {
+                    if !entry.is_array() && !entry.is_null() {
+                        *entry = Value::Array(vec![entry.take()]);
+                    }
+                }
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedCoerced(data),
+            ),
+        }
+    }
+}
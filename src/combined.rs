@@ -0,0 +1,129 @@
+//! Guard assembling a single struct from the path, the query and the body of a request
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+use serde_json::Map;
+
+///  Part of the request a field is read from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    ///  Dynamic path segment named like the field, e.g. `<user_id>`
+    Path,
+    ///  Query parameter named like the field
+    Query,
+    ///  Key of the json body, the default for fields without a source
+    Body,
+}
+
+///  Structs whose fields come from different parts of the request
+///  ```rust
+///  use rocket_validation::{Source, Sourced};
+///
+///  pub struct NewPost {
+///      user_id: u64,
+///      title: String,
+///  }
+///
+///  impl Sourced for NewPost {
+///      fn sources() -> Vec<(&'static str, Source)> {
+///          vec![("user_id", Source::Path)]
+///      }
+///  }
+///  ```
+pub trait Sourced {
+    ///  Fields not read from the body along with their source
+    fn sources() -> Vec<(&'static str, Source)>;
+}
+
+///  Struct used for Request Guards validating a struct assembled from the path, the query and the body
+#[derive(Clone, Debug)]
+pub struct ValidatedCombined<T>(pub T);
+
+impl_into_inner!(ValidatedCombined);
+
+///  Raw value of the path segment `<name>` of the matched route
+fn path_value<'r>(req: &'r Request<'_>, name: &str) -> Option<&'r str> {
+    let pattern = format!("<{name}>");
+    let index = req
+        .route()?
+        .uri
+        .path()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .position(|segment| segment == pattern)?;
+
+    req.uri().path().segments().get(index)
+}
+
+///  Path and query values are strings, those reading as a json number or boolean are handed to serde as such
+fn scalar(raw: &str) -> Value {
+    match json::from_str::<Value>(raw) {
+        Ok(value @ (Value::Number(_) | Value::Bool(_))) => value,
+        _ => Value::String(raw.to_owned()),
+    }
+}
+
+///  Implementation of `ValidatedCombined` for `Json`
+///
+///  Keys of the body claiming to be a path or query field are dropped, so clients can't override them. The merged
+///  struct is validated as a whole and its errors are cached like any other validation failure
+#[rocket::async_trait]
+impl<'r, D: Validate + Sourced + DeserializeOwned> FromData<'r> for ValidatedCombined<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let value = if body.trim().is_empty() {
+            Value::Object(Map::new())
+        } else {
+            match parse_body_value::<D>(req, body) {
+                Ok(value) => value,
+                Err(failure) => return Outcome::Error(failure),
+            }
+        };
+
+        let value = match value {
+            Value::Object(mut object) => {
+                for (field, source) in D::sources() {
+                    let raw = match source {
+                        Source::Body => continue,
+                        Source::Path => path_value(req, field),
+                        Source::Query => req.query_value::<&str>(field).and_then(|v| v.ok()),
+                    };
+
+                    match raw {
+                        Some(raw) => object.insert(field.to_owned(), scalar(raw)),
+                        None => object.remove(field),
+                    };
+                }
+
+                Value::Object(object)
+            }
+            value => value,
+        };
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedCombined(data),
+            ),
+        }
+    }
+}
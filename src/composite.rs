@@ -0,0 +1,110 @@
+//! Request guards composed of several guards, each validating independently
+
+use rocket::{
+    http::Status,
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+};
+
+///  Runs the guards of the fields of a composite request guard, each of them validating independently and caching
+///  its errors, so a request failing several fields reports all of them instead of the first one only
+///
+///  Rocket stops at the first failing guard of a struct written with `?`, `Composite` keeps going and fails once every
+///  field ran
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::request::{self, FromRequest, Request};
+///  use rocket_validation::{Composite, CompositeError, QueryParams, Validate, Validated, ValidatedHeaders};
+///  use rocket::serde::Deserialize;
+///
+///  #[derive(Debug, FromForm, Validate)]
+///  pub struct Paging {
+///      #[validate(range(min = 1, max = 100))]
+///      per_page: u8,
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Client {
+///      #[validate(length(min = 3))]
+///      x_client_id: String,
+///  }
+///
+///  pub struct Search {
+///      paging: Validated<QueryParams<Paging>>,
+///      client: ValidatedHeaders<Client>,
+///  }
+///
+///  #[rocket::async_trait]
+///  impl<'r> FromRequest<'r> for Search {
+///      type Error = CompositeError;
+///
+///      async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+///          let mut fields = Composite::new(req);
+///          let paging = fields.guard().await;
+///          let client = fields.guard().await;
+///
+///          fields.outcome(|| {
+///              Some(Search {
+///                  paging: paging?,
+///                  client: client?,
+///              })
+///          })
+///      }
+///  }
+///  ```
+pub struct Composite<'r, 'a> {
+    req: &'r Request<'a>,
+    failed: Vec<(&'static str, Status)>,
+    forward: Option<Status>,
+}
+
+///  Error of composite guards, the guards of the fields which failed
+#[derive(Debug)]
+pub struct CompositeError {
+    ///  Names of the types of the failed guards, in the order they ran
+    pub failed: Vec<&'static str>,
+}
+
+impl<'r, 'a> Composite<'r, 'a> {
+    pub fn new(req: &'r Request<'a>) -> Self {
+        Composite {
+            req,
+            failed: Vec::new(),
+            forward: None,
+        }
+    }
+
+    ///  Runs the guard `T` of a field, `None` if it failed or forwarded
+    pub async fn guard<T: FromRequest<'r>>(&mut self) -> Option<T> {
+        match T::from_request(self.req).await {
+            Outcome::Success(value) => Some(value),
+            Outcome::Error((status, _)) => {
+                self.failed.push((std::any::type_name::<T>(), status));
+                None
+            }
+            Outcome::Forward(status) => {
+                self.forward.get_or_insert(status);
+                None
+            }
+        }
+    }
+
+    ///  Outcome of the composite guard, failing with the status of the first failed field, forwarding if a field
+    ///  forwarded, otherwise succeeding with the value built by `build` out of the fields
+    pub fn outcome<T>(
+        self,
+        build: impl FnOnce() -> Option<T>,
+    ) -> request::Outcome<T, CompositeError> {
+        if let Some((_, status)) = self.failed.first() {
+            let status = *status;
+            let failed = self.failed.into_iter().map(|(name, _)| name).collect();
+            return Outcome::Error((status, CompositeError { failed }));
+        }
+
+        match (self.forward, build()) {
+            (None, Some(value)) => Outcome::Success(value),
+            (forward, _) => Outcome::Forward(forward.unwrap_or(Status::NotFound)),
+        }
+    }
+}
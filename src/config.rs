@@ -0,0 +1,161 @@
+//! Application wide configuration of the guards
+
+use crate::{body, cached_by_type, cached_errors, Error, TraceId, VALIDATION_MESSAGE};
+use rocket::{
+    catcher::{BoxFuture, Catcher},
+    http::Status,
+    request::Request,
+    response::Responder,
+    serde::json::Json,
+};
+
+///  Message sent along validation errors failing with a status other than `422 Unprocessable Entity`
+const FAILED_MESSAGE: &str = "Validation Failed. The request was well-formed \
+                              but was unable to be followed due to semantic errors.";
+
+///  Once managed, overrides the status guards fail with when the data is invalid and whether invalid values are
+///  echoed
+///
+///  The failure status defaults to `422 Unprocessable Entity`. Transient failures keep failing with
+///  `503 Service Unavailable`, and `Form<Validated<T>>` fails through rocket's form errors with
+///  `422 Unprocessable Entity` regardless of the config. As `validation_catcher` only catches `422`, register
+///  `ValidationConfig::catcher` instead
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::http::Status;
+///  use rocket_validation::ValidationConfig;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      let config = ValidationConfig::default().failure_status(Status::BadRequest);
+///
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", vec![config.catcher()])
+///          .manage(config)
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidationConfig {
+    failure_status: Status,
+    echo_values: bool,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        ValidationConfig {
+            failure_status: Status::UnprocessableEntity,
+            echo_values: true,
+        }
+    }
+}
+
+impl ValidationConfig {
+    ///  Status guards fail with when the data is invalid, `422 Unprocessable Entity` by default
+    pub fn failure_status(mut self, status: Status) -> Self {
+        self.failure_status = status;
+        self
+    }
+
+    ///  Whether the catchers echo the invalid values `validator` adds as `value` param to errors, `true` by default
+    ///
+    ///  Values of `Sensitive` fields are masked as `****` either way
+    pub fn echo_values(mut self, echo: bool) -> Self {
+        self.echo_values = echo;
+        self
+    }
+
+    ///  Whether the catchers echo the invalid values, see `echo_values`
+    pub fn echoes_values(&self) -> bool {
+        self.echo_values
+    }
+
+    ///  Status invalid data fails with
+    pub fn status(&self) -> Status {
+        self.failure_status
+    }
+
+    ///  Catcher rendering the cached errors like `validation_catcher`, for the configured status
+    pub fn catcher(&self) -> Catcher {
+        Catcher::new(self.failure_status.code, render)
+    }
+}
+
+///  Once managed, replaces the messages the catchers send along validation errors, e.g. to localize them
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::ValidationMessages;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher])
+///          .manage(ValidationMessages::default().unprocessable("Die Anfrage ist ungültig."))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidationMessages {
+    unprocessable: String,
+    failed: String,
+}
+
+impl Default for ValidationMessages {
+    fn default() -> Self {
+        ValidationMessages {
+            unprocessable: VALIDATION_MESSAGE.to_owned(),
+            failed: FAILED_MESSAGE.to_owned(),
+        }
+    }
+}
+
+impl ValidationMessages {
+    ///  Message of `422 Unprocessable Entity` responses
+    pub fn unprocessable(mut self, message: impl Into<String>) -> Self {
+        self.unprocessable = message.into();
+        self
+    }
+
+    ///  Message of validation errors failing with the other status of `ValidationConfig::failure_status`
+    pub fn failed(mut self, message: impl Into<String>) -> Self {
+        self.failed = message.into();
+        self
+    }
+}
+
+///  Message of `422 Unprocessable Entity` responses, from the managed `ValidationMessages` if any
+pub(crate) fn validation_message<'r>(req: &'r Request<'_>) -> &'r str {
+    req.rocket()
+        .state::<ValidationMessages>()
+        .map_or(VALIDATION_MESSAGE, |messages| {
+            messages.unprocessable.as_str()
+        })
+}
+
+///  Message of validation errors failing with another status, from the managed `ValidationMessages` if any
+fn failed_message<'r>(req: &'r Request<'_>) -> &'r str {
+    req.rocket()
+        .state::<ValidationMessages>()
+        .map_or(FAILED_MESSAGE, |messages| messages.failed.as_str())
+}
+
+///  Renders the cached errors like `validation_catcher` for any status, requests failing without validation errors,
+///  e.g. unparsable or oversized bodies, getting the reason of their status as message
+pub(crate) fn render<'r>(status: Status, req: &'r Request<'_>) -> BoxFuture<'r> {
+    Box::pin(async move {
+        let errors = cached_errors(req);
+
+        Json(Error {
+            code: u128::from(status.code),
+            message: match errors {
+                _ if status == Status::UnprocessableEntity => validation_message(req),
+                Some(ref errors) if !body::is_unreadable(errors) => failed_message(req),
+                _ => status.reason().unwrap_or_else(|| failed_message(req)),
+            },
+            errors,
+            by_type: cached_by_type(req),
+            trace_id: TraceId::of(req).map(|trace_id| trace_id.0.as_str()),
+        })
+        .respond_to(req)
+    })
+}
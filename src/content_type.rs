@@ -0,0 +1,103 @@
+//! Guard enforcing an exact `Content-Type` before validating the body
+
+use crate::{
+    body::{parse_body, read_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::{ContentType, Status},
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+
+///  Content types accepted by `ValidatedContentType`, compared exactly including their parameters
+///
+///  Without it being managed only a bare `application/json` is accepted
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::http::ContentType;
+///  use rocket_validation::AllowedContentTypes;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(AllowedContentTypes(vec![
+///          ContentType::JSON,
+///          ContentType::parse_flexible("application/json; charset=utf-8").unwrap(),
+///      ]))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct AllowedContentTypes(pub Vec<ContentType>);
+
+impl AllowedContentTypes {
+    fn allows(&self, content_type: &ContentType) -> bool {
+        self.0.iter().any(|allowed| allowed.exact_eq(content_type))
+    }
+}
+
+impl Default for AllowedContentTypes {
+    fn default() -> Self {
+        AllowedContentTypes(vec![ContentType::JSON])
+    }
+}
+
+///  Struct used for Request Guards checking the `Content-Type` against `AllowedContentTypes` before validating the body
+#[derive(Clone, Debug)]
+pub struct ValidatedContentType<T>(pub T);
+
+impl_into_inner!(ValidatedContentType);
+
+///  Errors of `ValidatedContentType` happening before validation
+#[derive(Debug)]
+pub enum ContentTypeError<'r> {
+    ///  The `Content-Type` is missing or not allowed
+    Unsupported,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedContentType` for `Json`
+///
+///  A `Content-Type` not in the allowlist fails with `415 Unsupported Media Type` before the body is read
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r>
+    for ValidatedContentType<Json<D>>
+{
+    type Error = ValidationGuardError<ContentTypeError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let allowed = match (
+            req.content_type(),
+            req.rocket().state::<AllowedContentTypes>(),
+        ) {
+            (Some(content_type), Some(allowlist)) => allowlist.allows(content_type),
+            (Some(content_type), None) => AllowedContentTypes::default().allows(content_type),
+            (None, _) => false,
+        };
+
+        if !allowed {
+            return Outcome::Error((
+                Status::UnsupportedMediaType,
+                ValidationGuardError::Parse(ContentTypeError::Unsupported),
+            ));
+        }
+
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(ContentTypeError::Json)))
+            }
+        };
+
+        match parse_body::<D>(req, body) {
+            Err((status, err)) => Outcome::Error((status, err.map_parse(ContentTypeError::Json))),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedContentType(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Validation against a context provided by the application, e.g. data loaded at startup
+
+use crate::{
+    body::{parse_body, read_body},
+    validation_outcome, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+use validator::ValidateArgs;
+
+///  Context handed to custom validators using `use_context`
+///
+///  By default the context is the managed instance of the type, override `from_request` for contexts derived from
+///  the request
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::ValidationContext;
+///
+///  pub struct Reserved(Vec<String>);
+///
+///  impl ValidationContext for Reserved {}
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(Reserved(vec!["admin".to_string()]))
+///  }
+///  ```
+pub trait ValidationContext: Send + Sync + Sized + 'static {
+    ///  Context to validate the body of `req` with, `None` failing the request with `500 Internal Server Error`
+    fn from_request<'r>(req: &'r Request<'_>) -> Option<&'r Self> {
+        req.rocket().state::<Self>()
+    }
+}
+
+///  Struct used for Request Guards validating with a `ValidationContext`
+///
+///  The context is the one of the `#[validate(context = ...)]` attribute of the struct, fetched from the managed
+///  state of Rocket unless `ValidationContext::from_request` is overridden, and handed to `validate_with_args`.
+///  Without a context the request fails with `500 Internal Server Error`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Validate, ValidatedWith, ValidationContext};
+///  use validator::ValidationError;
+///
+///  pub struct Reserved(Vec<String>);
+///
+///  impl ValidationContext for Reserved {}
+///
+///  fn not_reserved(name: &str, reserved: &Reserved) -> Result<(), ValidationError> {
+///      if reserved.0.iter().any(|r| r == name) {
+///          return Err(ValidationError::new("reserved"));
+///      }
+///      Ok(())
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = Reserved)]
+///  pub struct Signup {
+///      #[validate(custom(function = "not_reserved", use_context))]
+///      name: String,
+///  }
+///
+///  #[post("/signup", data = "<data>")]
+///  fn signup(data: ValidatedWith<Json<Signup>>) -> String {
+///      data.into_deep_inner().name
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![signup])
+///          .manage(Reserved(vec!["admin".to_string()]))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedWith<T>(pub T);
+
+impl_into_inner!(ValidatedWith);
+
+///  Errors of `ValidatedWith` besides validation failures
+#[derive(Debug)]
+pub enum ContextError<'r> {
+    ///  No context could be found for the request
+    Unmanaged,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedWith` for `Json`
+#[rocket::async_trait]
+impl<'r, D, C> FromData<'r> for ValidatedWith<Json<D>>
+where
+    D: ValidateArgs<'r, Args = &'r C> + Deserialize<'r>,
+    C: ValidationContext,
+{
+    type Error = ValidationGuardError<ContextError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let context = match C::from_request(req) {
+            Some(context) => context,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(ContextError::Unmanaged),
+                ))
+            }
+        };
+
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(ContextError::Json)))
+            }
+        };
+
+        match parse_body::<D>(req, body) {
+            Err((status, err)) => Outcome::Error((status, err.map_parse(ContextError::Json))),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                data.validate_with_args(context),
+                ValidatedWith(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Guard checking a CSRF token against the session before validating a form
+
+use crate::{body::read_form, instrument, validation_outcome, Validate, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    form::{self, Form, FromForm},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+};
+use std::io;
+
+///  Storage of the CSRF token issued to each session
+pub trait CsrfStore: Send + Sync {
+    ///  Token issued to `session`, if any
+    fn token(&self, session: &str) -> Option<String>;
+}
+
+///  Managed configuration of `ValidatedCsrf`
+///
+///  The session is read from the cookie `session`, the token from the form field `csrf_token` or, when the form
+///  doesn't have one, the `X-CSRF-Token` header
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::{CsrfStore, CsrfTokens};
+///  use std::collections::HashMap;
+///
+///  struct Sessions(HashMap<String, String>);
+///
+///  impl CsrfStore for Sessions {
+///      fn token(&self, session: &str) -> Option<String> {
+///          self.0.get(session).cloned()
+///      }
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(CsrfTokens::new(Sessions(HashMap::new())).cookie("sid"))
+///  }
+///  ```
+pub struct CsrfTokens {
+    store: Box<dyn CsrfStore>,
+    cookie: &'static str,
+    field: &'static str,
+}
+
+impl CsrfTokens {
+    ///  Looks the expected token of each session up in `store`
+    pub fn new(store: impl CsrfStore + 'static) -> Self {
+        CsrfTokens {
+            store: Box::new(store),
+            cookie: "session",
+            field: "csrf_token",
+        }
+    }
+
+    ///  Name of the cookie holding the session
+    pub fn cookie(mut self, cookie: &'static str) -> Self {
+        self.cookie = cookie;
+        self
+    }
+
+    ///  Name of the form field holding the token
+    pub fn field(mut self, field: &'static str) -> Self {
+        self.field = field;
+        self
+    }
+
+    ///  Whether the token sent along `body` is the one issued to the session of `req`
+    fn verify(&self, req: &Request<'_>, body: &str) -> bool {
+        let expected = match req
+            .cookies()
+            .get(self.cookie)
+            .and_then(|session| self.store.token(session.value()))
+        {
+            Some(expected) => expected,
+            None => return false,
+        };
+
+        let sent = Form::values(body)
+            .find(|field| field.name.source() == self.field)
+            .map(|field| field.value)
+            .or_else(|| req.headers().get_one("X-CSRF-Token"));
+
+        match sent {
+            Some(sent) => constant_time_eq(sent.as_bytes(), expected.as_bytes()),
+            None => false,
+        }
+    }
+}
+
+///  Compares tokens without leaking the length of their common prefix
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+///  Struct used for Request Guards checking the CSRF token of a form before validating it
+#[derive(Clone, Debug)]
+pub struct ValidatedCsrf<T>(pub T);
+
+///  Impl to get type T of `Form`
+impl<T> ValidatedCsrf<Form<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+///  Impl to get type T
+impl<T> ValidatedCsrf<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+///  Errors of `ValidatedCsrf` besides validation failures
+#[derive(Debug)]
+pub enum CsrfError<'r> {
+    ///  `CsrfTokens` isn't managed
+    Unmanaged,
+    ///  The token is missing or isn't the one issued to the session
+    Mismatch,
+    ///  The body couldn't be read
+    Io(io::Error),
+    ///  The body couldn't be parsed
+    Form(form::Errors<'r>),
+}
+
+///  Implementation of `ValidatedCsrf` for url-encoded `Form`s
+///
+///  Requests without a session, without a token or with another token fail with `403 Forbidden` before the form is
+///  parsed. The token field is ignored when parsing `T`
+#[rocket::async_trait]
+impl<'r, T: Validate + FromForm<'r>> FromData<'r> for ValidatedCsrf<Form<T>> {
+    type Error = ValidationGuardError<CsrfError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let tokens = match req.rocket().state::<CsrfTokens>() {
+            Some(tokens) => tokens,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(CsrfError::Unmanaged),
+                ))
+            }
+        };
+
+        let body = match read_form(req, data).await {
+            Ok(body) => body,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Outcome::Error((
+                    Status::PayloadTooLarge,
+                    ValidationGuardError::Parse(CsrfError::Io(e)),
+                ))
+            }
+            Err(e) => {
+                return Outcome::Error((
+                    Status::BadRequest,
+                    ValidationGuardError::Parse(CsrfError::Io(e)),
+                ))
+            }
+        };
+
+        if !tokens.verify(req, body) {
+            return Outcome::Error((
+                Status::Forbidden,
+                ValidationGuardError::Parse(CsrfError::Mismatch),
+            ));
+        }
+
+        match Form::<T>::parse(body) {
+            Err(errors) => Outcome::Error((
+                errors.status(),
+                ValidationGuardError::Parse(CsrfError::Form(errors)),
+            )),
+            Ok(data) => validation_outcome::<T, _, _, _>(
+                req,
+                instrument::validate(&data),
+                ValidatedCsrf(Form::from(data)),
+            ),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Catcher rendering validation errors as CSV, e.g. for spreadsheets
+
+use crate::{
+    cached_errors,
+    errors::{dotted, walk},
+};
+use rocket::{http::ContentType, request::Request};
+
+///  Quotes `value` if it holds a separator, a quote or a line break, as RFC 4180 requires
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+///  Catcher returning validation errors as `text/csv` with a `field,code,message` header and a row per failed rule
+///
+///  Rows are sorted by field and code, errors without a message have an empty `message` column
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_csv])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_csv(req: &Request) -> (ContentType, String) {
+    let mut rows = Vec::new();
+
+    if let Some(cached) = cached_errors(req) {
+        walk(&cached, &mut |path, error| {
+            rows.push((
+                dotted(path),
+                error.code.to_string(),
+                error
+                    .message
+                    .as_ref()
+                    .map_or_else(String::new, |message| message.to_string()),
+            ))
+        });
+    }
+
+    rows.sort();
+
+    let mut csv = String::from("field,code,message\r\n");
+    for (field, code, message) in rows {
+        csv.push_str(&format!(
+            "{},{},{}\r\n",
+            escape(&field),
+            escape(&code),
+            escape(&message)
+        ));
+    }
+
+    (ContentType::CSV, csv)
+}
@@ -0,0 +1,104 @@
+//! Detection of cyclic `$ref` references in json bodies
+
+use crate::{body::LazyValue, ValidationErrors};
+use rocket::serde::json::Value;
+use std::collections::HashSet;
+use validator::ValidationError;
+
+///  Collects `(location, target)` of every local `{"$ref": "#/..."}` below `value`
+fn collect_refs(value: &Value, location: &mut String, found: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(target) = object.get("$ref").and_then(Value::as_str) {
+                if let Some(pointer) = target.strip_prefix('#') {
+                    found.push((location.clone(), pointer.to_owned()));
+                }
+            }
+
+            for (key, nested) in object {
+                let len = location.len();
+                location.push('/');
+                location.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                collect_refs(nested, location, found);
+                location.truncate(len);
+            }
+        }
+        Value::Array(items) => {
+            for (index, nested) in items.iter().enumerate() {
+                let len = location.len();
+                location.push('/');
+                location.push_str(&index.to_string());
+                collect_refs(nested, location, found);
+                location.truncate(len);
+            }
+        }
+        _ => {}
+    }
+}
+
+///  References found in the node `target` points to, none if it doesn't point to any
+fn refs_of(doc: &Value, target: &str) -> std::vec::IntoIter<(String, String)> {
+    let mut found = Vec::new();
+    if let Some(node) = doc.pointer(target) {
+        collect_refs(node, &mut target.to_owned(), &mut found);
+    }
+
+    found.into_iter()
+}
+
+///  Location of a reference leading back into a node being expanded from the root of `doc`, if any
+///
+///  References are followed depth first with an explicit stack, so long chains of references can't overflow the one
+///  of the thread, and every node is expanded once at most
+fn find_cycle(doc: &Value) -> Option<String> {
+    let mut stack = vec![(String::new(), refs_of(doc, ""))];
+    let mut expanding = HashSet::new();
+    let mut acyclic = HashSet::new();
+    expanding.insert(String::new());
+
+    while let Some((_, pending)) = stack.last_mut() {
+        match pending.next() {
+            Some((location, next)) => {
+                if expanding.contains(&next) {
+                    return Some(location);
+                }
+
+                if !acyclic.contains(&next) {
+                    let pending = refs_of(doc, &next);
+                    expanding.insert(next.clone());
+                    stack.push((next, pending));
+                }
+            }
+            None => {
+                if let Some((target, _)) = stack.pop() {
+                    expanding.remove(&target);
+                    acyclic.insert(target);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+///  Rejects bodies whose `$ref` references can't be expanded without looping
+///
+///  Bodies which aren't valid json pass, the parser reports them afterwards
+pub(crate) fn check_ref_cycles(value: &mut LazyValue<'_>) -> Result<(), ValidationErrors> {
+    let doc = match value.get() {
+        Some(doc) => &*doc,
+        None => return Ok(()),
+    };
+
+    match find_cycle(doc) {
+        None => Ok(()),
+        Some(location) => {
+            let mut error = ValidationError::new("ref_cycle");
+            error.add_param("pointer".into(), &location);
+
+            let mut errors = ValidationErrors::new();
+            errors.add("__all__", error);
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Scale and precision of decimal values, e.g. monetary amounts
+
+use std::fmt::Display;
+use validator::ValidationError;
+
+///  Integer digits and decimal places of `value`, ignoring its sign, leading and trailing zeros
+fn digits<T: Display + ?Sized>(value: &T) -> Option<(usize, usize)> {
+    let value = value.to_string();
+    let value = value.trim().trim_start_matches(['-', '+']);
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    Some((
+        integer.trim_start_matches('0').len(),
+        fraction.trim_end_matches('0').len(),
+    ))
+}
+
+///  Error for values which aren't decimals at all
+fn not_decimal() -> ValidationError {
+    ValidationError::new("decimal")
+}
+
+///  Helper for custom validators rejecting values with more than `max` decimal places, with a `decimal_scale` error
+///  holding `max` and the `scale` of the value
+///
+///  Works with floats as well as decimals sent as strings, trailing zeros don't count towards the scale
+///  ```rust
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{max_scale, Validate};
+///  use validator::ValidationError;
+///
+///  fn cents(amount: &f64) -> Result<(), ValidationError> {
+///      max_scale(amount, 2)
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Payment {
+///      #[validate(custom(function = "cents"))]
+///      amount: f64,
+///  }
+///  ```
+pub fn max_scale<T: Display + ?Sized>(value: &T, max: usize) -> Result<(), ValidationError> {
+    let (_, scale) = digits(value).ok_or_else(not_decimal)?;
+
+    if scale <= max {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("decimal_scale");
+    error.add_param("max".into(), &max);
+    error.add_param("scale".into(), &scale);
+    Err(error)
+}
+
+///  Helper for custom validators rejecting values which don't fit a `NUMERIC(precision, scale)` column
+///
+///  Too many decimal places fail like `max_scale`, too many integer digits fail with a `decimal_precision` error
+///  holding `precision`, `scale` and the number of `integer_digits` of the value
+///  ```rust
+///  use rocket_validation::max_precision;
+///  use validator::ValidationError;
+///
+///  fn price(amount: &str) -> Result<(), ValidationError> {
+///      max_precision(amount, 10, 2)
+///  }
+///  ```
+pub fn max_precision<T: Display + ?Sized>(
+    value: &T,
+    precision: usize,
+    scale: usize,
+) -> Result<(), ValidationError> {
+    max_scale(value, scale)?;
+
+    let (integer_digits, _) = digits(value).ok_or_else(not_decimal)?;
+
+    if integer_digits + scale <= precision {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("decimal_precision");
+    error.add_param("precision".into(), &precision);
+    error.add_param("scale".into(), &scale);
+    error.add_param("integer_digits".into(), &integer_digits);
+    Err(error)
+}
@@ -0,0 +1,122 @@
+//! Canonical dedup keys of validated bodies, e.g. for upserts
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    request::Request,
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+
+///  Bodies identified by a business key, e.g. the email of a user or the sku and warehouse of a stock entry
+///  ```rust
+///  use rocket_validation::Deduplicated;
+///
+///  pub struct Stock {
+///      sku: String,
+///      warehouse: String,
+///      quantity: u32,
+///  }
+///
+///  impl Deduplicated for Stock {
+///      fn key_components(&self) -> Vec<String> {
+///          vec![self.sku.clone(), self.warehouse.clone()]
+///      }
+///  }
+///  ```
+pub trait Deduplicated {
+    ///  Values making up the key, in order
+    fn key_components(&self) -> Vec<String>;
+}
+
+///  Canonical key of a `Deduplicated` body
+///
+///  Components are trimmed, lowercased and joined with `|`, a `|` or `\` within a component being escaped with `\`,
+///  so ` ABC-1 ` and `abc-1` share a key while `a|b` and `a`, `b` don't
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DedupKey(pub String);
+
+impl DedupKey {
+    pub fn of(value: &impl Deduplicated) -> Self {
+        let components: Vec<String> = value
+            .key_components()
+            .iter()
+            .map(|component| {
+                component
+                    .trim()
+                    .to_lowercase()
+                    .replace('\\', "\\\\")
+                    .replace('|', "\\|")
+            })
+            .collect();
+
+        DedupKey(components.join("|"))
+    }
+}
+
+///  Struct used for Request Guards validating the body and computing its `DedupKey`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  # use rocket::serde::Deserialize;
+///  # use rocket_validation::{Deduplicated, Validate};
+///  # #[derive(Debug, Deserialize, Validate)]
+///  # #[serde(crate = "rocket::serde")]
+///  # pub struct Stock {
+///  #     sku: String,
+///  #     warehouse: String,
+///  # }
+///  # impl Deduplicated for Stock {
+///  #     fn key_components(&self) -> Vec<String> {
+///  #         vec![self.sku.clone(), self.warehouse.clone()]
+///  #     }
+///  # }
+///  use rocket::serde::json::Json;
+///  use rocket_validation::ValidatedDedup;
+///
+///  #[put("/stock", data = "<data>")]
+///  fn upsert(data: ValidatedDedup<Json<Stock>>) -> String {
+///      // insert or update the entry stored under the key
+///      data.key().0.clone()
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedDedup<T>(pub T, pub DedupKey);
+
+///  Impl to get type T of `Json`
+impl<T> ValidatedDedup<Json<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T
+impl<T> ValidatedDedup<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    pub fn key(&self) -> &DedupKey {
+        &self.1
+    }
+}
+
+///  Implementation of `ValidatedDedup` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Deduplicated + Deserialize<'r>> FromData<'r> for ValidatedDedup<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        <Validated<Json<D>> as FromData<'r>>::from_data(req, data)
+            .await
+            .map(|Validated(data)| {
+                let key = DedupKey::of(&*data);
+                ValidatedDedup(data, key)
+            })
+    }
+}
@@ -0,0 +1,108 @@
+//! Missing fields of bodies filled from server-side defaults before validating them
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+        Serialize,
+    },
+};
+use serde_json::Map;
+
+///  Struct used for Request Guards filling the fields missing from the body with the ones of `D::default()` before
+///  deserializing and validating it, so defaults are set in one place instead of a `#[serde(default)]` on every field
+///
+///  Nested objects are filled field by field, fields sent as `null` are left untouched
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize, Serialize};
+///  use rocket_validation::{Validate, ValidatedWithDefaults};
+///
+///  #[derive(Debug, Deserialize, Serialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Paging {
+///      #[validate(range(min = 1, max = 100))]
+///      per_page: u8,
+///      page: u32,
+///  }
+///
+///  impl Default for Paging {
+///      fn default() -> Self {
+///          Paging { per_page: 20, page: 1 }
+///      }
+///  }
+///
+///  #[post("/search", data = "<paging>")]
+///  fn search(paging: ValidatedWithDefaults<Json<Paging>>) -> String {
+///      paging.into_deep_inner().per_page.to_string()
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedWithDefaults<T>(pub T);
+
+impl_into_inner!(ValidatedWithDefaults);
+
+///  Inserts the fields of `defaults` missing from `value`, recursing into objects present in both
+fn fill(value: &mut Map<String, Value>, defaults: Map<String, Value>) {
+    for (key, default) in defaults {
+        match (value.get_mut(&key), default) {
+            (Some(Value::Object(value)), Value::Object(default)) => fill(value, default),
+            (Some(_), _) => {}
+            (None, default) => {
+                value.insert(key, default);
+            }
+        }
+    }
+}
+
+///  Implementation of `ValidatedWithDefaults` for `Json`
+///
+///  Defaults which don't serialize fail with `500 Internal Server Error`
+#[rocket::async_trait]
+impl<'r, D: Validate + Default + Serialize + DeserializeOwned> FromData<'r>
+    for ValidatedWithDefaults<Json<D>>
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let mut value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        if let Some(object) = value.as_object_mut() {
+            match serde_json::to_value(D::default()) {
+                Ok(Value::Object(defaults)) => fill(object, defaults),
+                Ok(_) => {}
+                Err(err) => {
+                    return Outcome::Error((
+                        Status::InternalServerError,
+                        ValidationGuardError::Parse(json::Error::Parse(body, err)),
+                    ))
+                }
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedWithDefaults(data),
+            ),
+        }
+    }
+}
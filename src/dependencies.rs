@@ -0,0 +1,141 @@
+//! Guard requiring fields to be sent along with the fields they depend on
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_failure, validation_outcome, Validate, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+use serde_json::Map;
+use validator::ValidationError;
+
+///  Bodies whose fields may only be sent along with others, forming a dependency graph
+///  ```rust
+///  use rocket_validation::Dependent;
+///
+///  pub struct Checkout {
+///      account: Option<String>,
+///      billing_address: Option<String>,
+///      shipping_address: Option<String>,
+///  }
+///
+///  impl Dependent for Checkout {
+///      const DEPENDENCIES: &'static [(&'static str, &'static [&'static str])] = &[
+///          ("billing_address", &["account"]),
+///          ("shipping_address", &["billing_address"]),
+///      ];
+///  }
+///  ```
+pub trait Dependent {
+    ///  Top level fields along with the fields they require
+    const DEPENDENCIES: &'static [(&'static str, &'static [&'static str])];
+}
+
+fn is_present(object: &Map<String, Value>, field: &str) -> bool {
+    matches!(object.get(field), Some(value) if !value.is_null())
+}
+
+fn requirements(
+    field: &str,
+    dependencies: &[(&'static str, &'static [&'static str])],
+) -> Vec<&'static str> {
+    dependencies
+        .iter()
+        .filter(|(dependent, _)| *dependent == field)
+        .flat_map(|(_, required)| required.iter().copied())
+        .collect()
+}
+
+///  Missing prerequisites of `field`, direct ones first, then the ones they require in turn
+fn missing(
+    object: &Map<String, Value>,
+    field: &str,
+    dependencies: &[(&'static str, &'static [&'static str])],
+) -> Vec<&'static str> {
+    let mut visited = vec![];
+    let mut queue = requirements(field, dependencies);
+    let mut missing = vec![];
+
+    while !queue.is_empty() {
+        let required = queue.remove(0);
+        if required == field || visited.contains(&required) {
+            continue;
+        }
+        visited.push(required);
+
+        if !is_present(object, required) {
+            missing.push(required);
+        }
+        queue.extend(requirements(required, dependencies));
+    }
+
+    missing
+}
+
+///  Struct used for Request Guards rejecting `Dependent` fields sent without the fields they require
+///
+///  Fields are present when sent with a value other than `null`. A present field missing prerequisites, directly or
+///  through the fields it requires, fails with a `missing_prerequisite` error holding the `missing` fields, before the
+///  body is validated
+#[derive(Clone, Debug)]
+pub struct ValidatedDependencies<T>(pub T);
+
+impl_into_inner!(ValidatedDependencies);
+
+///  Implementation of `ValidatedDependencies` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Dependent + DeserializeOwned> FromData<'r>
+    for ValidatedDependencies<Json<D>>
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        if let Some(object) = value.as_object() {
+            let mut err = ValidationErrors::new();
+
+            for (field, _) in D::DEPENDENCIES {
+                if !is_present(object, field) || err.errors().contains_key(field) {
+                    continue;
+                }
+
+                let missing = missing(object, field, D::DEPENDENCIES);
+                if !missing.is_empty() {
+                    let mut error = ValidationError::new("missing_prerequisite");
+                    error.add_param("missing".into(), &missing);
+                    err.add(field, error);
+                }
+            }
+
+            if !err.is_empty() {
+                return Outcome::Error(validation_failure::<D, _>(req, err));
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedDependencies(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! `Deprecation` and `Sunset` headers for deprecated routes which still validate their input, `Warning` headers for
+//! deprecated fields
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    response::{self, Responder},
+    serde::json::{self, Json},
+};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+///  Headers sent by a deprecated route
+#[derive(Clone, Debug)]
+pub struct DeprecationNotice {
+    ///  Value of the `Deprecation` header, e.g. `@1735689599` or `true`
+    pub deprecation: String,
+    ///  Value of the `Sunset` header as a HTTP date, e.g. `Wed, 31 Dec 2025 23:59:59 GMT`
+    pub sunset: Option<String>,
+}
+
+impl DeprecationNotice {
+    pub fn new(deprecation: impl Into<String>) -> Self {
+        DeprecationNotice {
+            deprecation: deprecation.into(),
+            sunset: None,
+        }
+    }
+
+    pub fn sunset(mut self, sunset: impl Into<String>) -> Self {
+        self.sunset = Some(sunset.into());
+        self
+    }
+}
+
+///  Deprecated routes, by the name of their handler
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::json::Json;
+///  use rocket_validation::{Deprecated, DeprecationNotice, Deprecations, Validate, Validated};
+///
+///  #[derive(Debug, rocket::serde::Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct HelloData {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[post("/v1/hello", data = "<data>")]
+///  fn hello_v1(data: Validated<Json<HelloData>>) -> Deprecated<String> {
+///      Deprecated(data.into_deep_inner().name)
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![hello_v1])
+///          .manage(Deprecations::default().route(
+///              "hello_v1",
+///              DeprecationNotice::new("true").sunset("Wed, 31 Dec 2025 23:59:59 GMT"),
+///          ))
+///  }
+///  ```
+#[derive(Clone, Debug, Default)]
+pub struct Deprecations(pub HashMap<String, DeprecationNotice>);
+
+impl Deprecations {
+    ///  Marks the route handled by the function `name` as deprecated
+    pub fn route(mut self, name: impl Into<String>, notice: DeprecationNotice) -> Self {
+        self.0.insert(name.into(), notice);
+        self
+    }
+
+    fn notice(&self, req: &Request<'_>) -> Option<&DeprecationNotice> {
+        let name = req.route()?.name.as_deref()?;
+        self.0.get(name)
+    }
+}
+
+///  Responder adding the headers configured in the managed `Deprecations` for the current route to the response of `R`
+///
+///  A `Warning: 299 - "Deprecated field: <name>"` header is added for every deprecated field a `ValidatedDeprecated`
+///  guard of the route received. Responses of routes without a `DeprecationNotice` or deprecated fields are left
+///  unchanged
+#[derive(Debug)]
+pub struct Deprecated<R>(pub R);
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Deprecated<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.0.respond_to(req)?;
+
+        let notice = req
+            .rocket()
+            .state::<Deprecations>()
+            .and_then(|deprecations| deprecations.notice(req));
+
+        if let Some(notice) = notice {
+            response.set_raw_header("Deprecation", notice.deprecation.clone());
+
+            if let Some(sunset) = &notice.sunset {
+                response.set_raw_header("Sunset", sunset.clone());
+            }
+        }
+
+        for field in received(req) {
+            response.adjoin_raw_header("Warning", format!("299 - \"Deprecated field: {field}\""));
+        }
+
+        Ok(response)
+    }
+}
+
+///  Trait listing the deprecated fields a body was sent with
+///  ```rust
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{DeprecatedFields, Validate};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct HelloData {
+///      #[validate(length(min = 1))]
+///      name: Option<String>,
+///      #[validate(length(min = 1))]
+///      username: Option<String>,
+///  }
+///
+///  impl DeprecatedFields for HelloData {
+///      fn deprecated_fields(&self) -> Vec<&'static str> {
+///          match self.username {
+///              Some(_) => vec!["username"],
+///              None => vec![],
+///          }
+///      }
+///  }
+///  ```
+pub trait DeprecatedFields {
+    ///  Names of the deprecated fields present in `self`
+    fn deprecated_fields(&self) -> Vec<&'static str>;
+}
+
+///  Request local storage of the deprecated fields received
+struct ReceivedFields(Mutex<Vec<&'static str>>);
+
+fn received(req: &Request<'_>) -> Vec<&'static str> {
+    req.local_cache(|| ReceivedFields(Mutex::new(Vec::new())))
+        .0
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+fn record(req: &Request<'_>, fields: Vec<&'static str>) {
+    req.local_cache(|| ReceivedFields(Mutex::new(Vec::new())))
+        .0
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .extend(fields);
+}
+
+///  Struct used for Request Guards recording the deprecated fields of a valid body, for `Deprecated` to warn about
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  # use rocket::serde::Deserialize;
+///  # use rocket_validation::{DeprecatedFields, Validate};
+///  # #[derive(Debug, Deserialize, Validate)]
+///  # #[serde(crate = "rocket::serde")]
+///  # pub struct HelloData {
+///  #     name: Option<String>,
+///  #     username: Option<String>,
+///  # }
+///  # impl DeprecatedFields for HelloData {
+///  #     fn deprecated_fields(&self) -> Vec<&'static str> {
+///  #         self.username.iter().map(|_| "username").collect()
+///  #     }
+///  # }
+///  use rocket::serde::json::Json;
+///  use rocket_validation::{Deprecated, ValidatedDeprecated};
+///
+///  #[post("/hello", data = "<data>")]
+///  fn hello(data: ValidatedDeprecated<Json<HelloData>>) -> Deprecated<&'static str> {
+///      Deprecated("hello")
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedDeprecated<T>(pub T);
+
+impl_into_inner!(ValidatedDeprecated);
+
+///  Implementation of `ValidatedDeprecated` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + DeprecatedFields + rocket::serde::Deserialize<'r>> FromData<'r>
+    for ValidatedDeprecated<Json<D>>
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        <Validated<Json<D>> as FromData<'r>>::from_data(req, data)
+            .await
+            .map(|Validated(data)| {
+                record(req, data.deprecated_fields());
+                ValidatedDeprecated(data)
+            })
+    }
+}
@@ -0,0 +1,95 @@
+//! Guard verifying the `Digest` header against the body before validating it
+
+use crate::{
+    body::{check_body, parse_body, read_raw_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use sha2::{Digest, Sha256};
+
+///  Struct used for Request Guards checking the body against its `Digest: sha-256=...` header before validating it
+#[derive(Clone, Debug)]
+pub struct ValidatedDigest<T>(pub T);
+
+impl_into_inner!(ValidatedDigest);
+
+///  Errors of `ValidatedDigest` happening before validation
+#[derive(Debug)]
+pub enum DigestError<'r> {
+    ///  The `Digest` header is absent or carries no `sha-256` value
+    Missing,
+    ///  The `sha-256` value doesn't match the received body
+    Mismatch,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Extracts the decoded `sha-256` value of a `Digest` header
+fn sha256_of(header: &str) -> Option<Vec<u8>> {
+    header.split(',').find_map(|instance| {
+        let (algorithm, value) = instance.trim().split_once('=')?;
+
+        if algorithm.eq_ignore_ascii_case("sha-256") {
+            STANDARD.decode(value.trim()).ok()
+        } else {
+            None
+        }
+    })
+}
+
+///  Implementation of `ValidatedDigest` for `Json`
+///
+///  A missing digest or a digest not matching the body fails with `400 Bad Request` before validation
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for ValidatedDigest<Json<D>> {
+    type Error = ValidationGuardError<DigestError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let expected = match req.headers().get_one("Digest").and_then(sha256_of) {
+            Some(expected) => expected,
+            None => {
+                return Outcome::Error((
+                    Status::BadRequest,
+                    ValidationGuardError::Parse(DigestError::Missing),
+                ))
+            }
+        };
+
+        let body = match read_raw_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(DigestError::Json)))
+            }
+        };
+
+        if Sha256::digest(body.as_bytes()).as_slice() != expected.as_slice() {
+            return Outcome::Error((
+                Status::BadRequest,
+                ValidationGuardError::Parse(DigestError::Mismatch),
+            ));
+        }
+
+        let body = match check_body::<D>(req, body) {
+            Ok(body) => body,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(DigestError::Json)))
+            }
+        };
+
+        match parse_body::<D>(req, body) {
+            Err((status, err)) => Outcome::Error((status, err.map_parse(DigestError::Json))),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedDigest(data),
+            ),
+        }
+    }
+}
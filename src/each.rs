@@ -0,0 +1,69 @@
+//! Guard validating every element of a json array
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    validation_outcome, Validate, ValidationErrors, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json},
+    },
+};
+use std::collections::BTreeMap;
+use validator::ValidationErrorsKind;
+
+///  Struct used for Request Guards validating every element of a json array
+///
+///  Failures of all the elements are aggregated, by index, so the catchers report e.g. `[2].name`
+#[derive(Clone, Debug)]
+pub struct ValidatedEach<T>(pub T);
+
+impl_into_inner!(ValidatedEach);
+
+///  Errors of the failed elements listed by index under `__all__`, or `None` if every element is valid
+pub(crate) fn validate_elements<D: Validate>(elements: &[D]) -> Option<ValidationErrors> {
+    let items: BTreeMap<usize, Box<ValidationErrors>> = elements
+        .iter()
+        .enumerate()
+        .filter_map(|(index, element)| element.validate().err().map(|err| (index, Box::new(err))))
+        .collect();
+
+    if items.is_empty() {
+        return None;
+    }
+
+    let mut err = ValidationErrors::new();
+    err.errors_mut()
+        .insert("__all__", ValidationErrorsKind::List(items));
+    Some(err)
+}
+
+///  Implementation of `ValidatedEach` for `Json` arrays
+#[rocket::async_trait]
+impl<'r, D: Validate + DeserializeOwned> FromData<'r> for ValidatedEach<Json<Vec<D>>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let data = match body_from_value::<Vec<D>>(req, body, value) {
+            Ok(data) => data,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let validated = validate_elements(&data).map_or(Ok(()), Err);
+        validation_outcome::<D, _, _, _>(req, validated, ValidatedEach(data))
+    }
+}
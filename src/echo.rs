@@ -0,0 +1,95 @@
+//! Echo of the body of invalid requests, a debugging aid for client developers
+//!
+//! Echoed bodies are sent back verbatim, passwords, tokens and any other secret they hold included, and bypass the
+//! masking of `Sensitive` fields. Only enable the `echo_input` feature in development builds
+
+use crate::{cached_errors, config::validation_message, ValidationErrors};
+use rocket::{
+    request::Request,
+    serde::{json::Json, Serialize},
+};
+use std::borrow::Cow;
+
+///  Bytes of the body echoed when no `EchoLimit` is managed
+const DEFAULT_LIMIT: usize = 1024;
+
+///  Maximum number of bytes of the body echoed by `validation_catcher_echo`, longer bodies being truncated
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .register("/", catchers![rocket_validation::validation_catcher_echo])
+///          .manage(rocket_validation::EchoLimit(256))
+///  }
+///  ```
+#[derive(Clone, Copy, Debug)]
+pub struct EchoLimit(pub usize);
+
+///  Body of the request as read by the guard, capped to the `EchoLimit`, along with its full size
+struct CachedInput(Option<(Vec<u8>, usize)>);
+
+///  Records the start of `body` for the catcher
+pub(crate) fn record(req: &Request<'_>, body: &str) {
+    let limit = req
+        .rocket()
+        .state::<EchoLimit>()
+        .map_or(DEFAULT_LIMIT, |limit| limit.0);
+
+    let echoed = body.as_bytes()[..body.len().min(limit)].to_vec();
+    req.local_cache(|| CachedInput(Some((echoed, body.len()))));
+}
+
+///  Body received with the request
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct Received {
+    ///  Start of the body, bytes which aren't valid UTF-8 replaced by `U+FFFD`
+    body: String,
+    ///  Size of the whole body in bytes
+    size: usize,
+    ///  Whether `body` was cut at the `EchoLimit`
+    truncated: bool,
+}
+
+///  Struct representing validation errors along with the body which failed
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct EchoError<'a> {
+    code: u128,
+    message: &'a str,
+    errors: Option<Cow<'a, ValidationErrors>>,
+    received: Option<Received>,
+}
+
+///  Catcher returning validation errors like `validation_catcher` along with the body the client sent as `received`
+///
+///  Only `Validated<Json<T>>` records bodies, `received` is `null` for other guards
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_echo])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_echo<'a>(req: &'a Request) -> Json<EchoError<'a>> {
+    let received = req
+        .local_cache(|| CachedInput(None))
+        .0
+        .as_ref()
+        .map(|(echoed, size)| Received {
+            body: String::from_utf8_lossy(echoed).into_owned(),
+            size: *size,
+            truncated: echoed.len() < *size,
+        });
+
+    Json(EchoError {
+        code: 422,
+        message: validation_message(req),
+        errors: cached_errors(req),
+        received,
+    })
+}
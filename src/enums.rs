@@ -0,0 +1,141 @@
+//! Case insensitive matching of enum variants, suggesting the closest ones to unknown values
+
+use crate::introspect::declared_names;
+use rocket::serde::{
+    de::{self, value, DeserializeOwned, IntoDeserializer},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use validator::ValidationError;
+
+///  Enum `T` matched regardless of case, keeping unknown values for `known_variant` to report
+///  ```rust
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{known_variant, CaseInsensitive, Validate};
+///
+///  #[derive(Debug, Deserialize)]
+///  #[serde(crate = "rocket::serde", rename_all = "snake_case")]
+///  pub enum Status {
+///      Active,
+///      Suspended,
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Account {
+///      #[validate(custom(function = "known_variant"))]
+///      status: CaseInsensitive<Status>,
+///  }
+///  ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CaseInsensitive<T> {
+    Known(T),
+    ///  Value sent matching none of the variants of `T`
+    Unknown(String),
+}
+
+impl<T> CaseInsensitive<T> {
+    ///  The variant, always `Some` once validated
+    #[inline]
+    pub fn known(&self) -> Option<&T> {
+        match self {
+            CaseInsensitive::Known(known) => Some(known),
+            CaseInsensitive::Unknown(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn into_known(self) -> Option<T> {
+        match self {
+            CaseInsensitive::Known(known) => Some(known),
+            CaseInsensitive::Unknown(_) => None,
+        }
+    }
+}
+
+///  Only unit variants can be matched, as they are the only ones sent as plain strings
+impl<'de, T: DeserializeOwned> Deserialize<'de> for CaseInsensitive<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        match declared_names::<T>()
+            .iter()
+            .find(|variant| variant.eq_ignore_ascii_case(&value))
+        {
+            Some(variant) => {
+                let variant: value::StrDeserializer<value::Error> = (*variant).into_deserializer();
+                T::deserialize(variant)
+                    .map(CaseInsensitive::Known)
+                    .map_err(de::Error::custom)
+            }
+            None => Ok(CaseInsensitive::Unknown(value)),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for CaseInsensitive<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CaseInsensitive::Known(known) => known.serialize(serializer),
+            CaseInsensitive::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+///  Levenshtein distance between `a` and `b`
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a == *b {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+///  Custom validator failing unknown values of a `CaseInsensitive` with an `unknown_variant` error
+///
+///  The error carries the `value` sent, the `expected` variants and `suggestions`, the variants closest to the value
+pub fn known_variant<T: DeserializeOwned>(
+    value: &CaseInsensitive<T>,
+) -> Result<(), ValidationError> {
+    let sent = match value {
+        CaseInsensitive::Known(_) => return Ok(()),
+        CaseInsensitive::Unknown(sent) => sent,
+    };
+
+    let variants = declared_names::<T>();
+    let lowercase = sent.to_lowercase();
+
+    let mut suggestions: Vec<(usize, &str)> = variants
+        .iter()
+        .map(|variant| (distance(&lowercase, &variant.to_lowercase()), *variant))
+        .filter(|(distance, variant)| {
+            *distance <= 2 || (!lowercase.is_empty() && variant.to_lowercase().contains(&lowercase))
+        })
+        .collect();
+    suggestions.sort_unstable();
+
+    let mut error = ValidationError::new("unknown_variant");
+    error.add_param("value".into(), sent);
+    error.add_param("expected".into(), &variants);
+    error.add_param(
+        "suggestions".into(),
+        &suggestions
+            .iter()
+            .map(|(_, variant)| *variant)
+            .collect::<Vec<_>>(),
+    );
+    Err(error)
+}
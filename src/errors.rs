@@ -0,0 +1,259 @@
+//! Traversal of nested `ValidationErrors`
+
+use crate::ValidationErrors;
+use std::collections::{BTreeMap, HashMap};
+use validator::{ValidationError, ValidationErrorsKind};
+
+///  Segment of the path leading to a failed field
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Segment<'a> {
+    Field(&'a str),
+    Index(usize),
+}
+
+///  Calls `visit` for every `ValidationError` along with the path of its field
+pub(crate) fn walk<'a>(
+    errors: &'a ValidationErrors,
+    visit: &mut impl FnMut(&[Segment<'a>], &'a ValidationError),
+) {
+    walk_at(errors, &mut Vec::new(), visit)
+}
+
+fn walk_at<'a>(
+    errors: &'a ValidationErrors,
+    path: &mut Vec<Segment<'a>>,
+    visit: &mut impl FnMut(&[Segment<'a>], &'a ValidationError),
+) {
+    for (field, kind) in errors.errors() {
+        path.push(Segment::Field(field));
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                for error in errors {
+                    visit(path.as_slice(), error);
+                }
+            }
+            ValidationErrorsKind::Struct(nested) => walk_at(nested, path, visit),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    path.push(Segment::Index(*index));
+                    walk_at(nested, path, visit);
+                    path.pop();
+                }
+            }
+        }
+
+        path.pop();
+    }
+}
+
+///  Renders a path as `address.zip` or `children[2].name`, elements of a body which is an array as `[2].name`
+pub(crate) fn dotted(path: &[Segment<'_>]) -> String {
+    let mut rendered = String::new();
+
+    let path = match path {
+        [Segment::Field("__all__"), rest @ [Segment::Index(_), ..]] => rest,
+        path => path,
+    };
+
+    for segment in path {
+        match segment {
+            Segment::Field(field) => {
+                if !rendered.is_empty() {
+                    rendered.push('.');
+                }
+                rendered.push_str(field);
+            }
+            Segment::Index(index) => {
+                rendered.push('[');
+                rendered.push_str(&index.to_string());
+                rendered.push(']');
+            }
+        }
+    }
+
+    rendered
+}
+
+///  Renders a path without its indices, e.g. `children.name`, shared by every element of an array
+pub(crate) fn field_path(path: &[Segment<'_>]) -> String {
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Field(field) => Some(*field),
+            Segment::Index(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+///  Calls `visit` for every `ValidationError` along with the path of its field, allowing to modify it
+pub(crate) fn walk_mut(
+    errors: &mut ValidationErrors,
+    visit: &mut impl FnMut(&[Segment<'static>], &mut ValidationError),
+) {
+    walk_mut_at(errors, &mut Vec::new(), visit)
+}
+
+fn walk_mut_at(
+    errors: &mut ValidationErrors,
+    path: &mut Vec<Segment<'static>>,
+    visit: &mut impl FnMut(&[Segment<'static>], &mut ValidationError),
+) {
+    for (field, kind) in errors.errors_mut().iter_mut() {
+        path.push(Segment::Field(*field));
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                for error in errors {
+                    visit(path.as_slice(), error);
+                }
+            }
+            ValidationErrorsKind::Struct(nested) => walk_mut_at(nested, path, visit),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items.iter_mut() {
+                    path.push(Segment::Index(*index));
+                    walk_mut_at(nested, path, visit);
+                    path.pop();
+                }
+            }
+        }
+
+        path.pop();
+    }
+}
+
+///  Renders a path as a JSON Pointer like `/children/2/name`
+pub(crate) fn pointer(path: &[Segment<'_>]) -> String {
+    let mut rendered = String::new();
+
+    for segment in path {
+        rendered.push('/');
+        match segment {
+            Segment::Field(field) => {
+                rendered.push_str(&field.replace('~', "~0").replace('/', "~1"))
+            }
+            Segment::Index(index) => rendered.push_str(&index.to_string()),
+        }
+    }
+
+    rendered
+}
+
+///  Number of `ValidationError`s in `errors`
+pub(crate) fn count(errors: &ValidationErrors) -> usize {
+    let mut count = 0;
+    walk(errors, &mut |_, _| count += 1);
+    count
+}
+
+///  Copy of `errors` keeping at most `max` `ValidationError`s, fields being visited in sorted order so the kept
+///  errors don't depend on the order of the underlying `HashMap`
+pub(crate) fn truncate(errors: &ValidationErrors, max: usize) -> ValidationErrors {
+    let mut remaining = max;
+    truncate_at(errors, &mut remaining)
+}
+
+fn truncate_at(errors: &ValidationErrors, remaining: &mut usize) -> ValidationErrors {
+    let mut kept = ValidationErrors::new();
+
+    let mut fields: Vec<_> = errors.errors().iter().collect();
+    fields.sort_by_key(|(field, _)| **field);
+
+    for (field, kind) in fields {
+        if *remaining == 0 {
+            break;
+        }
+
+        let kind = match kind {
+            ValidationErrorsKind::Field(errors) => {
+                let taken: Vec<_> = errors.iter().take(*remaining).cloned().collect();
+                *remaining -= taken.len();
+                ValidationErrorsKind::Field(taken)
+            }
+            ValidationErrorsKind::Struct(nested) => {
+                ValidationErrorsKind::Struct(Box::new(truncate_at(nested, remaining)))
+            }
+            ValidationErrorsKind::List(items) => {
+                let mut taken = BTreeMap::new();
+                for (index, nested) in items {
+                    if *remaining == 0 {
+                        break;
+                    }
+                    taken.insert(*index, Box::new(truncate_at(nested, remaining)));
+                }
+                ValidationErrorsKind::List(taken)
+            }
+        };
+
+        kept.errors_mut().insert(*field, kind);
+    }
+
+    kept
+}
+
+///  Appends `other` to `errors`, the rules failed by a field in both being listed once each
+pub(crate) fn merge(errors: &mut ValidationErrors, other: ValidationErrors) {
+    for (field, kind) in other.into_errors() {
+        match (errors.errors_mut().get_mut(field), kind) {
+            (None, kind) => {
+                errors.errors_mut().insert(field, kind);
+            }
+            (Some(ValidationErrorsKind::Field(known)), ValidationErrorsKind::Field(added)) => {
+                for error in added {
+                    if !known.contains(&error) {
+                        known.push(error);
+                    }
+                }
+            }
+            (Some(ValidationErrorsKind::Struct(known)), ValidationErrorsKind::Struct(added)) => {
+                merge(known, *added)
+            }
+            (Some(ValidationErrorsKind::List(known)), ValidationErrorsKind::List(added)) => {
+                for (index, added) in added {
+                    match known.get_mut(&index) {
+                        Some(nested) => merge(nested, *added),
+                        None => {
+                            known.insert(index, added);
+                        }
+                    }
+                }
+            }
+            // a field can't be a value and a struct at once, the first guard wins
+            (Some(_), _) => {}
+        }
+    }
+}
+
+///  Message of `error`, or one naming the field at `path` and the failed rule if it has none
+pub(crate) fn message(path: &[Segment<'_>], error: &ValidationError) -> String {
+    match &error.message {
+        Some(message) => message.to_string(),
+        None => format!("{} failed the {} validation", dotted(path), error.code),
+    }
+}
+
+///  Flattens nested errors into the messages of every failed field, keyed by paths like `address.zip` or
+///  `children[2].name`
+///  ```rust
+///  use rocket_validation::{flatten_errors, Validate};
+///
+///  #[derive(Validate)]
+///  struct HelloData {
+///      #[validate(length(min = 1, message = "name can't be empty"))]
+///      name: String,
+///  }
+///
+///  let errors = HelloData { name: String::new() }.validate().unwrap_err();
+///  assert_eq!(flatten_errors(&errors)["name"], ["name can't be empty"]);
+///  ```
+pub fn flatten_errors(errors: &ValidationErrors) -> HashMap<String, Vec<String>> {
+    let mut flat = HashMap::<String, Vec<String>>::new();
+
+    walk(errors, &mut |path, error| {
+        flat.entry(dotted(path))
+            .or_default()
+            .push(message(path, error))
+    });
+
+    flat
+}
@@ -0,0 +1,231 @@
+//! Guard checking payloads stay compatible with the previous version of their schema
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    errors::{pointer, Segment},
+    instrument, validation_failure, validation_outcome, Validate, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+use std::collections::HashMap;
+use validator::ValidationError;
+
+///  Payloads of a schema with several versions, e.g. events read by consumers of older versions
+pub trait Evolving {
+    ///  Name the previous version of the schema is registered under in `SchemaVersions`
+    const SCHEMA: &'static str;
+}
+
+///  Check of a payload against the previous version of its schema
+pub trait Compatibility: Send + Sync {
+    ///  Adds an error to `errors` for every part of `payload` readers of `previous` couldn't handle
+    fn check(&self, previous: &Value, payload: &Value, errors: &mut ValidationErrors);
+}
+
+///  `Compatibility` of payloads holding the `required` properties of the previous Json Schema with the declared
+///  `type`, in nested objects as well
+///
+///  Each violation is an `incompatible_schema` error on `__all__` with the params `pointer`, `reason` (`missing` or
+///  `type`) and the `expected` type
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BackwardCompatible;
+
+///  Whether `value` is of the Json Schema `type`
+fn is_type(value: &Value, kind: &str) -> bool {
+    match kind {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn incompatible(path: &[Segment<'_>], reason: &str, expected: Option<&Value>) -> ValidationError {
+    let mut error = ValidationError::new("incompatible_schema");
+    error.add_param("pointer".into(), &pointer(path));
+    error.add_param("reason".into(), &reason);
+    if let Some(expected) = expected {
+        error.add_param("expected".into(), expected);
+    }
+    error
+}
+
+fn check_value<'v>(
+    schema: &'v Value,
+    value: &'v Value,
+    path: &mut Vec<Segment<'v>>,
+    errors: &mut ValidationErrors,
+) {
+    let expected = schema.get("type");
+    let matches = match expected {
+        Some(Value::String(kind)) => is_type(value, kind),
+        Some(Value::Array(kinds)) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|kind| is_type(value, kind)),
+        _ => true,
+    };
+
+    if !matches {
+        errors.add("__all__", incompatible(path, "type", expected));
+        return;
+    }
+
+    let object = match value.as_object() {
+        Some(object) => object,
+        None => return,
+    };
+
+    let required = schema.get("required").and_then(Value::as_array);
+    for property in required.into_iter().flatten().filter_map(Value::as_str) {
+        if !object.contains_key(property) {
+            path.push(Segment::Field(property));
+            errors.add("__all__", incompatible(path, "missing", None));
+            path.pop();
+        }
+    }
+
+    let properties = schema.get("properties").and_then(Value::as_object);
+    for (property, nested) in properties.into_iter().flatten() {
+        if let Some(value) = object.get(property) {
+            path.push(Segment::Field(property));
+            check_value(nested, value, path, errors);
+            path.pop();
+        }
+    }
+}
+
+impl Compatibility for BackwardCompatible {
+    fn check(&self, previous: &Value, payload: &Value, errors: &mut ValidationErrors) {
+        check_value(previous, payload, &mut Vec::new(), errors);
+    }
+}
+
+///  Previous versions of the schemas of `Evolving` payloads, checked by `ValidatedCompatible`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::json::json;
+///  use rocket_validation::SchemaVersions;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(SchemaVersions::default().schema(
+///          "order_placed",
+///          json!({
+///              "type": "object",
+///              "required": ["id", "total"],
+///              "properties": { "id": { "type": "string" }, "total": { "type": "number" } }
+///          }),
+///      ))
+///  }
+///  ```
+pub struct SchemaVersions {
+    schemas: HashMap<&'static str, Value>,
+    compatibility: Box<dyn Compatibility>,
+}
+
+impl Default for SchemaVersions {
+    fn default() -> Self {
+        SchemaVersions {
+            schemas: HashMap::new(),
+            compatibility: Box::new(BackwardCompatible),
+        }
+    }
+}
+
+impl SchemaVersions {
+    ///  Registers the previous version of the schema `name`
+    pub fn schema(mut self, name: &'static str, previous: Value) -> Self {
+        self.schemas.insert(name, previous);
+        self
+    }
+
+    ///  Replaces the `BackwardCompatible` check
+    pub fn compatibility(mut self, compatibility: impl Compatibility + 'static) -> Self {
+        self.compatibility = Box::new(compatibility);
+        self
+    }
+}
+
+///  Struct used for Request Guards checking the body is compatible with the previous version of its schema before
+///  validating it
+///
+///  Payloads of schemas without a previous version are always compatible
+#[derive(Clone, Debug)]
+pub struct ValidatedCompatible<T>(pub T);
+
+impl_into_inner!(ValidatedCompatible);
+
+///  Errors of `ValidatedCompatible` besides validation failures
+#[derive(Debug)]
+pub enum CompatibilityError<'r> {
+    ///  No `SchemaVersions` are managed by the application
+    Unmanaged,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedCompatible` for `Json`
+///
+///  Incompatible payloads fail with the errors of the `Compatibility` check before being deserialized
+#[rocket::async_trait]
+impl<'r, D: Validate + Evolving + DeserializeOwned> FromData<'r> for ValidatedCompatible<Json<D>> {
+    type Error = ValidationGuardError<CompatibilityError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let versions = match req.rocket().state::<SchemaVersions>() {
+            Some(versions) => versions,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(CompatibilityError::Unmanaged),
+                ))
+            }
+        };
+
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(CompatibilityError::Json)))
+            }
+        };
+
+        let value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(CompatibilityError::Json)))
+            }
+        };
+
+        if let Some(previous) = versions.schemas.get(D::SCHEMA) {
+            let mut err = ValidationErrors::new();
+            versions.compatibility.check(previous, &value, &mut err);
+
+            if !err.is_empty() {
+                return Outcome::Error(validation_failure::<D, _>(req, err));
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err((status, err)) => Outcome::Error((status, err.map_parse(CompatibilityError::Json))),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedCompatible(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Guard limiting the validated requests in flight per resource
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+///  Bodies modifying the resource identified by `resource_id`
+pub trait Resource {
+    ///  Id keying the requests in flight in `ResourceLocks`, bodies with equal ids contend for the same permits
+    fn resource_id(&self) -> String;
+}
+
+///  Number of requests in flight per resource id, limiting `ValidatedExclusive`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(rocket_validation::ResourceLocks::new(1))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ResourceLocks {
+    max: usize,
+    in_flight: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl ResourceLocks {
+    ///  Allows `max` requests in flight for every resource
+    pub fn new(max: usize) -> Self {
+        ResourceLocks {
+            max,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    ///  Requests in flight for `id`
+    pub fn in_flight(&self, id: &str) -> usize {
+        let in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        in_flight.get(id).copied().unwrap_or(0)
+    }
+
+    fn acquire(&self, id: String) -> Option<Permit> {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let count = in_flight.entry(id.clone()).or_insert(0);
+
+        if *count >= self.max {
+            return None;
+        }
+
+        *count += 1;
+        Some(Permit {
+            id,
+            in_flight: Arc::clone(&self.in_flight),
+        })
+    }
+}
+
+///  Slot of a request in flight, released once dropped
+#[derive(Debug)]
+pub struct Permit {
+    id: String,
+    in_flight: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(count) = in_flight.get_mut(&self.id) {
+            *count -= 1;
+            if *count == 0 {
+                in_flight.remove(&self.id);
+            }
+        }
+    }
+}
+
+///  Struct used for Request Guards validating the body and holding a slot of the `ResourceLocks` of its resource
+///
+///  The slot is held as long as the guard, i.e. until the handler returns unless the guard is moved elsewhere.
+///  Requests for a resource which has no slot left fail with `409 Conflict`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Resource, Validate, ValidatedExclusive};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Transfer {
+///      account: String,
+///      #[validate(range(min = 1))]
+///      amount: u64,
+///  }
+///
+///  impl Resource for Transfer {
+///      fn resource_id(&self) -> String {
+///          self.account.clone()
+///      }
+///  }
+///
+///  #[post("/transfers", data = "<data>")]
+///  fn transfer(data: ValidatedExclusive<Json<Transfer>>) -> String {
+///      // no other transfer of the account is in flight
+///      data.0.account.clone()
+///  }
+///  ```
+#[derive(Debug)]
+pub struct ValidatedExclusive<T>(pub T, pub Permit);
+
+///  Impl to get type T of `Json`, releasing the slot
+impl<T> ValidatedExclusive<Json<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T, releasing the slot
+impl<T> ValidatedExclusive<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+///  Errors of `ValidatedExclusive` besides validation failures
+#[derive(Debug)]
+pub enum ExclusiveError<'r> {
+    ///  No `ResourceLocks` are managed by the application
+    Unmanaged,
+    ///  The resource has no slot left
+    Contended,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedExclusive` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Resource + rocket::serde::Deserialize<'r>> FromData<'r>
+    for ValidatedExclusive<Json<D>>
+{
+    type Error = ValidationGuardError<ExclusiveError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let locks = match req.rocket().state::<ResourceLocks>() {
+            Some(locks) => locks,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(ExclusiveError::Unmanaged),
+                ))
+            }
+        };
+
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, ValidationGuardError::Validation(err))) => {
+                Outcome::Error((status, ValidationGuardError::Validation(err)))
+            }
+            Outcome::Error((status, ValidationGuardError::Parse(err))) => Outcome::Error((
+                status,
+                ValidationGuardError::Parse(ExclusiveError::Json(err)),
+            )),
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(Validated(data)) => match locks.acquire(data.resource_id()) {
+                Some(permit) => Outcome::Success(ValidatedExclusive(data, permit)),
+                None => Outcome::Error((
+                    Status::Conflict,
+                    ValidationGuardError::Parse(ExclusiveError::Contended),
+                )),
+            },
+        }
+    }
+}
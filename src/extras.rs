@@ -0,0 +1,117 @@
+//! Rules for extra fields captured by a `#[serde(flatten)]` map
+
+use crate::ValueRules;
+use rocket::serde::json::Value;
+use validator::ValidationError;
+
+///  Whether `key` matches `pattern`, in which `*` matches any run of characters
+fn matches(pattern: &str, key: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == key,
+        Some((prefix, rest)) => match key.strip_prefix(prefix) {
+            None => false,
+            Some(key) => (0..=key.len())
+                .filter(|at| key.is_char_boundary(*at))
+                .any(|at| matches(rest, &key[at..])),
+        },
+    }
+}
+
+///  Rules for the extra fields of a body, captured by a `#[serde(flatten)]` map and checked from a custom validation
+///  function
+///  ```rust
+///  use rocket::serde::{json::Value, Deserialize};
+///  use rocket_validation::{ExtraRules, Validate, ValueRule, ValueRules};
+///  use std::collections::HashMap;
+///  use validator::ValidationError;
+///
+///  fn check_extra(extra: &HashMap<String, Value>) -> Result<(), ValidationError> {
+///      ExtraRules::new()
+///          .max_count(10)
+///          .key_pattern("x-*")
+///          .values(ValueRules::new().at("", ValueRule::String))
+///          .check(extra)
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Event {
+///      name: String,
+///      #[serde(flatten)]
+///      #[validate(custom(function = "check_extra"))]
+///      extra: HashMap<String, Value>,
+///  }
+///  ```
+#[derive(Clone, Debug, Default)]
+pub struct ExtraRules {
+    max_count: Option<usize>,
+    key_patterns: Vec<String>,
+    values: Option<ValueRules>,
+}
+
+impl ExtraRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///  At most `max` extra fields
+    pub fn max_count(mut self, max: usize) -> Self {
+        self.max_count = Some(max);
+        self
+    }
+
+    ///  Adds a pattern keys may match, e.g. `x-*`, keys having to match one of the patterns once any is added
+    pub fn key_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.key_patterns.push(pattern.into());
+        self
+    }
+
+    ///  Rules checked against the value of every extra field
+    pub fn values(mut self, rules: ValueRules) -> Self {
+        self.values = Some(rules);
+        self
+    }
+
+    ///  Checks the count, then the keys and values in key order, failing on the first rule which doesn't hold
+    ///
+    ///  Too many fields fail with a `max_extra_fields` error holding the `max` and the `actual` count, keys matching
+    ///  no pattern with an `extra_field_key` error holding the `key` and the `patterns`. Errors of the value rules get
+    ///  the `key` of the field added
+    pub fn check<'a>(
+        &self,
+        extra: impl IntoIterator<Item = (&'a String, &'a Value)>,
+    ) -> Result<(), ValidationError> {
+        let mut fields: Vec<(&String, &Value)> = extra.into_iter().collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+
+        if let Some(max) = self.max_count.filter(|max| fields.len() > *max) {
+            let mut error = ValidationError::new("max_extra_fields");
+            error.add_param("max".into(), &max);
+            error.add_param("actual".into(), &fields.len());
+            return Err(error);
+        }
+
+        for (key, value) in fields {
+            if !self.key_patterns.is_empty()
+                && !self
+                    .key_patterns
+                    .iter()
+                    .any(|pattern| matches(pattern, key))
+            {
+                let mut error = ValidationError::new("extra_field_key");
+                error.add_param("key".into(), key);
+                error.add_param("patterns".into(), &self.key_patterns);
+                return Err(error);
+            }
+
+            if let Some(rules) = &self.values {
+                rules.check(value).map_err(|mut error| {
+                    error.add_param("key".into(), key);
+                    error
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+//! Logging of failed validations through the logger of rocket
+
+use crate::{trace::failed_fields, ValidationErrors};
+use rocket::request::Request;
+
+///  Level failed validations are logged at, `warn` when none is managed
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::FailureLogLevel;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .manage(FailureLogLevel(log::Level::Info))
+///  }
+///  ```
+#[derive(Clone, Copy, Debug)]
+pub struct FailureLogLevel(pub log::Level);
+
+///  Logs the name `type_name` of the type a guard failed to validate along the paths of its invalid fields, never their values
+pub(crate) fn log(req: &Request<'_>, type_name: &str, errors: &ValidationErrors) {
+    let level = req
+        .rocket()
+        .state::<FailureLogLevel>()
+        .map_or(log::Level::Warn, |level| level.0);
+
+    log::log!(
+        target: "rocket_validation",
+        level,
+        "validation of {} failed: fields={}",
+        type_name,
+        failed_fields(errors).join(",")
+    );
+}
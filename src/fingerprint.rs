@@ -0,0 +1,99 @@
+//! Fingerprints of the set of keys sent in json bodies, telling client versions apart
+
+use crate::body::LazyValue;
+use rocket::{request::Request, serde::json::Value};
+use std::{
+    collections::BTreeSet,
+    sync::{Mutex, PoisonError},
+};
+
+///  Once managed, every json guard records the `Fingerprint` of every body it reads
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .manage(rocket_validation::SchemaFingerprints)
+///  }
+///  ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SchemaFingerprints;
+
+///  Hash of the paths of the keys present in a json body, e.g. `address.zip` or `items[].name`
+///
+///  Values and the order of keys don't change the fingerprint, so it stays the same for every body a given client
+///  version sends. It is rendered as 16 hex digits and is stable across builds and platforms
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint(pub String);
+
+///  Request local storage of the fingerprint
+struct CachedFingerprint(Mutex<Option<Fingerprint>>);
+
+fn cached<'r>(req: &'r Request<'_>) -> &'r Mutex<Option<Fingerprint>> {
+    &req.local_cache(|| CachedFingerprint(Mutex::new(None))).0
+}
+
+impl Fingerprint {
+    ///  Fingerprint recorded for the body of `req`, e.g. from the `on_response` of a metrics fairing
+    pub fn of(req: &Request<'_>) -> Option<Fingerprint> {
+        cached(req)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn compute(value: &Value) -> Self {
+        let mut paths = BTreeSet::new();
+        collect(value, &mut String::new(), &mut paths);
+
+        // FNV-1a, fixed unlike the keys of std's hashers
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for path in &paths {
+            for byte in path.bytes().chain(Some(b'\n')) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+
+        Fingerprint(format!("{hash:016x}"))
+    }
+}
+
+fn collect(value: &Value, path: &mut String, paths: &mut BTreeSet<String>) {
+    let len = path.len();
+
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+                paths.insert(path.clone());
+                collect(value, path, paths);
+                path.truncate(len);
+            }
+        }
+        Value::Array(items) => {
+            path.push_str("[]");
+            for item in items {
+                collect(item, path, paths);
+            }
+            path.truncate(len);
+        }
+        _ => {}
+    }
+}
+
+///  Records the fingerprint of the body of `value` if `SchemaFingerprints` is managed
+pub(crate) fn record(req: &Request<'_>, value: &mut LazyValue<'_>) {
+    if req.rocket().state::<SchemaFingerprints>().is_none() {
+        return;
+    }
+
+    if let Some(value) = value.get() {
+        *cached(req).lock().unwrap_or_else(PoisonError::into_inner) =
+            Some(Fingerprint::compute(value));
+    }
+}
@@ -0,0 +1,73 @@
+//! Feature flags consulted by custom validators, toggled at runtime
+
+use crate::ValidationContext;
+use std::{
+    collections::HashMap,
+    sync::{PoisonError, RwLock},
+};
+
+///  Map of feature flags handed to custom validators as a `ValidationContext`
+///
+///  Flags which were never set are disabled
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{FeatureFlags, Validate, ValidatedWith};
+///  use validator::ValidationError;
+///
+///  fn strict_name(name: &str, flags: &FeatureFlags) -> Result<(), ValidationError> {
+///      if flags.is_enabled("strict_names") && !name.chars().all(char::is_alphanumeric) {
+///          return Err(ValidationError::new("strict_name"));
+///      }
+///      Ok(())
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = FeatureFlags)]
+///  pub struct Signup {
+///      #[validate(custom(function = "strict_name", use_context))]
+///      name: String,
+///  }
+///
+///  #[post("/signup", data = "<data>")]
+///  fn signup(data: ValidatedWith<Json<Signup>>) -> String {
+///      data.into_deep_inner().name
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![signup])
+///          .manage(FeatureFlags::default().with("strict_names", true))
+///  }
+///  ```
+#[derive(Debug, Default)]
+pub struct FeatureFlags(RwLock<HashMap<String, bool>>);
+
+impl FeatureFlags {
+    ///  Sets `flag` before managing the flags
+    pub fn with(self, flag: impl Into<String>, enabled: bool) -> Self {
+        self.set(flag, enabled);
+        self
+    }
+
+    ///  Toggles `flag`, affecting the requests validated from now on
+    pub fn set(&self, flag: impl Into<String>, enabled: bool) {
+        self.0
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(flag.into(), enabled);
+    }
+
+    pub fn is_enabled(&self, flag: &str) -> bool {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(flag)
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+impl ValidationContext for FeatureFlags {}
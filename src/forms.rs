@@ -0,0 +1,85 @@
+//! Failures of `Validated<T>` parsed as forms, collected for the catchers by the guards parsing them
+
+use crate::{cache_errors_of, form_errors, ValidationErrors};
+use rocket::{form, request::Request};
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+thread_local! {
+    ///  Failures of the forms parsed by the guard polled on this thread, `None` outside of `collect`
+    static FAILURES: RefCell<Option<Vec<(&'static str, ValidationErrors)>>> = RefCell::new(None);
+}
+
+///  Form errors of a `Validated<T>` failing to validate `D` with `errors`, kept for the guard parsing the form, if
+///  any, to cache them
+pub(crate) fn failure<'r, D: ?Sized>(errors: &ValidationErrors) -> form::Errors<'r> {
+    FAILURES.with(|failures| {
+        if let Some(failures) = failures.borrow_mut().as_mut() {
+            failures.push((std::any::type_name::<D>(), errors.clone()));
+        }
+    });
+
+    form_errors(errors).into()
+}
+
+///  Restores the failures collected by an outer `collect` once dropped, even if `parse` panicked
+struct Outer(Option<Vec<(&'static str, ValidationErrors)>>);
+
+impl Drop for Outer {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        FAILURES.with(|failures| failures.replace(outer));
+    }
+}
+
+///  Runs `parse`, caching the failures of the `Validated<T>` it parsed as forms for the catchers. Returns whether any
+///  failed along the result of `parse`
+pub(crate) fn collect<R>(req: &Request<'_>, parse: impl FnOnce() -> R) -> (R, bool) {
+    let outer = Outer(FAILURES.with(|failures| failures.replace(Some(Vec::new()))));
+    let parsed = parse();
+    let collected = FAILURES.with(|failures| failures.borrow_mut().take());
+    drop(outer);
+
+    let collected = collected.unwrap_or_default();
+    for (type_name, errors) in &collected {
+        cache_errors_of(req, type_name, errors);
+    }
+
+    (parsed, !collected.is_empty())
+}
+
+///  Future of a guard parsing a form, collecting the failures of the `Validated<T>` it parses on every poll
+pub(crate) struct Collect<'r, 'a, F> {
+    req: &'r Request<'a>,
+    future: F,
+    failed: bool,
+}
+
+impl<'r, 'a, F> Collect<'r, 'a, F> {
+    pub(crate) fn new(req: &'r Request<'a>, future: F) -> Self {
+        Collect {
+            req,
+            future,
+            failed: false,
+        }
+    }
+}
+
+impl<F: Future + Unpin> Future for Collect<'_, '_, F> {
+    type Output = (F::Output, bool);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let (poll, failed) = collect(this.req, || Pin::new(&mut this.future).poll(cx));
+        this.failed |= failed;
+
+        match poll {
+            Poll::Ready(output) => Poll::Ready((output, this.failed)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
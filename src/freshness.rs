@@ -0,0 +1,182 @@
+//! Guard rejecting stale or replayed signed requests before validating them
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+///  Storage of the nonces already seen by `ValidatedFresh`
+pub trait NonceStore: Send + Sync {
+    ///  Records `nonce` until the unix timestamp `expires`, returns `false` if it was already recorded
+    fn insert(&self, nonce: &str, expires: i64) -> bool;
+}
+
+///  In memory `NonceStore`, forgetting nonces once they expired
+#[derive(Debug, Default)]
+pub struct MemoryNonceStore(Mutex<HashMap<String, i64>>);
+
+impl NonceStore for MemoryNonceStore {
+    fn insert(&self, nonce: &str, expires: i64) -> bool {
+        let mut nonces = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let now = now();
+        nonces.retain(|_, expires| *expires >= now);
+
+        if nonces.contains_key(nonce) {
+            return false;
+        }
+
+        nonces.insert(nonce.to_owned(), expires);
+        true
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+///  Managed configuration of `ValidatedFresh`
+///
+///  Requests carry their unix timestamp, in seconds, in `X-Timestamp` and a unique nonce in `X-Nonce`. Nonces are
+///  kept in a `MemoryNonceStore` unless another store is given, e.g. one shared between instances
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(rocket_validation::Freshness::new(300))
+///  }
+///  ```
+pub struct Freshness {
+    window: i64,
+    timestamp_header: &'static str,
+    nonce_header: &'static str,
+    store: Box<dyn NonceStore>,
+}
+
+impl Freshness {
+    ///  Accepts timestamps at most `window` seconds away from now
+    pub fn new(window: u32) -> Self {
+        Freshness {
+            window: i64::from(window),
+            timestamp_header: "X-Timestamp",
+            nonce_header: "X-Nonce",
+            store: Box::new(MemoryNonceStore::default()),
+        }
+    }
+
+    ///  Names of the headers holding the timestamp and the nonce, instead of `X-Timestamp` and `X-Nonce`
+    pub fn headers(mut self, timestamp: &'static str, nonce: &'static str) -> Self {
+        self.timestamp_header = timestamp;
+        self.nonce_header = nonce;
+        self
+    }
+
+    ///  Keeps the nonces in `store` instead of a `MemoryNonceStore`
+    pub fn store(mut self, store: impl NonceStore + 'static) -> Self {
+        self.store = Box::new(store);
+        self
+    }
+
+    ///  Checks the timestamp then records the nonce of `req`
+    fn check<'r>(&self, req: &Request<'_>) -> Result<(), (Status, FreshnessError<'r>)> {
+        let headers = req.headers();
+
+        let timestamp = headers
+            .get_one(self.timestamp_header)
+            .and_then(|timestamp| timestamp.trim().parse::<i64>().ok())
+            .ok_or((Status::BadRequest, FreshnessError::MissingTimestamp))?;
+
+        // timestamps too far off to even compute their distance to now are stale as well
+        let stale = now()
+            .checked_sub(timestamp)
+            .and_then(i64::checked_abs)
+            .map_or(true, |age| age > self.window);
+        if stale {
+            return Err((Status::Unauthorized, FreshnessError::Stale));
+        }
+
+        let nonce = headers
+            .get_one(self.nonce_header)
+            .filter(|nonce| !nonce.is_empty())
+            .ok_or((Status::BadRequest, FreshnessError::MissingNonce))?;
+
+        // a nonce can't be replayed once its timestamp left the window
+        let expires = timestamp.saturating_add(self.window);
+        if !self.store.insert(nonce, expires) {
+            return Err((Status::Conflict, FreshnessError::Replayed));
+        }
+
+        Ok(())
+    }
+}
+
+///  Struct used for Request Guards checking the timestamp and the nonce of a signed request before validating it
+#[derive(Clone, Debug)]
+pub struct ValidatedFresh<T>(pub T);
+
+impl_into_inner!(ValidatedFresh);
+
+///  Errors of `ValidatedFresh` besides validation failures
+#[derive(Debug)]
+pub enum FreshnessError<'r> {
+    ///  `Freshness` isn't managed
+    Unmanaged,
+    ///  The timestamp header is absent or not a unix timestamp
+    MissingTimestamp,
+    ///  The timestamp is outside of the window
+    Stale,
+    ///  The nonce header is absent
+    MissingNonce,
+    ///  The nonce was already used
+    Replayed,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedFresh` for `Json`
+///
+///  Missing headers fail with `400 Bad Request`, stale timestamps with `401 Unauthorized` and replayed nonces with
+///  `409 Conflict`, all before the body is read
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for ValidatedFresh<Json<D>> {
+    type Error = ValidationGuardError<FreshnessError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let freshness = match req.rocket().state::<Freshness>() {
+            Some(freshness) => freshness,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(FreshnessError::Unmanaged),
+                ))
+            }
+        };
+
+        if let Err((status, err)) = freshness.check(req) {
+            return Outcome::Error((status, ValidationGuardError::Parse(err)));
+        }
+
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Success(Validated(data)) => Outcome::Success(ValidatedFresh(data)),
+            Outcome::Error((status, ValidationGuardError::Validation(err))) => {
+                Outcome::Error((status, ValidationGuardError::Validation(err)))
+            }
+            Outcome::Error((status, ValidationGuardError::Parse(err))) => Outcome::Error((
+                status,
+                ValidationGuardError::Parse(FreshnessError::Json(err)),
+            )),
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+        }
+    }
+}
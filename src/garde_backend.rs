@@ -0,0 +1,262 @@
+//! Guards validating with `garde` instead of `validator`
+
+use crate::{
+    body, cache_errors, errors::Segment, parse_errors, validation_outcome, ValidationErrors,
+    ValidationGuardError,
+};
+use garde::{Report, Validate};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    form::{self, Form, FromForm},
+    http::Status,
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Mutex,
+};
+use validator::{ValidationError, ValidationErrorsKind};
+
+///  Struct used for Request Guards validating with `garde` instead of `validator`, for `Json`, `Form` and anything
+///  implementing `FromRequest`
+///
+///  Validation runs with the `T::Context` managed by the application, or its default if none is. Errors are cached like
+///  the ones of `Validated`, so the catchers serve both: one `garde` error per failure keyed by its field as
+///  `validator` would, with the message of `garde` and the `path` of the field, e.g. `address.zip` or `tags[0]`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::ValidatedGarde;
+///
+///  #[derive(Debug, Deserialize, garde::Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct HelloData {
+///      #[garde(length(min = 3))]
+///      name: String,
+///  }
+///
+///  #[post("/hello", data = "<data>")]
+///  fn validated_hello(data: ValidatedGarde<Json<HelloData>>) -> String {
+///      data.into_deep_inner().name
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![validated_hello])
+///          .register("/", catchers![rocket_validation::validation_catcher])
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedGarde<T>(pub T);
+
+impl_into_inner!(ValidatedGarde);
+
+///  Impl to get type T of `Form`
+impl<T> ValidatedGarde<Form<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+///  Most field names of `garde` paths kept for the lifetime of the application, keys of dived maps being chosen by
+///  clients
+const MAX_FIELD_NAMES: usize = 4096;
+
+///  Field names of `garde` paths, which `ValidationErrors` only holds as `&'static str`
+static FIELD_NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+///  `field` as a `&'static str`, `None` once `MAX_FIELD_NAMES` different names are known
+fn field_name(field: &str) -> Option<&'static str> {
+    let mut names = FIELD_NAMES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(name) = names.get(field) {
+        return Some(*name);
+    }
+    if names.len() >= MAX_FIELD_NAMES {
+        return None;
+    }
+
+    let name: &'static str = Box::leak(field.to_string().into_boxed_str());
+    names.insert(name);
+    Some(name)
+}
+
+///  Segments of a `garde` path like `address.zip` or `tags[0].name`
+fn segments(path: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+
+    for part in path.split('.').filter(|part| !part.is_empty()) {
+        let (field, indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if !field.is_empty() {
+            segments.push(Segment::Field(field));
+        }
+        for index in indices.split(|c| c == '[' || c == ']') {
+            match index.parse() {
+                Ok(index) => segments.push(Segment::Index(index)),
+                Err(_) if index.is_empty() => {}
+                Err(_) => return Vec::new(),
+            }
+        }
+    }
+
+    segments
+}
+
+///  Adds `error` at `path` as `validator` would: under the field, nested fields in a `Struct` and elements of
+///  arrays in a `List`, an index at the end of the path being passed as the `index` param. Errors which can't be
+///  placed there, e.g. of the root of the value, go under `__all__`
+fn add_error(errors: &mut ValidationErrors, path: &[Segment<'_>], mut error: ValidationError) {
+    let (field, rest) = match path {
+        [Segment::Field(field), rest @ ..] => match field_name(field) {
+            Some(field) => (field, rest),
+            None => return errors.add("__all__", error),
+        },
+        _ => return errors.add("__all__", error),
+    };
+
+    let kind = match rest {
+        [] => ValidationErrorsKind::Field(Vec::new()),
+        [Segment::Index(index)] => {
+            error.add_param("index".into(), index);
+            ValidationErrorsKind::Field(Vec::new())
+        }
+        [Segment::Index(_), ..] => ValidationErrorsKind::List(BTreeMap::new()),
+        _ => ValidationErrorsKind::Struct(Box::new(ValidationErrors::new())),
+    };
+
+    let unplaced = match (errors.errors_mut().entry(field).or_insert(kind), rest) {
+        (ValidationErrorsKind::Field(known), [] | [Segment::Index(_)]) => {
+            known.push(error);
+            None
+        }
+        (ValidationErrorsKind::List(items), [Segment::Index(index), rest @ ..])
+            if !rest.is_empty() =>
+        {
+            let nested = items
+                .entry(*index)
+                .or_insert_with(|| Box::new(ValidationErrors::new()));
+            add_error(nested, rest, error);
+            None
+        }
+        (ValidationErrorsKind::Struct(nested), [Segment::Field(_), ..]) => {
+            add_error(nested, rest, error);
+            None
+        }
+        _ => Some(error),
+    };
+
+    // a field failing both as a value and as a struct keeps the later errors under `__all__`
+    if let Some(error) = unplaced {
+        errors.add("__all__", error);
+    }
+}
+
+///  Errors of a `garde` report keyed by the path of the failed field like the ones of `validator`, each a `garde`
+///  error with the message of `garde` and the full `path`
+fn report_errors(report: &Report) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+
+    for (path, error) in report.iter() {
+        let path = path.to_string();
+        let mut validation_error = ValidationError::new("garde");
+        validation_error.message = Some(error.message().to_string().into());
+        validation_error.add_param("path".into(), &path);
+        add_error(&mut errors, &segments(&path), validation_error);
+    }
+
+    errors
+}
+
+///  Validates `data` with the managed `T::Context`, or its default
+fn validate<T>(req: &Request<'_>, data: &T) -> Result<(), ValidationErrors>
+where
+    T: Validate,
+    T::Context: Default + Send + Sync + 'static,
+{
+    let result = match req.rocket().state::<T::Context>() {
+        Some(context) => data.validate_with(context),
+        None => data.validate_with(&T::Context::default()),
+    };
+
+    result.map_err(|report| report_errors(&report))
+}
+
+///  Implementation of `ValidatedGarde` for `Json`, failing like `Validated<Json<T>>`
+#[rocket::async_trait]
+impl<'r, D> FromData<'r> for ValidatedGarde<Json<D>>
+where
+    D: Validate + Deserialize<'r>,
+    D::Context: Default + Send + Sync + 'static,
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match body::read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let data = match body::parse_body::<D>(req, body) {
+            Ok(data) => data,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        validation_outcome::<D, _, _, _>(req, validate(req, &*data), ValidatedGarde(data))
+    }
+}
+
+///  Implementation of `ValidatedGarde` for `Form`, failing like `Validated<Form<T>>`
+#[rocket::async_trait]
+impl<'r, T> FromData<'r> for ValidatedGarde<Form<T>>
+where
+    T: Validate + FromForm<'r>,
+    T::Context: Default + Send + Sync + 'static,
+{
+    type Error = ValidationGuardError<form::Errors<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        match <Form<T> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Success(data) => {
+                validation_outcome::<T, _, _, _>(req, validate(req, &*data), ValidatedGarde(data))
+            }
+            Outcome::Error((status, errors)) => {
+                if status == Status::UnprocessableEntity {
+                    cache_errors::<T>(req, &parse_errors(&errors));
+                }
+                Outcome::Error((status, ValidationGuardError::Parse(errors)))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+        }
+    }
+}
+
+///  Implementation of `ValidatedGarde` for `FromRequest`
+#[rocket::async_trait]
+impl<'r, D> FromRequest<'r> for ValidatedGarde<D>
+where
+    D: Validate + FromRequest<'r>,
+    D::Context: Default + Send + Sync + 'static,
+{
+    type Error = ValidationGuardError<D::Error>;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match D::from_request(req).await {
+            Outcome::Success(data) => {
+                validation_outcome::<D, _, _, _>(req, validate(req, &data), ValidatedGarde(data))
+            }
+            Outcome::Error((status, err)) => {
+                Outcome::Error((status, ValidationGuardError::Parse(err)))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Guard deserializing the headers of the request into a struct before validating it
+
+use crate::{
+    cache_errors, failure_status, instrument, validation_outcome, Validate, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+    serde::{
+        de::{
+            self, value::MapAccessDeserializer, DeserializeOwned, DeserializeSeed,
+            IntoDeserializer, MapAccess, Unexpected, Visitor,
+        },
+        forward_to_deserialize_any,
+    },
+};
+use std::fmt::{self, Display};
+use validator::ValidationError;
+
+///  Struct used for Request Guards deserializing the headers of the request into `T` before validating it
+///
+///  Header names are matched case-insensitively with `-` standing for `_`, so `X-Api-Version` fills the
+///  `x_api_version` field, and only the first value of repeated headers is kept. Values are parsed into numbers and
+///  booleans for fields of those types
+///
+///  Missing headers fail with a `required` error keyed to their field, values which don't parse with an
+///  `invalid_header` error under `__all__` holding the `header`, both cached for the catchers and returned as the
+///  `HeaderError` of `ValidationGuardError::Parse`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{Validate, ValidatedHeaders};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct ClientHeaders {
+///      #[validate(range(min = 1, max = 3))]
+///      x_api_version: u8,
+///      #[validate(length(min = 8))]
+///      x_client_id: String,
+///  }
+///
+///  #[get("/")]
+///  fn index(headers: ValidatedHeaders<ClientHeaders>) -> String {
+///      headers.into_inner().x_client_id
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedHeaders<T>(pub T);
+
+///  Impl to get type T
+impl<T> ValidatedHeaders<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+///  Errors of `ValidatedHeaders` deserializing the headers, before validation
+#[derive(Debug)]
+pub enum HeaderError {
+    ///  No header fills the field
+    Missing(&'static str),
+    ///  The value of `header` doesn't parse into its field
+    Invalid {
+        ///  Name of the header, as sent
+        header: Option<String>,
+        ///  Why the value doesn't parse
+        message: String,
+    },
+}
+
+impl Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderError::Missing(field) => write!(f, "missing header `{field}`"),
+            HeaderError::Invalid { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for HeaderError {}
+
+impl de::Error for HeaderError {
+    fn custom<M: Display>(message: M) -> Self {
+        HeaderError::Invalid {
+            header: None,
+            message: message.to_string(),
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        HeaderError::Missing(field)
+    }
+}
+
+impl HeaderError {
+    ///  Errors cached for the catchers
+    fn validation_errors(&self) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+
+        match self {
+            HeaderError::Missing(field) => errors.add(field, ValidationError::new("required")),
+            HeaderError::Invalid { header, message } => {
+                let mut error = ValidationError::new("invalid_header");
+                if let Some(header) = header {
+                    error.add_param("header".into(), header);
+                }
+                error.message = Some(message.clone().into());
+                errors.add("__all__", error);
+            }
+        }
+
+        errors
+    }
+}
+
+///  Value of a header, parsed according to the type of its field
+struct HeaderValue<'a>(&'a str);
+
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, HeaderError> {
+                match self.0.trim().parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(de::Error::invalid_value(Unexpected::Str(self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for HeaderValue<'a> {
+    type Error = HeaderError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, HeaderError> {
+        visitor.visit_str(self.0)
+    }
+
+    parse_value! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, HeaderError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, HeaderError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, HeaderError> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+///  Headers of the request as a map of their field names to their values
+struct HeaderMap<'a> {
+    headers: std::vec::IntoIter<(String, &'a str, &'a str)>,
+    value: Option<(&'a str, &'a str)>,
+}
+
+impl<'de, 'a> MapAccess<'de> for HeaderMap<'a> {
+    type Error = HeaderError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, HeaderError> {
+        match self.headers.next() {
+            Some((field, header, value)) => {
+                self.value = Some((header, value));
+                seed.deserialize(field.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, HeaderError> {
+        let (header, value) = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before its header"))?;
+
+        seed.deserialize(HeaderValue(value))
+            .map_err(|err| match err {
+                HeaderError::Invalid {
+                    header: None,
+                    message,
+                } => HeaderError::Invalid {
+                    header: Some(header.to_owned()),
+                    message,
+                },
+                err => err,
+            })
+    }
+}
+
+///  Deserializes the headers of `req`, keeping the first value of each
+fn deserialize<T: DeserializeOwned>(req: &Request<'_>) -> Result<T, HeaderError> {
+    let mut headers: Vec<(String, &str, &str)> = Vec::new();
+
+    for header in req.headers().iter() {
+        let field = header
+            .name()
+            .as_str()
+            .to_ascii_lowercase()
+            .replace('-', "_");
+
+        if !headers.iter().any(|(known, _, _)| *known == field) {
+            headers.push((field, header.name().as_str(), header.value()));
+        }
+    }
+
+    T::deserialize(MapAccessDeserializer::new(HeaderMap {
+        headers: headers.into_iter(),
+        value: None,
+    }))
+}
+
+#[rocket::async_trait]
+impl<'r, T: Validate + DeserializeOwned> FromRequest<'r> for ValidatedHeaders<T> {
+    type Error = ValidationGuardError<HeaderError>;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let data = match deserialize::<T>(req) {
+            Ok(data) => data,
+            Err(err) => {
+                let errors = err.validation_errors();
+                cache_errors::<T>(req, &errors);
+                return Outcome::Error((
+                    failure_status(req, &errors),
+                    ValidationGuardError::Parse(err),
+                ));
+            }
+        };
+
+        let validated = instrument::validate(&data);
+        validation_outcome::<T, _, _, _>(req, validated, ValidatedHeaders(data))
+    }
+}
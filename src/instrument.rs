@@ -0,0 +1,37 @@
+//! Validation run by the guards, traced if the `tracing` feature is enabled
+
+use crate::{Validate, ValidationErrors};
+
+///  Validates `data` within a `rocket_validation::validate` span carrying the name of its type, with events when
+///  validation starts, succeeds or fails. Failures are `warn`-level events holding the number of field errors
+#[cfg(feature = "tracing")]
+pub(crate) fn validate<T: Validate + ?Sized>(data: &T) -> Result<(), ValidationErrors> {
+    let span = tracing::info_span!(
+        target: "rocket_validation",
+        "rocket_validation::validate",
+        r#type = std::any::type_name::<T>()
+    );
+    let _entered = span.enter();
+
+    tracing::trace!(target: "rocket_validation", "validating");
+    let outcome = data.validate();
+
+    match &outcome {
+        Ok(_) => tracing::debug!(target: "rocket_validation", "validation succeeded"),
+        Err(errors) => {
+            let mut count = 0usize;
+            crate::errors::walk(errors, &mut |_, _| count += 1);
+
+            tracing::warn!(target: "rocket_validation", errors = count, "validation failed");
+        }
+    }
+
+    outcome
+}
+
+///  Validates `data`
+#[cfg(not(feature = "tracing"))]
+#[inline]
+pub(crate) fn validate<T: Validate + ?Sized>(data: &T) -> Result<(), ValidationErrors> {
+    data.validate()
+}
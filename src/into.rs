@@ -0,0 +1,76 @@
+//! Guard mapping the validated body to another type before handing it to the handler
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    request::Request,
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+use std::marker::PhantomData;
+
+///  Struct used for Request Guards validating `T` and converting it into `O`, e.g. a DTO of the response
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize, Serialize};
+///  use rocket_validation::{Validate, ValidatedInto};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct NewUser {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[derive(Debug, Serialize)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct User {
+///      name: String,
+///      active: bool,
+///  }
+///
+///  impl From<NewUser> for User {
+///      fn from(new: NewUser) -> Self {
+///          User { name: new.name, active: true }
+///      }
+///  }
+///
+///  #[post("/users", data = "<data>")]
+///  fn create_user(data: ValidatedInto<Json<NewUser>, User>) -> Json<User> {
+///      Json(data.into_inner())
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().mount("/", routes![create_user])
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedInto<T, O>(pub O, PhantomData<fn() -> T>);
+
+///  Impl to get type O
+impl<T, O> ValidatedInto<T, O> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> O {
+        self.0
+    }
+}
+
+///  Implementation of `ValidatedInto` for `Json`
+#[rocket::async_trait]
+impl<'r, D, O> FromData<'r> for ValidatedInto<Json<D>, O>
+where
+    D: Validate + Deserialize<'r>,
+    O: From<D>,
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        <Validated<Json<D>> as FromData<'r>>::from_data(req, data)
+            .await
+            .map(|Validated(Json(data))| ValidatedInto(O::from(data), PhantomData))
+    }
+}
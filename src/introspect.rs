@@ -0,0 +1,51 @@
+//! Names serde hands to deserializers, read without any input
+
+use rocket::serde::{
+    de::{self, value, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+
+///  Deserializer only recording the names a type hands to `deserialize_struct` or `deserialize_enum`
+struct Introspect<'a>(&'a mut &'static [&'static str]);
+
+impl<'de, 'a> Deserializer<'de> for Introspect<'a> {
+    type Error = value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("neither a struct nor an enum"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("introspected"))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = variants;
+        Err(de::Error::custom("introspected"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map identifier ignored_any
+    }
+}
+
+///  Fields of the struct `D` in declaration order, after renaming, or variants of the enum `D`
+///
+///  Empty if `D` is deserialized as neither, e.g. structs using `#[serde(flatten)]` or untagged enums
+pub(crate) fn declared_names<'de, D: Deserialize<'de>>() -> &'static [&'static str] {
+    let mut names: &'static [&'static str] = &[];
+    let _ = D::deserialize(Introspect(&mut names));
+    names
+}
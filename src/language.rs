@@ -0,0 +1,95 @@
+//! Guard checking the language of a body matches its `Content-Language` header
+
+use crate::{validation_failure, Validate, Validated, ValidationErrors, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use std::borrow::Cow;
+use validator::ValidationError;
+
+///  Bodies carrying the language checked by `ValidatedLanguage`
+pub trait Languaged {
+    ///  Field reported when the language doesn't match the `Content-Language` header
+    const FIELD: &'static str = "language";
+
+    ///  Language tag of the content, e.g. `de-CH`
+    fn language(&self) -> &str;
+}
+
+///  Struct used for Request Guards validating the body and checking its language is one of the `Content-Language`
+///  header
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Languaged, Validate, ValidatedLanguage};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Article {
+///      #[validate(length(min = 2))]
+///      language: String,
+///      #[validate(length(min = 1))]
+///      title: String,
+///  }
+///
+///  impl Languaged for Article {
+///      fn language(&self) -> &str {
+///          &self.language
+///      }
+///  }
+///
+///  #[post("/articles", data = "<data>")]
+///  fn publish(data: ValidatedLanguage<Json<Article>>) -> String {
+///      data.into_deep_inner().title
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedLanguage<T>(pub T);
+
+impl_into_inner!(ValidatedLanguage);
+
+///  Whether `language` is one of the comma separated tags of `header`, ignoring case
+fn declared(header: &str, language: &str) -> bool {
+    header
+        .split(',')
+        .any(|tag| tag.trim().eq_ignore_ascii_case(language.trim()))
+}
+
+///  Implementation of `ValidatedLanguage` for `Json`
+///
+///  A language missing from the `Content-Language` header, or a missing header, fails validation with a
+///  `language_mismatch` error on `Languaged::FIELD`, with the header as `content_language` param
+#[rocket::async_trait]
+impl<'r, D: Validate + Languaged + rocket::serde::Deserialize<'r>> FromData<'r>
+    for ValidatedLanguage<Json<D>>
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Success(Validated(data)) => {
+                let header = req.headers().get_one("Content-Language");
+
+                if header.map_or(false, |header| declared(header, data.language())) {
+                    return Outcome::Success(ValidatedLanguage(data));
+                }
+
+                let mut error = ValidationError::new("language_mismatch");
+                error.message = Some(Cow::from("language must match the Content-Language header"));
+                error.add_param(Cow::from("value"), &data.language());
+                if let Some(header) = header {
+                    error.add_param(Cow::from("content_language"), &header);
+                }
+
+                let mut err = ValidationErrors::new();
+                err.add(D::FIELD, error);
+
+                Outcome::Error(validation_failure::<D, _>(req, err))
+            }
+            outcome => outcome.map(|Validated(data)| ValidatedLanguage(data)),
+        }
+    }
+}
@@ -72,6 +72,37 @@
 //!         .register("/", catchers![rocket_validation::validation_catcher])
 //! }
 //! ```
+//!
+//! ## Cargo features
+//!
+//! - `audit`: `AuditLog` fairing emitting an `AuditEntry` with the outcome and failed fields of every validated request
+//! - `cache`: `ValidationCache` which, once managed, skips validating bodies whose outcome is already known
+//! - `cbor`: `Validated<Cbor<T>>` validating CBOR bodies like `Validated<Json<T>>` does json ones
+//! - `csv`: `validation_catcher_csv` returning validation errors as `text/csv` rows of `field,code,message`
+//! - `digest`: `ValidatedDigest` guard checking a `Digest: sha-256=...` header against the body before validating it
+//! - `echo_input`: `validation_catcher_echo` sending back the (capped) body of invalid `Validated<Json<T>>` requests as
+//!   `received`. Echoed bodies may hold secrets, only enable it in development builds
+//! - `enums`: `CaseInsensitive` enums matching variants regardless of case, with `known_variant` suggesting the closest variants to unknown values
+//! - `garde`: `ValidatedGarde` guards for `Json`, `Form` and `FromRequest` validating with `garde` instead of
+//!   `validator`, their errors cached for the same catchers. `garde` needs a newer toolchain than the `rust-version` of
+//!   this crate, the one of its own `rust-version`
+//! - `locale`: `ValidatedLocalized` guard parsing numbers and dates formatted in the locale of the request before validating them
+//! - `log_failures`: failed validations are logged through the logger of rocket with the name of the validated type and
+//!   the paths of the invalid fields, never their values, at `warn` or the managed `FailureLogLevel`
+//! - `okapi`: enables `schemars`, with `error_schema` describing the error responses of the catchers for OpenAPI
+//!   documents, `validation_response` adding them to one and `document_validated_routes` to the operations of the
+//!   routes given as using validated guards
+//! - `parser_errors`: the `parse` errors `Validated<Json<T>>` caches for bodies it couldn't deserialize hold the `path` of
+//!   the field serde failed at, e.g. `address.zip`
+//! - `problem_details`: `validation_catcher_problem` returning validation errors as RFC 7807 `application/problem+json`
+//!   documents to clients asking for them in their `Accept` header
+//! - `schemars`: `ValidatedTyped` guard adding the Json Schema type of failed fields as the `field_type` param of their errors,
+//!   `ValidatedStrict` guard rejecting properties the Json Schema of the body doesn't declare
+//! - `tracing`: guards validate within a `rocket_validation::validate` span carrying the name of the validated type,
+//!   failures emitting a `warn` event with the number of field errors
+//! - `unicode`: `NormalizeNfc` which, once managed, normalizes strings of json bodies to NFC before validating them
+//! - `msgpack`: `Validated<MsgPack<T>>` validating MessagePack bodies like `Validated<Json<T>>` does json ones
+//! - `ref_cycles`: json guards reject bodies whose `{"$ref": "#/..."}` references are cyclic before deserializing them
 #![deny(clippy::all, clippy::cargo)]
 #![forbid(unsafe_code)]
 
@@ -82,6 +113,8 @@ pub extern crate validator;
 #[macro_use]
 extern crate rocket;
 
+#[cfg(feature = "msgpack")]
+use rocket::serde::msgpack::MsgPack;
 use rocket::{
     data::{Data, FromData, Outcome as DataOutcome},
     form,
@@ -89,10 +122,230 @@ use rocket::{
     http::Status,
     outcome::Outcome,
     request::{FromRequest, Request},
+    response::{self, Responder},
     serde::{json::Json, Serialize},
 };
-use std::fmt::Debug;
-pub use validator::{Validate, ValidationErrors};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::{Mutex, PoisonError},
+};
+pub use validator::{Validate, ValidateArgs, ValidationErrors};
+
+///  Implements `into_deep_inner` for the `Json` bodies and `into_inner` of a guard wrapping its data like `Validated`
+macro_rules! impl_into_inner {
+    ($guard:ident) => {
+        ///  Impl to get type T of `Json`
+        impl<T> $guard<rocket::serde::json::Json<T>> {
+            ///  Consumes the guard, returning the validated data
+            #[inline]
+            pub fn into_deep_inner(self) -> T {
+                self.0 .0
+            }
+        }
+
+        ///  Impl to get type T
+        impl<T> $guard<T> {
+            ///  Consumes the guard, returning the data it wraps
+            #[inline]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+    };
+}
+
+mod asynchronous;
+#[cfg(feature = "audit")]
+mod audit;
+mod body;
+#[cfg(feature = "cache")]
+mod cache;
+mod catchers;
+#[cfg(feature = "cbor")]
+mod cbor;
+mod coerce;
+mod combined;
+mod composite;
+mod config;
+mod content_type;
+mod context;
+mod csrf;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "ref_cycles")]
+mod cycles;
+mod decimals;
+mod dedup;
+mod defaults;
+mod dependencies;
+mod deprecation;
+#[cfg(feature = "digest")]
+mod digest;
+mod each;
+#[cfg(feature = "echo_input")]
+mod echo;
+#[cfg(feature = "enums")]
+mod enums;
+mod errors;
+mod evolution;
+mod exclusive;
+mod extras;
+#[cfg(feature = "log_failures")]
+mod failures;
+mod fingerprint;
+mod flags;
+mod forms;
+mod freshness;
+#[cfg(feature = "garde")]
+mod garde_backend;
+mod headers;
+mod instrument;
+mod into;
+mod introspect;
+mod language;
+mod limits;
+#[cfg(feature = "locale")]
+mod locale;
+mod methods;
+mod monotonic;
+mod multi_status;
+mod optional;
+mod order;
+mod patch;
+mod path;
+#[cfg(feature = "problem_details")]
+mod problem;
+mod provided;
+mod query;
+mod quota;
+mod references;
+mod region;
+mod role;
+#[cfg(feature = "okapi")]
+mod schema;
+mod sensitive;
+mod shared;
+mod slugs;
+#[cfg(feature = "schemars")]
+mod strict;
+mod tenant;
+mod trace;
+mod transform;
+mod transient;
+mod transitions;
+#[cfg(feature = "schemars")]
+mod typed;
+#[cfg(feature = "unicode")]
+mod unicode;
+mod unique;
+mod value_rules;
+mod variant;
+mod version;
+mod warnings;
+mod window;
+mod wordlist;
+
+pub use asynchronous::{ValidateAsync, ValidatedAsync};
+#[cfg(feature = "audit")]
+pub use audit::{AuditEntry, AuditLog, AuditSink};
+#[cfg(feature = "cache")]
+pub use cache::ValidationCache;
+pub use catchers::{
+    validation_catcher_ajv, validation_catcher_flat, validation_catcher_grouped,
+    validation_catcher_handler, validation_catcher_json_api, validation_catcher_responder,
+    validation_catcher_structured, validation_catcher_unavailable,
+    validation_catcher_upgrade_required, validation_catchers, AjvError, AjvErrors, ErrorHandler,
+    ErrorResponder, FlatError, GroupedError, JsonApiError, JsonApiErrors, JsonApiSource,
+    StructuredError, ValidationErrorResponder,
+};
+#[cfg(feature = "cbor")]
+pub use cbor::{Cbor, CborError};
+pub use coerce::{Coerced, ValidatedCoerced};
+pub use combined::{Source, Sourced, ValidatedCombined};
+pub use composite::{Composite, CompositeError};
+pub use config::{ValidationConfig, ValidationMessages};
+pub use content_type::{AllowedContentTypes, ContentTypeError, ValidatedContentType};
+pub use context::{ContextError, ValidatedWith, ValidationContext};
+pub use csrf::{CsrfError, CsrfStore, CsrfTokens, ValidatedCsrf};
+#[cfg(feature = "csv")]
+pub use csv::validation_catcher_csv;
+pub use decimals::{max_precision, max_scale};
+pub use dedup::{DedupKey, Deduplicated, ValidatedDedup};
+pub use defaults::ValidatedWithDefaults;
+pub use dependencies::{Dependent, ValidatedDependencies};
+pub use deprecation::{
+    Deprecated, DeprecatedFields, DeprecationNotice, Deprecations, ValidatedDeprecated,
+};
+#[cfg(feature = "digest")]
+pub use digest::{DigestError, ValidatedDigest};
+pub use each::ValidatedEach;
+#[cfg(feature = "echo_input")]
+pub use echo::{validation_catcher_echo, EchoError, EchoLimit, Received};
+#[cfg(feature = "enums")]
+pub use enums::{known_variant, CaseInsensitive};
+pub use errors::flatten_errors;
+pub use evolution::{
+    BackwardCompatible, Compatibility, CompatibilityError, Evolving, SchemaVersions,
+    ValidatedCompatible,
+};
+pub use exclusive::{ExclusiveError, Permit, Resource, ResourceLocks, ValidatedExclusive};
+pub use extras::ExtraRules;
+#[cfg(feature = "log_failures")]
+pub use failures::FailureLogLevel;
+pub use fingerprint::{Fingerprint, SchemaFingerprints};
+pub use flags::FeatureFlags;
+pub use freshness::{Freshness, FreshnessError, MemoryNonceStore, NonceStore, ValidatedFresh};
+#[cfg(feature = "garde")]
+pub use garde_backend::ValidatedGarde;
+pub use headers::{HeaderError, ValidatedHeaders};
+pub use into::ValidatedInto;
+pub use language::{Languaged, ValidatedLanguage};
+pub use limits::{MaxBodySize, MaxErrors, MaxStringLen};
+#[cfg(feature = "locale")]
+pub use locale::{Localized, ValidatedLocalized};
+pub use methods::{MethodFields, ValidatedMethod};
+pub use monotonic::{MonotonicError, TimestampStore, Timestamped, Timestamps, ValidatedMonotonic};
+pub use multi_status::{validate_each, ItemStatus, MultiStatus, MultiStatusBody};
+pub use optional::{OptionContext, ValidatedOption};
+pub use order::ValidatedOrdered;
+pub use patch::Patch;
+pub use path::{PathParam, PathParamError};
+#[cfg(feature = "problem_details")]
+pub use problem::{validation_catcher_problem, ProblemDetails};
+pub use provided::ValidatedProvided;
+pub use query::QueryParams;
+pub use quota::{MemoryQuotaStore, Quota, QuotaError, QuotaStore, ValidatedQuota};
+pub use references::{ReferenceStore, References};
+pub use region::{ClientRegion, Regions};
+pub use role::{Privileged, PrivilegedFields, RoleError, ValidatedRole};
+#[cfg(feature = "okapi")]
+pub use schema::{
+    document_validated_routes, error_schema, validation_response, ERROR_SCHEMA_VERSION,
+};
+pub use sensitive::{Sensitive, ValidatedSensitive};
+pub use shared::{Shared, ValidatedShared};
+pub use slugs::ErrorSlugs;
+#[cfg(feature = "schemars")]
+pub use strict::ValidatedStrict;
+pub use tenant::{Tenant, Tenants};
+pub use trace::{TraceId, TraceIds};
+pub use transform::{Transform, Transforms, ValidatedTransform};
+pub use transient::{transient, RetryAfter, TRANSIENT_CODE};
+pub use transitions::Transitions;
+#[cfg(feature = "schemars")]
+pub use typed::ValidatedTyped;
+#[cfg(feature = "unicode")]
+pub use unicode::NormalizeNfc;
+pub use unique::ValidatedUnique;
+pub use value_rules::{ValueRule, ValueRules};
+pub use variant::{ValidatedVariant, VariantError, Variants};
+pub use version::{MinClientVersion, ValidatedVersion, VersionError};
+pub use warnings::{ValidateWithWarnings, ValidatedWithWarnings, Warned};
+pub use window::{TimeWindow, ValidatedWindow, WindowError};
+pub use wordlist::{filtered, Wordlist};
 
 ///  Struct used for Request Guards
 #[derive(Clone, Debug)]
@@ -100,27 +353,166 @@ pub struct Validated<T>(pub T);
 
 ///  Impl to get type T of `Json`
 impl<T> Validated<Json<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T of `MsgPack`
+#[cfg(feature = "msgpack")]
+impl<T> Validated<MsgPack<T>> {
+    ///  Consumes the guard, returning the validated data
     #[inline]
     pub fn into_deep_inner(self) -> T {
         self.0 .0
     }
 }
 
+///  Impl to get type T of `Form`
+impl<T> Validated<form::Form<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
 ///  Impl to get type T
 impl<T> Validated<T> {
+    ///  Consumes the guard, returning the data it wraps
     #[inline]
     pub fn into_inner(self) -> T {
         self.0
     }
 }
 
+///  Derefs to the wrapped `T`, so a `Validated<Json<T>>` derefs once to `Json<T>`, whose own `Deref` reaches `T`, e.g.
+///  `data.name` on a `Validated<Json<User>>`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Validate, Validated};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct User {
+///      #[validate(length(min = 3))]
+///      name: String,
+///  }
+///
+///  #[post("/users", data = "<user>")]
+///  fn create(user: Validated<Json<User>>) -> String {
+///      let _: &Json<User> = &*user;
+///      format!("created {}", user.name)
+///  }
+///  ```
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Validated<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+///  Responds with `T`, so a `Validated<Json<T>>` can be returned as is
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize, Serialize};
+///  use rocket_validation::{Validate, Validated};
+///
+///  #[derive(Debug, Deserialize, Serialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct HelloData {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[post("/hello", data = "<data>")]
+///  fn validated_hello(data: Validated<Json<HelloData>>) -> Validated<Json<HelloData>> {
+///      data
+///  }
+///  ```
+impl<'r, 'o: 'r, T: Responder<'r, 'o>> Responder<'r, 'o> for Validated<T> {
+    #[inline]
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        self.0.respond_to(req)
+    }
+}
+
+///  Error of the guards of the crate, `Parse` holding the error of the data or guard `T` wraps, e.g.
+///  `rocket::serde::json::Error` for `Validated<Json<T>>`, and `Validation` the errors of invalid data
+///  ```rust
+///  use rocket::serde::json;
+///  use rocket_validation::{ValidationErrors, ValidationGuardError};
+///
+///  fn describe(err: &ValidationGuardError<json::Error<'_>>) -> String {
+///      match err {
+///          ValidationGuardError::Parse(err) => format!("malformed body: {err}"),
+///          ValidationGuardError::Validation(errors) => format!("{} invalid fields", errors.errors().len()),
+///      }
+///  }
+///  ```
+#[derive(Debug)]
+pub enum ValidationGuardError<E> {
+    ///  The data couldn't be read or parsed, or the guard rejected it before validating it
+    Parse(E),
+    ///  The data failed validation
+    Validation(ValidationErrors),
+}
+
+impl<E> ValidationGuardError<E> {
+    ///  Errors of the failed validation, `None` for `Parse` errors
+    pub fn validation_errors(&self) -> Option<&ValidationErrors> {
+        match self {
+            ValidationGuardError::Parse(_) => None,
+            ValidationGuardError::Validation(errors) => Some(errors),
+        }
+    }
+
+    ///  Wraps the error of `Parse` with `f`, for guards whose error holds the one of a guard they build upon
+    pub(crate) fn map_parse<F>(self, f: impl FnOnce(E) -> F) -> ValidationGuardError<F> {
+        match self {
+            ValidationGuardError::Parse(err) => ValidationGuardError::Parse(f(err)),
+            ValidationGuardError::Validation(errors) => ValidationGuardError::Validation(errors),
+        }
+    }
+}
+
 ///  Struct representing errors sent by the catcher
+///
+///  `errors` holds every failed rule of a field, so a field breaking several rules lists all of them, and merges the
+///  errors of every failed guard of the request. `by_type` is only sent when several guards of the request failed,
+///  e.g. an `Option` guard before the one failing the request, and `trace_id` when `TraceIds` is managed
 #[derive(Serialize)]
+#[cfg_attr(feature = "okapi", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "okapi", schemars(rename = "ValidationErrorResponse"))]
 #[serde(crate = "rocket::serde")]
 pub struct Error<'a> {
+    ///  Status of the response
     code: u128,
+    ///  Reason of the status
     message: &'a str,
-    errors: Option<&'a ValidationErrors>,
+    ///  Failed rules by field, `null` if the request failed before being validated
+    #[cfg_attr(feature = "okapi", schemars(schema_with = "schema::errors_schema"))]
+    errors: Option<Cow<'a, ValidationErrors>>,
+    ///  Failed rules by field of every failed guard, by the name of the type it validated, only sent when several
+    ///  guards failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "okapi", schemars(schema_with = "schema::by_type_schema"))]
+    by_type: Option<BTreeMap<&'static str, Cow<'a, ValidationErrors>>>,
+    ///  Id of the request in the logs, with `TraceIds` managed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_id: Option<&'a str>,
 }
 
 ///  Catcher to return validation errors to the client
@@ -137,17 +529,227 @@ pub struct Error<'a> {
 pub fn validation_catcher<'a>(req: &'a Request) -> Json<Error<'a>> {
     Json(Error {
         code: 422,
-        message: "Unprocessable Entity. The request was well-formed but was unable to be followed \
-                  due to semantic errors.",
-        errors: req.local_cache(|| CachedValidationErrors(None)).0.as_ref(),
+        message: config::validation_message(req),
+        errors: cached_errors(req),
+        by_type: cached_by_type(req),
+        trace_id: TraceId::of(req).map(|trace_id| trace_id.0.as_str()),
     })
 }
 
-///  Wrapper used to store `ValidationErrors` within the scope of the request
-#[derive(Clone)]
-pub struct CachedValidationErrors(pub Option<ValidationErrors>);
+///  Message sent along validation errors by the catchers
+pub(crate) const VALIDATION_MESSAGE: &str = "Unprocessable Entity. The request was well-formed \
+                                             but was unable to be followed due to semantic errors.";
+
+///  Form errors for `errors`, one per failed rule named by the dotted path of its field, with the message of the rule,
+///  falling back to its code, and the invalid value when `validator` provides it
+pub(crate) fn form_errors<'r>(errors: &ValidationErrors) -> Vec<form::Error<'r>> {
+    let mut form_errors = Vec::new();
+
+    errors::walk(errors, &mut |path, error| {
+        let message = error.message.clone().unwrap_or_else(|| error.code.clone());
+        let value = error.params.get("value").map(|value| match value {
+            serde_json::Value::String(value) => Cow::Owned(value.clone()),
+            value => Cow::Owned(value.to_string()),
+        });
+
+        form_errors.push(form::Error {
+            name: Some(errors::dotted(path).into()),
+            kind: form::error::ErrorKind::Validation(message),
+            value,
+            entity: form::error::Entity::Value,
+        });
+    });
+
+    form_errors
+}
+
+///  Validation errors for the form `errors` of fields rocket failed to parse, one per error under `__all__` with the
+///  `field` it names, a code for its kind, e.g. `required` for missing fields, its message and the invalid `value`
+pub(crate) fn parse_errors(errors: &form::Errors<'_>) -> ValidationErrors {
+    parse_errors_of(errors.iter())
+}
+
+///  Validation errors for the form `errors`, see `parse_errors`
+pub(crate) fn parse_errors_of<'a, 'v: 'a>(
+    errors: impl Iterator<Item = &'a form::Error<'v>>,
+) -> ValidationErrors {
+    use form::error::ErrorKind;
+
+    let mut validation_errors = ValidationErrors::new();
+
+    for error in errors {
+        let code = match &error.kind {
+            ErrorKind::Missing => "required",
+            ErrorKind::InvalidLength { .. } => "length",
+            ErrorKind::InvalidChoice { .. } => "choice",
+            ErrorKind::OutOfRange { .. } => "range",
+            ErrorKind::Duplicate => "duplicate",
+            ErrorKind::Unexpected => "unexpected",
+            _ => "invalid",
+        };
+
+        let mut validation_error = validator::ValidationError::new(code);
+        validation_error.message = Some(error.kind.to_string().into());
+        if let Some(name) = &error.name {
+            validation_error.add_param("field".into(), &name.to_string());
+        }
+        if let Some(value) = &error.value {
+            validation_error.add_param("value".into(), value);
+        }
+
+        validation_errors.add("__all__", validation_error);
+    }
+
+    validation_errors
+}
+
+///  Errors cached by the failed guards of the request merged together, if any, with the managed `ErrorSlugs`, the
+///  known field types and the masking of values applied
+pub(crate) fn cached_errors<'r>(req: &'r Request<'_>) -> Option<Cow<'r, ValidationErrors>> {
+    let mut by_type = req
+        .local_cache(CachedValidationErrors::default)
+        .by_type()
+        .into_iter();
+
+    let (_, mut errors) = by_type.next()?;
+    for (_, other) in by_type {
+        errors::merge(&mut errors, other);
+    }
+
+    Some(present(req, errors))
+}
+
+///  Errors cached by every failed guard of the request by the name of the type it validated, presented like
+///  `cached_errors`, `None` unless several guards failed
+pub(crate) fn cached_by_type<'r>(
+    req: &'r Request<'_>,
+) -> Option<BTreeMap<&'static str, Cow<'r, ValidationErrors>>> {
+    let by_type = req.local_cache(CachedValidationErrors::default).by_type();
+    if by_type.len() < 2 {
+        return None;
+    }
+
+    let mut presented = BTreeMap::new();
+    for (type_name, errors) in by_type {
+        if !presented.contains_key(type_name) {
+            presented.insert(type_name, present(req, errors));
+        }
+    }
+
+    Some(presented)
+}
+
+///  Applies the managed `ErrorSlugs`, the known field types and the masking of values to cached `errors`
+fn present<'r>(req: &'r Request<'_>, errors: ValidationErrors) -> Cow<'r, ValidationErrors> {
+    let errors = match req.rocket().state::<ErrorSlugs>() {
+        Some(slugs) => Cow::Owned(slugs.apply(&errors)),
+        None => Cow::Owned(errors),
+    };
+
+    #[cfg(feature = "schemars")]
+    let errors = typed::apply(req, errors);
+
+    sensitive::mask(req, errors)
+}
+
+///  Status of guards failing with `errors`, `503 Service Unavailable` if any error is transient, otherwise the one of
+///  the managed `ValidationConfig`
+pub(crate) fn failure_status(req: &Request<'_>, errors: &ValidationErrors) -> Status {
+    if transient::is_transient(errors) {
+        Status::ServiceUnavailable
+    } else {
+        req.rocket()
+            .state::<ValidationConfig>()
+            .map_or(Status::UnprocessableEntity, ValidationConfig::status)
+    }
+}
+
+///  Caches `errors` of a failed guard validating `D` for the catchers, after the ones of guards which failed before
+pub(crate) fn cache_errors<D: ?Sized>(req: &Request<'_>, errors: &ValidationErrors) {
+    cache_errors_of(req, std::any::type_name::<D>(), errors)
+}
+
+///  Caches `errors` of a failed guard validating the type named `type_name`, see `cache_errors`
+pub(crate) fn cache_errors_of(
+    req: &Request<'_>,
+    type_name: &'static str,
+    errors: &ValidationErrors,
+) {
+    let errors = limits::cap_errors(req, errors);
+    req.local_cache(CachedValidationErrors::default)
+        .push(type_name, errors.clone().into_owned());
+    trace::log_failure(req, &errors);
+
+    #[cfg(feature = "log_failures")]
+    failures::log(req, type_name, &errors);
+
+    #[cfg(feature = "audit")]
+    audit::record(req, Some(&errors));
+}
+
+///  Records a guard validated its data successfully
+#[inline]
+pub(crate) fn cache_success(_req: &Request<'_>) {
+    #[cfg(feature = "audit")]
+    audit::record(_req, None);
+}
+
+///  Outcome of a guard validating `D` once `validated`, succeeding with `guard` or failing with the cached errors
+pub(crate) fn validation_outcome<D: ?Sized, T, E, F>(
+    req: &Request<'_>,
+    validated: Result<(), ValidationErrors>,
+    guard: T,
+) -> Outcome<T, (Status, ValidationGuardError<E>), F> {
+    match validated {
+        Ok(_) => {
+            cache_success(req);
+            Outcome::Success(guard)
+        }
+        Err(err) => Outcome::Error(validation_failure::<D, _>(req, err)),
+    }
+}
+
+///  Failure of a guard validating `D` with `errors`, cached for the catchers
+pub(crate) fn validation_failure<D: ?Sized, E>(
+    req: &Request<'_>,
+    errors: ValidationErrors,
+) -> (Status, ValidationGuardError<E>) {
+    cache_errors::<D>(req, &errors);
+    (
+        failure_status(req, &errors),
+        ValidationGuardError::Validation(errors),
+    )
+}
+
+///  Wrapper used to store the `ValidationErrors` of every failed guard within the scope of the request, along the name
+///  of the type each guard validated
+#[derive(Debug, Default)]
+pub struct CachedValidationErrors(Mutex<Vec<(&'static str, ValidationErrors)>>);
+
+impl CachedValidationErrors {
+    ///  Errors of the failed guards by the name of the type they validated, in the order they failed
+    pub fn by_type(&self) -> Vec<(&'static str, ValidationErrors)> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn push(&self, type_name: &'static str, errors: ValidationErrors) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((type_name, errors));
+    }
+}
 
 ///  Implementation of `Validated` for `Json`
+///
+///  Bodies failing validation fail with `422 Unprocessable Entity`, rendered by `validation_catcher`. Malformed bodies
+///  and ones not matching `T` fail with `400 Bad Request`, caching a `parse` error under `__all__` with the message of
+///  serde and the `line` and `column` it failed at. Bodies exceeding the `json` data limit of rocket fail with `413
+///  Payload Too Large`, caching a `payload_too_large` error under `__all__` with the `limit` in bytes. Register
+///  `validation_catchers` to render all of them
 //
 ///  An example with `Json`
 ///  ```rust
@@ -178,21 +780,125 @@ pub struct CachedValidationErrors(pub Option<ValidationErrors>);
 ///  ```
 #[rocket::async_trait]
 impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for Validated<Json<D>> {
-    type Error = Result<ValidationErrors, rocket::serde::json::Error<'r>>;
+    type Error = ValidationGuardError<rocket::serde::json::Error<'r>>;
 
     async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
-        let data_outcome = <Json<D> as FromData<'r>>::from_data(req, data).await;
+        validate_json(req, data, |_, _| {}).await
+    }
+}
 
-        match data_outcome {
-            Outcome::Error((status, err)) => Outcome::Error((status, Err(err))),
-            Outcome::Forward(err) => Outcome::Forward(err),
-            Outcome::Success(data) => match data.validate() {
-                Ok(_) => Outcome::Success(Validated(data)),
-                Err(err) => {
-                    req.local_cache(|| CachedValidationErrors(Some(err.to_owned())));
-                    Outcome::Error((Status::UnprocessableEntity, Ok(err)))
+///  Pipeline of `Validated<Json<T>>`, handing the body to `inspect` once read, for guards building upon it which
+///  need to look at the raw body
+pub(crate) async fn validate_json<'r, D: Validate + rocket::serde::Deserialize<'r>>(
+    req: &'r Request<'_>,
+    data: Data<'r>,
+    inspect: fn(&Request<'_>, &str),
+) -> DataOutcome<'r, Validated<Json<D>>> {
+    let body = match body::read_body::<D>(req, data).await {
+        Ok(body) => body,
+        Err(failure) => return Outcome::Error(failure),
+    };
+
+    inspect(req, body);
+
+    match body::parse_body::<D>(req, body) {
+        Err(failure) => Outcome::Error(failure),
+        Ok(data) => {
+            #[cfg(feature = "cache")]
+            let validated = cache::validate(req, body, &*data);
+            #[cfg(not(feature = "cache"))]
+            let validated = instrument::validate(&*data);
+
+            validation_outcome::<D, _, _, _>(
+                req,
+                validated.and_then(|_| limits::check_body_size(req)),
+                Validated(data),
+            )
+        }
+    }
+}
+
+///  Implementation of `Validated` for `Form` bodies, url-encoded as well as multipart
+///
+///  Unlike `Form<Validated<T>>`, which reports failures as rocket's form errors, invalid forms are cached for the
+///  catchers and fail with the status of the managed `ValidationConfig`. `T` is validated once every field is pushed,
+///  whatever the encoding, so fields without rules like files of multipart forms are left alone
+///
+///  Fields rocket fails to parse, e.g. missing ones or numbers which aren't, are cached as well, under `__all__` with
+///  the `field` they name, and fail with `422 Unprocessable Entity` like `Form` does
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::form::Form;
+///  use rocket_validation::{Validate, Validated};
+///
+///  #[derive(Debug, FromForm, Validate)]
+///  pub struct HelloData {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[post("/hello", data = "<data>")]
+///  fn validated_hello(data: Validated<Form<HelloData>>) -> String {
+///      data.into_deep_inner().name
+///  }
+///  ```
+#[rocket::async_trait]
+impl<'r, T: Validate + FromForm<'r>> FromData<'r> for Validated<form::Form<T>> {
+    type Error = ValidationGuardError<form::Errors<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let parsed = <form::Form<T> as FromData<'r>>::from_data(req, data);
+        match forms::Collect::new(req, parsed).await {
+            (Outcome::Success(data), _) => {
+                validation_outcome::<T, _, _, _>(req, instrument::validate(&*data), Validated(data))
+            }
+            (Outcome::Error((status, errors)), failed) => {
+                // the failures of nested `Validated<T>` are cached already, keyed by their fields
+                let unparsed = parse_errors_of(errors.iter().filter(|error| {
+                    !(failed && matches!(error.kind, form::error::ErrorKind::Validation(_)))
+                }));
+                if status == Status::UnprocessableEntity && !unparsed.errors().is_empty() {
+                    cache_errors::<T>(req, &unparsed);
                 }
-            },
+                Outcome::Error((status, ValidationGuardError::Parse(errors)))
+            }
+            (Outcome::Forward(forward), _) => Outcome::Forward(forward),
+        }
+    }
+}
+
+///  Implementation of `Validated` for `MsgPack`, behind the `msgpack` feature
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{msgpack::MsgPack, Deserialize};
+///  use rocket_validation::{Validate, Validated};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct HelloData {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[post("/hello", format = "msgpack", data = "<data>")]
+///  fn validated_hello(data: Validated<MsgPack<HelloData>>) -> String {
+///      data.into_deep_inner().name
+///  }
+///  ```
+#[cfg(feature = "msgpack")]
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for Validated<MsgPack<D>> {
+    type Error = ValidationGuardError<rocket::serde::msgpack::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        match <MsgPack<D> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, err)) => {
+                Outcome::Error((status, ValidationGuardError::Parse(err)))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(data) => {
+                validation_outcome::<D, _, _, _>(req, instrument::validate(&*data), Validated(data))
+            }
         }
     }
 }
@@ -202,26 +908,27 @@ impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for Validate
 ///  Anything you implement `FromRequest` for as well as `Validate`
 #[rocket::async_trait]
 impl<'r, D: Validate + FromRequest<'r>> FromRequest<'r> for Validated<D> {
-    type Error = Result<ValidationErrors, D::Error>;
+    type Error = ValidationGuardError<D::Error>;
     async fn from_request(req: &'r Request<'_>) -> rocket::request::Outcome<Self, Self::Error> {
         let data_outcome = D::from_request(req).await;
 
         match data_outcome {
-            Outcome::Error((status, err)) => Outcome::Error((status, Err(err))),
+            Outcome::Error((status, err)) => {
+                Outcome::Error((status, ValidationGuardError::Parse(err)))
+            }
             Outcome::Forward(err) => Outcome::Forward(err),
-            Outcome::Success(data) => match data.validate() {
-                Ok(_) => Outcome::Success(Validated(data)),
-                Err(err) => {
-                    req.local_cache(|| CachedValidationErrors(Some(err.to_owned())));
-                    Outcome::Error((Status::UnprocessableEntity, Ok(err)))
-                }
-            },
+            Outcome::Success(data) => {
+                validation_outcome::<D, _, _, _>(req, instrument::validate(&data), Validated(data))
+            }
         }
     }
 }
 
 ///  Implementation of `Validated` for `FromForm`
 ///
+///  Failures are reported as rocket's form errors. Forms are parsed without access to the request, so they're cached
+///  for the catchers only when parsed by `Validated<Form<T>>` or `QueryParams<T>`, not as a `?<params..>` parameter
+///
 ///  An example validating a query struct
 ///  ```rust
 ///  # #[macro_use] extern crate rocket;
@@ -273,17 +980,7 @@ impl<'r, T: Validate + FromForm<'r>> FromForm<'r> for Validated<T> {
             Err(err) => Err(err),
             Ok(data) => match data.validate() {
                 Ok(_) => Ok(Validated(data)),
-                Err(err) => Err(err
-                    .into_errors()
-                    .into_iter()
-                    .map(|e| form::Error {
-                        name: Some(e.0.into()),
-                        kind: form::error::ErrorKind::Validation(std::borrow::Cow::Borrowed(e.0)),
-                        value: None,
-                        entity: form::error::Entity::Value,
-                    })
-                    .collect::<Vec<_>>()
-                    .into()),
+                Err(err) => Err(forms::failure::<T>(&err)),
             },
         }
     }
@@ -0,0 +1,206 @@
+//! Limits checked on the json body
+
+use crate::{
+    body::LazyValue,
+    errors::{count, truncate},
+    ValidationErrors,
+};
+use rocket::{data::ToByteUnit, request::Request};
+use std::{borrow::Cow, io};
+use validator::ValidationError;
+
+///  Maximum length, in chars, of any string (key or value) in a json body
+///
+///  Once managed, every json guard scans the body for longer strings once it is read and before deserializing it. The
+///  body itself is already in memory by then, bounded by the `json` data limit, but huge strings are rejected before
+///  being copied into `T`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .manage(rocket_validation::MaxStringLen(1024))
+///  }
+///  ```
+#[derive(Clone, Copy, Debug)]
+pub struct MaxStringLen(pub usize);
+
+impl MaxStringLen {
+    ///  Whether any string of `body` is longer than the limit, checked in a single pass
+    fn exceeded_by(self, body: &str) -> bool {
+        let mut bytes = body.bytes();
+
+        while let Some(byte) = bytes.next() {
+            if byte != b'"' {
+                continue;
+            }
+
+            let mut len = 0;
+            while let Some(byte) = bytes.next() {
+                match byte {
+                    b'"' => break,
+                    b'\\' => {
+                        if bytes.next() == Some(b'u') {
+                            bytes.nth(3);
+                        }
+                        len += 1;
+                    }
+                    // utf-8 continuation bytes don't start a new char
+                    byte if byte & 0xC0 == 0x80 => {}
+                    _ => len += 1,
+                }
+
+                if len > self.0 {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+///  Checks `body` against the `MaxStringLen` managed by the application, if any
+pub(crate) fn check_string_len(req: &Request<'_>, body: &str) -> Result<(), ValidationErrors> {
+    match req.rocket().state::<MaxStringLen>() {
+        Some(max) if max.exceeded_by(body) => {
+            let mut error = ValidationError::new("max_string_len");
+            error.add_param("max".into(), &max.0);
+
+            let mut errors = ValidationErrors::new();
+            errors.add("__all__", error);
+            Err(errors)
+        }
+        _ => Ok(()),
+    }
+}
+
+///  Maximum size, in bytes, of a json body once normalized
+///
+///  Once managed, `Validated<Json<T>>` checks bodies which passed field validation against it. The size is the one
+///  of the compact serialization of the body after normalization (see `NormalizeNfc`), so whitespace and escapes
+///  sent by clients don't count
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .manage(rocket_validation::MaxBodySize(64 * 1024))
+///  }
+///  ```
+#[derive(Clone, Copy, Debug)]
+pub struct MaxBodySize(pub usize);
+
+///  Size of the compact serialization of the body, recorded while its value is at hand
+struct BodySize(usize);
+
+///  Counts the bytes written to it without storing them
+struct ByteCount(usize);
+
+impl io::Write for ByteCount {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+///  Records the size of the normalized body of `value` for `check_body_size` if `MaxBodySize` is managed
+pub(crate) fn record_body_size(req: &Request<'_>, value: &mut LazyValue<'_>) {
+    if req.rocket().state::<MaxBodySize>().is_none() {
+        return;
+    }
+
+    let body = value.body();
+    let size = value.get().map_or(body.len(), |value| {
+        let mut count = ByteCount(0);
+        serde_json::to_writer(&mut count, &*value).map_or(body.len(), |_| count.0)
+    });
+
+    req.local_cache(|| BodySize(size));
+}
+
+///  Checks the size recorded for the body against the `MaxBodySize` managed by the application, if any
+pub(crate) fn check_body_size(req: &Request<'_>) -> Result<(), ValidationErrors> {
+    let max = match req.rocket().state::<MaxBodySize>() {
+        Some(max) => max,
+        None => return Ok(()),
+    };
+
+    let size = req.local_cache(|| BodySize(0)).0;
+
+    if size <= max.0 {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("max_body_size");
+    error.add_param("max".into(), &max.0);
+    error.add_param("size".into(), &size);
+
+    let mut errors = ValidationErrors::new();
+    errors.add("__all__", error);
+    Err(errors)
+}
+
+///  Code of the error cached for bodies exceeding the `json` data limit
+pub(crate) const PAYLOAD_TOO_LARGE_CODE: &str = "payload_too_large";
+
+///  Errors of a body exceeding the `json` data limit of rocket, a `payload_too_large` error under `__all__` with the
+///  `limit` in bytes
+pub(crate) fn payload_too_large(req: &Request<'_>) -> ValidationErrors {
+    let limit = req.limits().get("json").unwrap_or_else(|| 1.mebibytes());
+
+    let mut error = ValidationError::new(PAYLOAD_TOO_LARGE_CODE);
+    error.add_param("limit".into(), &limit.as_u64());
+
+    let mut errors = ValidationErrors::new();
+    errors.add("__all__", error);
+    errors
+}
+
+///  Maximum number of errors retained for the catchers and the audit log
+///
+///  `validator` always collects every error, once managed the ones cached for the response are capped. Capped
+///  responses carry an additional `capped` error under `__all__` with the `max` and the `total` number of errors
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher])
+///          .manage(rocket_validation::MaxErrors(20))
+///  }
+///  ```
+#[derive(Clone, Copy, Debug)]
+pub struct MaxErrors(pub usize);
+
+///  Caps `errors` to the `MaxErrors` managed by the application, if any
+pub(crate) fn cap_errors<'e>(
+    req: &Request<'_>,
+    errors: &'e ValidationErrors,
+) -> Cow<'e, ValidationErrors> {
+    let max = match req.rocket().state::<MaxErrors>() {
+        Some(max) => max.0,
+        None => return Cow::Borrowed(errors),
+    };
+
+    let total = count(errors);
+    if total <= max {
+        return Cow::Borrowed(errors);
+    }
+
+    let mut capped = truncate(errors, max);
+
+    let mut error = ValidationError::new("capped");
+    error.add_param("max".into(), &max);
+    error.add_param("total".into(), &total);
+    capped.add("__all__", error);
+
+    Cow::Owned(capped)
+}
@@ -0,0 +1,167 @@
+//! Locale aware parsing of numbers and dates sent as strings in json bodies
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+
+///  Bodies with fields formatted according to the locale of the client
+///  ```rust
+///  use rocket_validation::Localized;
+///
+///  pub struct Invoice {
+///      amount: f64,
+///      due: String,
+///  }
+///
+///  impl Localized for Invoice {
+///      const NUMBERS: &'static [&'static str] = &["amount"];
+///      const DATES: &'static [&'static str] = &["due"];
+///  }
+///  ```
+pub trait Localized {
+    ///  Top level fields holding numbers, e.g. `"1.234,56"` or `"1,234.56"`
+    const NUMBERS: &'static [&'static str] = &[];
+    ///  Top level fields holding dates, e.g. `"31.12.2024"` or `"12/31/2024"`, handed to serde as `2024-12-31`
+    const DATES: &'static [&'static str] = &[];
+}
+
+///  Languages writing `1.234,56`
+const DECIMAL_COMMA: &[&str] = &[
+    "bg", "cs", "da", "de", "el", "es", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv", "nb", "nl",
+    "nn", "no", "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "tr", "uk", "vi",
+];
+
+///  Locale of the request from `Content-Language`, falling back to the first tag of `Accept-Language`
+fn request_locale<'r>(req: &'r Request<'_>) -> Option<&'r str> {
+    let headers = req.headers();
+    let header = headers
+        .get_one("Content-Language")
+        .or_else(|| headers.get_one("Accept-Language"))?;
+
+    header
+        .split(',')
+        .next()
+        .map(|tag| tag.split(';').next().unwrap_or(tag).trim())
+        .filter(|tag| !tag.is_empty() && *tag != "*")
+}
+
+fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_ascii_lowercase()
+}
+
+fn parse_number(raw: &str, locale: &str) -> Option<Value> {
+    let decimal = if DECIMAL_COMMA.contains(&&*language(locale)) {
+        ','
+    } else {
+        '.'
+    };
+
+    let normalized: String = raw
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c == decimal => Some('.'),
+            '.' | ',' | ' ' | '\u{a0}' | '\u{202f}' | '\'' => None,
+            c => Some(c),
+        })
+        .collect();
+
+    json::from_str::<Value>(&normalized)
+        .ok()
+        .filter(Value::is_number)
+}
+
+fn parse_date(raw: &str, locale: &str) -> Option<Value> {
+    let parts: Vec<&str> = raw.trim().split(['.', '/', '-']).collect();
+    if parts.len() != 3
+        || parts
+            .iter()
+            .any(|p| p.is_empty() || !p.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+
+    let (year, month, day) = if parts[0].len() == 4 {
+        (parts[0], parts[1], parts[2])
+    } else if locale.eq_ignore_ascii_case("en-US") || locale.eq_ignore_ascii_case("en") {
+        (parts[2], parts[0], parts[1])
+    } else {
+        (parts[2], parts[1], parts[0])
+    };
+
+    let (year, month, day): (u32, u32, u32) =
+        (year.parse().ok()?, month.parse().ok()?, day.parse().ok()?);
+    Some(Value::String(format!("{year:04}-{month:02}-{day:02}")))
+}
+
+///  Struct used for Request Guards parsing `Localized` fields according to the locale of the request before
+///  validating the body
+///
+///  Values which don't parse in the locale are left untouched, for deserialization or validation to reject them.
+///  Without a locale header, `en-US` is assumed
+#[derive(Clone, Debug)]
+pub struct ValidatedLocalized<T>(pub T);
+
+impl_into_inner!(ValidatedLocalized);
+
+///  Implementation of `ValidatedLocalized` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Localized + DeserializeOwned> FromData<'r> for ValidatedLocalized<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let mut value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let locale = request_locale(req).unwrap_or("en-US");
+
+        if let Some(object) = value.as_object_mut() {
+            let fields = D::NUMBERS
+                .iter()
+                .map(|field| (field, parse_number as fn(&str, &str) -> Option<Value>))
+                .chain(
+                    D::DATES
+                        .iter()
+                        .map(|field| (field, parse_date as fn(&str, &str) -> Option<Value>)),
+                );
+
+            for (field, parse) in fields {
+                if let Some(entry) = object.get_mut(*field) {
+                    if let Some(parsed) = entry.as_str().and_then(|raw| parse(raw, locale)) {
+                        *entry = parsed;
+                    }
+                }
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedLocalized(data),
+            ),
+        }
+    }
+}
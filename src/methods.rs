@@ -0,0 +1,94 @@
+//! Guard restricting the fields a body may set depending on the HTTP method of the request
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_failure, validation_outcome, Validate, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Method,
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json},
+    },
+};
+use validator::ValidationError;
+
+///  Bodies whose mutable fields depend on the HTTP method
+///  ```rust
+///  use rocket::http::Method;
+///  use rocket_validation::MethodFields;
+///
+///  pub struct Profile {
+///      name: String,
+///      email: String,
+///  }
+///
+///  impl MethodFields for Profile {
+///      fn allowed_fields(method: Method) -> Option<&'static [&'static str]> {
+///          match method {
+///              Method::Patch => Some(&["name"]),
+///              _ => None,
+///          }
+///      }
+///  }
+///  ```
+pub trait MethodFields {
+    ///  Top level keys the body may send with `method`, `None` allowing every field
+    fn allowed_fields(method: Method) -> Option<&'static [&'static str]>;
+}
+
+///  Struct used for Request Guards rejecting fields which the method of the request may not set
+///
+///  Disallowed keys fail with an `immutable` error under `__all__`, with the `field` and the `method` of the request as
+///  params, before the body is validated
+#[derive(Clone, Debug)]
+pub struct ValidatedMethod<T>(pub T);
+
+impl_into_inner!(ValidatedMethod);
+
+///  Implementation of `ValidatedMethod` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + MethodFields + DeserializeOwned> FromData<'r> for ValidatedMethod<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        if let (Some(allowed), Some(object)) = (D::allowed_fields(req.method()), value.as_object())
+        {
+            let mut err = ValidationErrors::new();
+
+            for field in object.keys().filter(|key| !allowed.contains(&key.as_str())) {
+                let mut error = ValidationError::new("immutable");
+                error.add_param("field".into(), field);
+                error.add_param("method".into(), &req.method().as_str());
+                err.add("__all__", error);
+            }
+
+            if !err.is_empty() {
+                return Outcome::Error(validation_failure::<D, _>(req, err));
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedMethod(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Guard rejecting events older than the last accepted one
+
+use crate::{validation_failure, Validate, Validated, ValidationErrors, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use validator::ValidationError;
+
+///  Bodies carrying the timestamp checked by `ValidatedMonotonic`
+pub trait Timestamped {
+    ///  Field reported when the timestamp isn't newer than the last one
+    const FIELD: &'static str = "timestamp";
+
+    ///  Timestamp of the event, in any unit as long as every body uses the same one
+    fn timestamp(&self) -> i64;
+}
+
+///  Storage of the last timestamp accepted by `ValidatedMonotonic`
+pub trait TimestampStore: Send + Sync {
+    ///  Atomically records `timestamp` as the last accepted one if it is newer than it, otherwise returns the last one
+    ///
+    ///  Concurrent requests go through this single call, so implementations must compare and record at once, e.g. with
+    ///  a compare-and-swap or under a lock, for two events to never be accepted with the same last timestamp
+    fn advance(&self, timestamp: i64) -> Result<(), i64>;
+}
+
+///  Managed wrapper of the `TimestampStore` used by `ValidatedMonotonic`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::{TimestampStore, Timestamps};
+///  use std::sync::atomic::{AtomicI64, Ordering};
+///
+///  struct Last(AtomicI64);
+///
+///  impl TimestampStore for Last {
+///      fn advance(&self, timestamp: i64) -> Result<(), i64> {
+///          self.0
+///              .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+///                  (timestamp > last).then(|| timestamp)
+///              })
+///              .map(|_| ())
+///      }
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(Timestamps::new(Last(AtomicI64::new(0))))
+///  }
+///  ```
+pub struct Timestamps(Box<dyn TimestampStore>);
+
+impl Timestamps {
+    ///  Keeps the last accepted timestamp in `store`
+    pub fn new(store: impl TimestampStore + 'static) -> Self {
+        Timestamps(Box::new(store))
+    }
+}
+
+///  Struct used for Request Guards validating the body and checking its timestamp is newer than the last accepted one
+#[derive(Clone, Debug)]
+pub struct ValidatedMonotonic<T>(pub T);
+
+impl_into_inner!(ValidatedMonotonic);
+
+///  Errors of `ValidatedMonotonic` besides validation failures
+#[derive(Debug)]
+pub enum MonotonicError<'r> {
+    ///  No `Timestamps` are managed by the application
+    Unmanaged,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedMonotonic` for `Json`
+///
+///  A timestamp not newer than the last one fails validation with a `monotonic` error on `Timestamped::FIELD`
+#[rocket::async_trait]
+impl<'r, D: Validate + Timestamped + rocket::serde::Deserialize<'r>> FromData<'r>
+    for ValidatedMonotonic<Json<D>>
+{
+    type Error = ValidationGuardError<MonotonicError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let store = match req.rocket().state::<Timestamps>() {
+            Some(Timestamps(store)) => store,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(MonotonicError::Unmanaged),
+                ))
+            }
+        };
+
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, ValidationGuardError::Validation(err))) => {
+                Outcome::Error((status, ValidationGuardError::Validation(err)))
+            }
+            Outcome::Error((status, ValidationGuardError::Parse(err))) => Outcome::Error((
+                status,
+                ValidationGuardError::Parse(MonotonicError::Json(err)),
+            )),
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(Validated(data)) => {
+                let timestamp = data.timestamp();
+
+                match store.advance(timestamp) {
+                    Ok(_) => Outcome::Success(ValidatedMonotonic(data)),
+                    Err(last) => {
+                        let mut error = ValidationError::new("monotonic");
+                        error.add_param("last".into(), &last);
+                        error.add_param("value".into(), &timestamp);
+
+                        let mut err = ValidationErrors::new();
+                        err.add(D::FIELD, error);
+
+                        Outcome::Error(validation_failure::<D, _>(req, err))
+                    }
+                }
+            }
+        }
+    }
+}
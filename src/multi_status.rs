@@ -0,0 +1,96 @@
+//! `207 Multi-Status` responses of bulk endpoints validating each item on its own
+
+use crate::{failure_status, Validate, ValidationErrors};
+use rocket::{
+    http::Status,
+    request::Request,
+    response::{self, Responder, Response},
+    serde::{json::Json, Serialize},
+};
+
+///  Validates each of `items`, keeping them in order
+pub fn validate_each<D: Validate>(items: Vec<D>) -> Vec<Result<D, ValidationErrors>> {
+    items
+        .into_iter()
+        .map(|item| item.validate().map(|_| item))
+        .collect()
+}
+
+///  Outcome of an item of a `MultiStatus` response
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ItemStatus<T> {
+    index: usize,
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<ValidationErrors>,
+}
+
+///  Body of a `MultiStatus` response
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct MultiStatusBody<T> {
+    code: u16,
+    items: Vec<ItemStatus<T>>,
+}
+
+///  Responder answering `207 Multi-Status` with the outcome of every item of a bulk request
+///
+///  Valid items are listed with status `200` and their data, invalid ones with the status `Validated` would have
+///  failed with and their errors
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize, Serialize};
+///  use rocket_validation::{validate_each, MultiStatus, Validate};
+///
+///  #[derive(Debug, Deserialize, Serialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct User {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[post("/users/bulk", data = "<data>")]
+///  fn create_users(data: Json<Vec<User>>) -> MultiStatus<User> {
+///      let results = validate_each(data.into_inner());
+///      // store the valid users
+///      MultiStatus(results)
+///  }
+///  ```
+#[derive(Debug)]
+pub struct MultiStatus<T>(pub Vec<Result<T, ValidationErrors>>);
+
+impl<'r, T: Serialize> Responder<'r, 'static> for MultiStatus<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let items = self
+            .0
+            .into_iter()
+            .enumerate()
+            .map(|(index, result)| match result {
+                Ok(data) => ItemStatus {
+                    index,
+                    status: Status::Ok.code,
+                    data: Some(data),
+                    errors: None,
+                },
+                Err(errors) => ItemStatus {
+                    index,
+                    status: failure_status(req, &errors).code,
+                    data: None,
+                    errors: Some(errors),
+                },
+            })
+            .collect();
+
+        let body = MultiStatusBody {
+            code: Status::MultiStatus.code,
+            items,
+        };
+
+        Response::build_from(Json(body).respond_to(req)?)
+            .status(Status::MultiStatus)
+            .ok()
+    }
+}
@@ -0,0 +1,84 @@
+//! Optional query structs, absent when no field of them was sent
+
+use crate::{forms, instrument, Validate};
+use rocket::form::{self, DataField, FromForm, ValueField};
+
+///  Struct used for optional `FromForm` structs, e.g. filters of a query, `None` when no field was sent instead of
+///  failing on required fields
+///
+///  Once any field is sent the struct is parsed and validated like `Validated<T>`, its failures cached for the catchers
+///  when parsed by `Validated<Form<T>>` or `QueryParams<T>`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::{Validate, ValidatedOption};
+///
+///  #[derive(Debug, FromForm, Validate)]
+///  pub struct Filter {
+///      #[validate(length(min = 3))]
+///      term: String,
+///      #[validate(range(min = 1, max = 100))]
+///      limit: u8,
+///  }
+///
+///  #[get("/search?<filter..>")]
+///  fn search(filter: ValidatedOption<Filter>) -> String {
+///      match filter.into_inner() {
+///          Some(filter) => filter.term,
+///          None => "everything".into(),
+///      }
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedOption<T>(pub Option<T>);
+
+///  Impl to get the optional type T
+impl<T> ValidatedOption<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+///  Context of `ValidatedOption`, the one of `T` along whether any field was pushed
+pub struct OptionContext<C> {
+    inner: C,
+    pushed: bool,
+}
+
+#[rocket::async_trait]
+impl<'r, T: Validate + FromForm<'r>> FromForm<'r> for ValidatedOption<T> {
+    type Context = OptionContext<T::Context>;
+
+    #[inline]
+    fn init(opts: form::Options) -> Self::Context {
+        OptionContext {
+            inner: T::init(opts),
+            pushed: false,
+        }
+    }
+
+    #[inline]
+    fn push_value(ctxt: &mut Self::Context, field: ValueField<'r>) {
+        ctxt.pushed = true;
+        T::push_value(&mut ctxt.inner, field)
+    }
+
+    #[inline]
+    async fn push_data(ctxt: &mut Self::Context, field: DataField<'r, '_>) {
+        ctxt.pushed = true;
+        T::push_data(&mut ctxt.inner, field).await
+    }
+
+    fn finalize(this: Self::Context) -> form::Result<'r, Self> {
+        if !this.pushed {
+            return Ok(ValidatedOption(None));
+        }
+
+        let data = T::finalize(this.inner)?;
+        match instrument::validate(&data) {
+            Ok(_) => Ok(ValidatedOption(Some(data))),
+            Err(err) => Err(forms::failure::<T>(&err)),
+        }
+    }
+}
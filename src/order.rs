@@ -0,0 +1,125 @@
+//! Guard checking the keys of a json body appear in the order the struct declares its fields
+
+use crate::{
+    body::{check_body, parse_body, read_raw_body},
+    instrument,
+    introspect::declared_names,
+    validation_failure, validation_outcome, Validate, ValidationErrors, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::{IgnoredAny, MapAccess, Visitor},
+        json::{self, Json},
+        Deserialize, Deserializer,
+    },
+};
+use std::fmt;
+use validator::ValidationError;
+
+///  Top level keys of a json object in the order they were sent
+struct Keys(Vec<String>);
+
+impl<'de> Deserialize<'de> for Keys {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        struct KeysVisitor;
+
+        impl<'de> Visitor<'de> for KeysVisitor {
+            type Value = Keys;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a json object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Keys, A::Error> {
+                let mut keys = Vec::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    map.next_value::<IgnoredAny>()?;
+                    keys.push(key);
+                }
+                Ok(Keys(keys))
+            }
+        }
+
+        deserializer.deserialize_map(KeysVisitor)
+    }
+}
+
+///  Checks the declared fields sent in `body` appear in declaration order
+///
+///  Unknown keys are ignored as are omitted fields, the first key sent after a field declared later fails with a
+///  `field_order` error naming the preceding key in the param `after`
+fn check_order(fields: &'static [&'static str], body: &str) -> Result<(), ValidationErrors> {
+    let keys = match json::from_str::<Keys>(body) {
+        Ok(keys) => keys.0,
+        // left to deserialization to reject
+        Err(_) => return Ok(()),
+    };
+
+    let mut previous: Option<(usize, &str)> = None;
+    for key in &keys {
+        let position = match fields.iter().position(|field| *field == key.as_str()) {
+            Some(position) => position,
+            None => continue,
+        };
+
+        if let Some((last, after)) = previous {
+            if position < last {
+                let mut error = ValidationError::new("field_order");
+                error.add_param("after".into(), &after);
+
+                let mut errors = ValidationErrors::new();
+                errors.add(fields[position], error);
+                return Err(errors);
+            }
+        }
+
+        previous = Some((position, key));
+    }
+
+    Ok(())
+}
+
+///  Struct used for Request Guards requiring the keys of the body in the order of the fields of the struct, e.g. for
+///  payloads signed in a canonical form
+#[derive(Clone, Debug)]
+pub struct ValidatedOrdered<T>(pub T);
+
+impl_into_inner!(ValidatedOrdered);
+
+///  Implementation of `ValidatedOrdered` for `Json`
+///
+///  The order is taken from `D`'s `Deserialize` impl, so `#[serde(rename)]`d fields are expected under their new name.
+///  Structs using `#[serde(flatten)]` aren't deserialized as plain structs and skip the check
+#[rocket::async_trait]
+impl<'r, D: Validate + Deserialize<'r>> FromData<'r> for ValidatedOrdered<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_raw_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        // the order is the one sent by the client, before normalization may re-serialize the body
+        if let Err(err) = check_order(declared_names::<D>(), body) {
+            return Outcome::Error(validation_failure::<D, _>(req, err));
+        }
+
+        let body = match check_body::<D>(req, body) {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        match parse_body::<D>(req, body) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedOrdered(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Fields of JSON Merge Patch bodies, telling apart `null`, which deletes a value, from absent fields left unchanged
+
+use rocket::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use validator::ValidationError;
+
+///  Field of a JSON Merge Patch body, either absent, `null` or holding a value
+///
+///  Absent fields deserialize only with `#[serde(default)]`. Custom validators receive the whole `Patch`, so `null`
+///  and values can be checked differently, see `Patch::not_null` and `Patch::check_value`
+///  ```rust
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{Patch, Validate};
+///  use validator::ValidationError;
+///
+///  fn email(email: &Patch<String>) -> Result<(), ValidationError> {
+///      email.not_null()?;
+///      email.check_value(|email| match email.contains('@') {
+///          true => Ok(()),
+///          false => Err(ValidationError::new("email")),
+///      })
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct AccountPatch {
+///      #[serde(default)]
+///      #[validate(custom(function = "email"))]
+///      email: Patch<String>,
+///  }
+///  ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Patch<T> {
+    ///  The field wasn't sent, the value is left unchanged
+    Absent,
+    ///  The field was sent as `null`, the value is deleted
+    Null,
+    ///  The field was sent with a value
+    Value(T),
+}
+
+impl<T> Default for Patch<T> {
+    fn default() -> Self {
+        Patch::Absent
+    }
+}
+
+impl<T> Patch<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    ///  Value sent, if any
+    pub fn as_value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    ///  Fails with a `not_nullable` error if the field was sent as `null`, for values which can't be deleted
+    pub fn not_null(&self) -> Result<(), ValidationError> {
+        match self {
+            Patch::Null => Err(ValidationError::new("not_nullable")),
+            _ => Ok(()),
+        }
+    }
+
+    ///  Fails with a `required` error unless the field was sent, `null` included
+    pub fn present(&self) -> Result<(), ValidationError> {
+        match self {
+            Patch::Absent => Err(ValidationError::new("required")),
+            _ => Ok(()),
+        }
+    }
+
+    ///  Runs `check` against the value sent, absent and `null` fields passing
+    pub fn check_value(
+        &self,
+        check: impl FnOnce(&T) -> Result<(), ValidationError>,
+    ) -> Result<(), ValidationError> {
+        self.as_value().map_or(Ok(()), check)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+///  Absent fields serialize as `null` unless skipped with `#[serde(skip_serializing_if = "Patch::is_absent")]`
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_value().serialize(serializer)
+    }
+}
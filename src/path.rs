@@ -0,0 +1,167 @@
+//! Path segments parsed as a request guard, so they can be validated like any other data
+
+use crate::{
+    cache_errors, failure_status, instrument, Validate, Validated, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    http::Status,
+    outcome::Outcome,
+    request::{self, FromParam, FromRequest, Request},
+};
+use std::str::FromStr;
+use validator::ValidationError;
+
+///  Segment `N` of the path, counted from 0 after the mount point, parsed into `T`
+///
+///  Parameters of the route validated as `Validated<T>` go through `FromParam`, which has no access to the request, so
+///  their errors can't reach the catchers. `Validated<PathParam<T, N>>` reads the segment as a request guard instead,
+///  validates it and caches the errors. Segments which don't parse fail with a `parse` error under `__all__` holding
+///  the `value`. The route still has to declare the segment, e.g. as an ignored `<_>`, paths without segment `N`
+///  fail with `404 Not Found`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::{PathParam, Validate, Validated, ValidationErrors};
+///  use std::str::FromStr;
+///  use validator::ValidationError;
+///
+///  pub struct Username(String);
+///
+///  impl FromStr for Username {
+///      type Err = ();
+///
+///      fn from_str(s: &str) -> Result<Self, ()> {
+///          Ok(Username(s.to_owned()))
+///      }
+///  }
+///
+///  impl Validate for Username {
+///      fn validate(&self) -> Result<(), ValidationErrors> {
+///          let mut errors = ValidationErrors::new();
+///          if self.0.len() < 3 {
+///              errors.add("username", ValidationError::new("length"));
+///          }
+///          match errors.is_empty() {
+///              true => Ok(()),
+///              false => Err(errors),
+///          }
+///      }
+///  }
+///
+///  #[get("/users/<_>")]
+///  fn user(username: Validated<PathParam<Username, 1>>) -> String {
+///      username.into_deep_inner().0
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct PathParam<T, const N: usize>(pub T);
+
+impl<T, const N: usize> PathParam<T, N> {
+    ///  Consumes the wrapper, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+///  Impl to get type T of `PathParam`
+impl<T, const N: usize> Validated<PathParam<T, N>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for PathParam<T, N> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.0.validate()
+    }
+}
+
+///  Errors of `PathParam` besides validation failures
+#[derive(Debug)]
+pub enum PathParamError {
+    ///  The path has no segment `N`, fails with `404 Not Found`
+    Missing,
+    ///  The segment doesn't parse into `T`
+    Parse,
+}
+
+#[rocket::async_trait]
+impl<'r, T: FromStr, const N: usize> FromRequest<'r> for PathParam<T, N> {
+    type Error = PathParamError;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let segment = match req.param::<&str>(N) {
+            Some(Ok(segment)) => segment,
+            _ => return Outcome::Error((Status::NotFound, PathParamError::Missing)),
+        };
+
+        match segment.parse() {
+            Ok(value) => Outcome::Success(PathParam(value)),
+            Err(_) => {
+                let mut error = ValidationError::new("parse");
+                error.add_param("value".into(), &segment);
+
+                let mut err = ValidationErrors::new();
+                err.add("__all__", error);
+
+                cache_errors::<T>(req, &err);
+                Outcome::Error((failure_status(req, &err), PathParamError::Parse))
+            }
+        }
+    }
+}
+
+///  Implementation of `Validated` for path parameters parsed with `FromStr`
+///
+///  Segments which don't parse fail with `ValidationGuardError::Parse`, invalid ones with
+///  `ValidationGuardError::Validation`, and rocket forwards both with `422 Unprocessable Entity`. Their errors aren't
+///  cached for the catchers, use `Validated<PathParam<T, N>>` for that
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::{Validate, Validated, ValidationErrors};
+///  use std::str::FromStr;
+///
+///  pub struct Page(u32);
+///
+///  impl FromStr for Page {
+///      type Err = std::num::ParseIntError;
+///
+///      fn from_str(s: &str) -> Result<Self, Self::Err> {
+///          s.parse().map(Page)
+///      }
+///  }
+///
+///  impl Validate for Page {
+///      fn validate(&self) -> Result<(), ValidationErrors> {
+///          let mut errors = ValidationErrors::new();
+///          if self.0 == 0 {
+///              errors.add("page", validator::ValidationError::new("range"));
+///          }
+///          match errors.is_empty() {
+///              true => Ok(()),
+///              false => Err(errors),
+///          }
+///      }
+///  }
+///
+///  #[get("/pages/<page>")]
+///  fn page(page: Validated<Page>) -> String {
+///      page.into_inner().0.to_string()
+///  }
+///  ```
+impl<'a, T> FromParam<'a> for Validated<T>
+where
+    T: FromStr + Validate,
+    T::Err: std::fmt::Debug,
+{
+    type Error = ValidationGuardError<T::Err>;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        let data = param.parse::<T>().map_err(ValidationGuardError::Parse)?;
+        instrument::validate(&data).map_err(ValidationGuardError::Validation)?;
+        Ok(Validated(data))
+    }
+}
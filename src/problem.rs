@@ -0,0 +1,82 @@
+//! Catcher negotiating RFC 7807 problem details with clients asking for them
+
+use crate::{
+    cached_by_type, cached_errors, config::validation_message, Error, TraceId, ValidationErrors,
+};
+use rocket::{
+    either::Either,
+    http::ContentType,
+    request::Request,
+    serde::{json::Json, Serialize},
+};
+use std::borrow::Cow;
+
+///  Struct representing an RFC 7807 problem document, the validation errors being its `errors` extension member
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ProblemDetails<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    title: &'static str,
+    status: u16,
+    detail: &'a str,
+    instance: &'a str,
+    errors: Option<Cow<'a, ValidationErrors>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_id: Option<&'a str>,
+}
+
+///  Whether the client prefers `application/problem+json` over the other media types it accepts
+fn prefers_problem(req: &Request<'_>) -> bool {
+    match req.accept() {
+        Some(accept) => {
+            let preferred = accept.preferred().media_type();
+            preferred.top() == "application" && preferred.sub() == "problem+json"
+        }
+        None => false,
+    }
+}
+
+///  Catcher returning validation errors as an `application/problem+json` document to clients preferring it in their
+///  `Accept` header, and like `validation_catcher` to the others
+///
+///  The document has `about:blank` as `type`, the path of the request as `instance` and the failed rules by field as
+///  `errors`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher_problem])
+///  }
+///  ```
+#[catch(422)]
+pub fn validation_catcher_problem<'a>(
+    req: &'a Request,
+) -> Either<Json<Error<'a>>, (ContentType, Json<ProblemDetails<'a>>)> {
+    let trace_id = TraceId::of(req).map(|trace_id| trace_id.0.as_str());
+
+    if !prefers_problem(req) {
+        return Either::Left(Json(Error {
+            code: 422,
+            message: validation_message(req),
+            errors: cached_errors(req),
+            by_type: cached_by_type(req),
+            trace_id,
+        }));
+    }
+
+    Either::Right((
+        ContentType::new("application", "problem+json"),
+        Json(ProblemDetails {
+            kind: "about:blank",
+            title: "Unprocessable Entity",
+            status: 422,
+            detail: validation_message(req),
+            instance: req.uri().path().as_str(),
+            errors: cached_errors(req),
+            trace_id,
+        }),
+    ))
+}
@@ -0,0 +1,133 @@
+//! Guard telling the fields the client sent apart from the ones which were defaulted
+
+use crate::{validate_json, Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    request::Request,
+    serde::{
+        json::{self, Json, Value},
+        Deserialize,
+    },
+};
+use serde_json::Map;
+use std::{
+    collections::BTreeSet,
+    sync::{Mutex, PoisonError},
+};
+
+///  Request local storage of the provided fields, `None` unless a `ValidatedProvided` guard asked for them
+struct CachedProvided(Mutex<Option<BTreeSet<String>>>);
+
+fn cached<'r>(req: &'r Request<'_>) -> &'r Mutex<Option<BTreeSet<String>>> {
+    &req.local_cache(|| CachedProvided(Mutex::new(None))).0
+}
+
+fn collect(object: &Map<String, Value>, prefix: &str, fields: &mut BTreeSet<String>) {
+    for (key, value) in object {
+        let field = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+
+        if let Value::Object(object) = value {
+            collect(object, &field, fields);
+        }
+        fields.insert(field);
+    }
+}
+
+///  Records the keys present in `body` if a `ValidatedProvided` guard is reading it
+pub(crate) fn record(req: &Request<'_>, body: &str) {
+    let mut provided = cached(req).lock().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(fields) = provided.as_mut() {
+        if let Ok(Value::Object(object)) = json::from_str::<Value>(body) {
+            collect(&object, "", fields);
+        }
+    }
+}
+
+///  Makes `Validated<Json<T>>` record the keys of the body it reads
+pub(crate) fn track(req: &Request<'_>) {
+    *cached(req).lock().unwrap_or_else(PoisonError::into_inner) = Some(BTreeSet::new());
+}
+
+///  Keys recorded since `track`
+pub(crate) fn take(req: &Request<'_>) -> BTreeSet<String> {
+    cached(req)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+        .unwrap_or_default()
+}
+
+///  Struct used for Request Guards validating the body and recording which fields the client explicitly sent
+///
+///  Fields of nested objects are recorded with their dotted path, e.g. `address.city`, fields sent as `null` count as
+///  provided
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Validate, ValidatedProvided};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct UserPatch {
+///      #[validate(length(min = 1))]
+///      name: Option<String>,
+///      #[serde(default)]
+///      newsletter: bool,
+///  }
+///
+///  #[patch("/users/<id>", data = "<data>")]
+///  fn patch_user(id: u64, data: ValidatedProvided<Json<UserPatch>>) -> String {
+///      if data.is_provided("newsletter") {
+///          // update the newsletter subscription, even if set to false
+///      }
+///      format!("{id}")
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedProvided<T>(pub T, pub BTreeSet<String>);
+
+///  Impl to get type T of `Json`
+impl<T> ValidatedProvided<Json<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T
+impl<T> ValidatedProvided<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    ///  Fields the client sent
+    pub fn provided_fields(&self) -> &BTreeSet<String> {
+        &self.1
+    }
+
+    pub fn is_provided(&self, field: &str) -> bool {
+        self.1.contains(field)
+    }
+}
+
+///  Implementation of `ValidatedProvided` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Deserialize<'r>> FromData<'r> for ValidatedProvided<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        track(req);
+
+        validate_json(req, data, record)
+            .await
+            .map(|Validated(data)| ValidatedProvided(data, take(req)))
+    }
+}
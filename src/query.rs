@@ -0,0 +1,76 @@
+//! Query strings parsed as a request guard, so their validation errors reach the catchers
+
+use crate::{forms, Validate, Validated, ValidationErrors};
+use rocket::{
+    form::{self, Form, FromForm},
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+};
+
+///  Query string of the request parsed into `T`, like `?<params..>` does
+///
+///  `Validated<T>` as a `?<params..>` parameter is validated while rocket parses the query, without access to the
+///  request, so the catchers don't get its errors. `Validated<QueryParams<T>>` validates as a request guard and caches
+///  them
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::json::Json;
+///  use rocket_validation::{QueryParams, Validate, Validated};
+///
+///  #[derive(Debug, FromForm, Validate)]
+///  pub struct Search {
+///      #[validate(length(min = 3))]
+///      term: String,
+///  }
+///
+///  #[get("/search")]
+///  fn search(params: Validated<QueryParams<Search>>) -> String {
+///      params.into_deep_inner().term
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![search])
+///          .register("/", catchers![rocket_validation::validation_catcher])
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct QueryParams<T>(pub T);
+
+impl<T> QueryParams<T> {
+    ///  Consumes the wrapper, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+///  Impl to get type T of `QueryParams`
+impl<T> Validated<QueryParams<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+impl<T: Validate> Validate for QueryParams<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.0.validate()
+    }
+}
+
+///  Queries which don't parse into `T` fail with the status of their form errors, the failures of the `Validated<U>`
+///  fields of `T` being cached for the catchers
+#[rocket::async_trait]
+impl<'r, T: FromForm<'r>> FromRequest<'r> for QueryParams<T> {
+    type Error = form::Errors<'r>;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match forms::collect(req, || Form::<T>::parse_iter(req.query_fields())).0 {
+            Ok(data) => Outcome::Success(QueryParams(data)),
+            Err(errors) => Outcome::Error((errors.status(), errors)),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Guard consuming a per api key quota once the body is validated
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+///  Storage of the requests consumed by the api keys of a `Quota`
+pub trait QuotaStore: Send + Sync {
+    ///  Consumes one of the `limit` requests `key` may make in the window starting at the unix timestamp `window`,
+    ///  returns `false` if none is left
+    fn take(&self, key: &str, window: i64, limit: u64) -> bool;
+
+    ///  Requests `key` consumed in the window starting at the unix timestamp `window`
+    fn used(&self, key: &str, window: i64) -> u64;
+}
+
+///  In memory `QuotaStore`, forgetting every key once its window is over
+///
+///  At most `capacity` keys are tracked per window: once full, keys it doesn't know yet have no quota left until the
+///  next window, so clients making up keys can't grow it without bound
+#[derive(Debug)]
+pub struct MemoryQuotaStore {
+    capacity: usize,
+    used: Mutex<(i64, HashMap<String, u64>)>,
+}
+
+impl MemoryQuotaStore {
+    ///  Tracks up to `capacity` keys per window
+    pub fn new(capacity: usize) -> Self {
+        MemoryQuotaStore {
+            capacity,
+            used: Mutex::new((i64::MIN, HashMap::new())),
+        }
+    }
+}
+
+impl Default for MemoryQuotaStore {
+    fn default() -> Self {
+        MemoryQuotaStore::new(10_000)
+    }
+}
+
+impl QuotaStore for MemoryQuotaStore {
+    fn take(&self, key: &str, window: i64, limit: u64) -> bool {
+        let mut used = self.used.lock().unwrap_or_else(PoisonError::into_inner);
+        let (current, counts) = &mut *used;
+
+        if *current != window {
+            *current = window;
+            counts.clear();
+        }
+
+        if !counts.contains_key(key) && counts.len() >= self.capacity {
+            return false;
+        }
+
+        let count = counts.entry(key.to_owned()).or_insert(0);
+        if *count >= limit {
+            false
+        } else {
+            *count += 1;
+            true
+        }
+    }
+
+    fn used(&self, key: &str, window: i64) -> u64 {
+        let used = self.used.lock().unwrap_or_else(PoisonError::into_inner);
+        match &*used {
+            (current, counts) if *current == window => counts.get(key).copied().unwrap_or(0),
+            _ => 0,
+        }
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+///  Number of validated requests each api key may make per window, keyed by the value of a header
+///
+///  Quotas are refilled every `window`, an hour unless another one is given, and kept in a `MemoryQuotaStore` unless
+///  another store is given, e.g. one shared between instances
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(rocket_validation::Quota::new("X-Api-Key", 1000).window(60))
+///  }
+///  ```
+pub struct Quota {
+    header: &'static str,
+    limit: u64,
+    window: i64,
+    store: Box<dyn QuotaStore>,
+}
+
+impl Quota {
+    ///  Allows `limit` validated requests per hour to every api key sent in `header`
+    pub fn new(header: &'static str, limit: u64) -> Self {
+        Quota {
+            header,
+            limit,
+            window: 3600,
+            store: Box::new(MemoryQuotaStore::default()),
+        }
+    }
+
+    ///  Refills the quotas every `seconds` instead
+    pub fn window(mut self, seconds: u32) -> Self {
+        self.window = i64::from(seconds.max(1));
+        self
+    }
+
+    ///  Keeps the consumed requests in `store` instead of a `MemoryQuotaStore`
+    pub fn store(mut self, store: impl QuotaStore + 'static) -> Self {
+        self.store = Box::new(store);
+        self
+    }
+
+    ///  Unix timestamp the current window started at
+    fn current_window(&self) -> i64 {
+        let now = now();
+        now - now.rem_euclid(self.window)
+    }
+
+    ///  Requests `key` may still make in the current window
+    pub fn remaining(&self, key: &str) -> u64 {
+        let used = self.store.used(key, self.current_window());
+        self.limit.saturating_sub(used)
+    }
+
+    ///  Consumes one request of `key`, returns `false` if none is left
+    fn take(&self, key: &str) -> bool {
+        self.store.take(key, self.current_window(), self.limit)
+    }
+}
+
+///  Struct used for Request Guards consuming the caller's `Quota` once the body is validated
+///
+///  Invalid bodies don't consume any quota
+#[derive(Clone, Debug)]
+pub struct ValidatedQuota<T>(pub T);
+
+impl_into_inner!(ValidatedQuota);
+
+///  Errors of `ValidatedQuota` besides validation failures
+#[derive(Debug)]
+pub enum QuotaError<'r> {
+    ///  No `Quota` is managed by the application
+    Unmanaged,
+    ///  The api key header is missing, fails with `401 Unauthorized`
+    MissingKey,
+    ///  The api key has no quota left, fails with `429 Too Many Requests`
+    Exhausted,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedQuota` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for ValidatedQuota<Json<D>> {
+    type Error = ValidationGuardError<QuotaError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let quota = match req.rocket().state::<Quota>() {
+            Some(quota) => quota,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(QuotaError::Unmanaged),
+                ))
+            }
+        };
+
+        let key = match req.headers().get_one(quota.header) {
+            Some(key) => key,
+            None => {
+                return Outcome::Error((
+                    Status::Unauthorized,
+                    ValidationGuardError::Parse(QuotaError::MissingKey),
+                ))
+            }
+        };
+
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, ValidationGuardError::Validation(err))) => {
+                Outcome::Error((status, ValidationGuardError::Validation(err)))
+            }
+            Outcome::Error((status, ValidationGuardError::Parse(err))) => {
+                Outcome::Error((status, ValidationGuardError::Parse(QuotaError::Json(err))))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(Validated(data)) => {
+                if quota.take(key) {
+                    Outcome::Success(ValidatedQuota(data))
+                } else {
+                    Outcome::Error((
+                        Status::TooManyRequests,
+                        ValidationGuardError::Parse(QuotaError::Exhausted),
+                    ))
+                }
+            }
+        }
+    }
+}
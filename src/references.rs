@@ -0,0 +1,78 @@
+//! Referential integrity of ids sent in bodies, checked against the application's repositories
+
+use crate::ValidationContext;
+use std::borrow::Cow;
+use validator::ValidationError;
+
+///  Repository telling whether an entity exists, e.g. backed by a database or a cache of it
+///
+///  Implemented for closures taking the kind of the entity and its id
+pub trait ReferenceStore: Send + Sync {
+    ///  Whether the entity of `kind` with `id` exists
+    fn exists(&self, kind: &str, id: &str) -> bool;
+}
+
+impl<F: Fn(&str, &str) -> bool + Send + Sync> ReferenceStore for F {
+    fn exists(&self, kind: &str, id: &str) -> bool {
+        self(kind, id)
+    }
+}
+
+///  Managed `ReferenceStore` handed to custom validators as a `ValidationContext`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{References, Validate, ValidatedWith};
+///  use validator::ValidationError;
+///
+///  fn known_category(id: &u64, references: &References) -> Result<(), ValidationError> {
+///      references.check("category", id)
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = References)]
+///  pub struct Product {
+///      #[validate(custom(function = "known_category", use_context))]
+///      category_id: u64,
+///  }
+///
+///  #[post("/products", data = "<data>")]
+///  fn create(data: ValidatedWith<Json<Product>>) -> String {
+///      data.into_deep_inner().category_id.to_string()
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![create])
+///          .manage(References::new(|kind: &str, id: &str| kind == "category" && id == "1"))
+///  }
+///  ```
+pub struct References(Box<dyn ReferenceStore>);
+
+impl References {
+    pub fn new(store: impl ReferenceStore + 'static) -> Self {
+        References(Box::new(store))
+    }
+
+    pub fn exists(&self, kind: &str, id: impl ToString) -> bool {
+        self.0.exists(kind, &id.to_string())
+    }
+
+    ///  Fails with an `unknown_reference` error with the params `kind` and `id` if the entity doesn't exist
+    pub fn check(&self, kind: &str, id: impl ToString) -> Result<(), ValidationError> {
+        let id = id.to_string();
+
+        if self.0.exists(kind, &id) {
+            return Ok(());
+        }
+
+        let mut error = ValidationError::new("unknown_reference");
+        error.add_param(Cow::from("kind"), &kind);
+        error.add_param(Cow::from("id"), &id);
+        Err(error)
+    }
+}
+
+impl ValidationContext for References {}
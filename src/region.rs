@@ -0,0 +1,82 @@
+//! Client ip and region handed to custom validators
+
+use crate::ValidationContext;
+use rocket::request::Request;
+use std::net::IpAddr;
+
+///  Managed resolver of the region, e.g. an ISO country code, a client ip belongs to
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::Regions;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(Regions::new(|ip| {
+///          // look the ip up in a geoip database
+///          ip.is_loopback().then(|| "CH".to_string())
+///      }))
+///  }
+///  ```
+#[allow(clippy::type_complexity)]
+pub struct Regions(Box<dyn Fn(IpAddr) -> Option<String> + Send + Sync>);
+
+impl Regions {
+    pub fn new(resolve: impl Fn(IpAddr) -> Option<String> + Send + Sync + 'static) -> Self {
+        Regions(Box::new(resolve))
+    }
+}
+
+///  Ip and region of the client, as a `ValidationContext`
+///
+///  The ip is the one rocket reports for the request (see `Request::client_ip`), the region the one the managed
+///  `Regions` resolves it to. Requests fail with `500 Internal Server Error` when `Regions` isn't managed
+///  ```rust
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{ClientRegion, Validate};
+///  use validator::ValidationError;
+///
+///  fn local_currency(currency: &str, client: &ClientRegion) -> Result<(), ValidationError> {
+///      match (client.region(), currency) {
+///          (Some("CH"), "CHF") | (Some("US"), "USD") => Ok(()),
+///          _ => Err(ValidationError::new("currency_region")),
+///      }
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = ClientRegion)]
+///  pub struct Payment {
+///      #[validate(custom(function = "local_currency", use_context))]
+///      currency: String,
+///  }
+///  ```
+#[derive(Clone, Debug, Default)]
+pub struct ClientRegion {
+    ip: Option<IpAddr>,
+    region: Option<String>,
+}
+
+impl ClientRegion {
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.ip
+    }
+
+    ///  Region of the client, `None` if its ip is unknown or couldn't be resolved
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+}
+
+impl ValidationContext for ClientRegion {
+    fn from_request<'r>(req: &'r Request<'_>) -> Option<&'r Self> {
+        let regions = req.rocket().state::<Regions>()?;
+
+        Some(req.local_cache(|| {
+            let ip = req.client_ip();
+            ClientRegion {
+                ip,
+                region: ip.and_then(|ip| (regions.0)(ip)),
+            }
+        }))
+    }
+}
@@ -0,0 +1,132 @@
+//! Guard stripping or rejecting privileged fields depending on the caller's role
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_failure, validation_outcome, Validate, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::{FromRequest, Request},
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json},
+    },
+};
+use validator::ValidationError;
+
+///  Bodies having fields only privileged callers may set
+pub trait PrivilegedFields {
+    ///  Keys only privileged callers may send
+    const FIELDS: &'static [&'static str];
+
+    ///  Whether privileged keys sent by other callers are dropped instead of failing validation
+    const STRIP: bool = false;
+}
+
+///  Role of the caller, usually provided by an authentication guard
+pub trait Privileged {
+    ///  Whether the caller may set `PrivilegedFields::FIELDS`
+    fn is_privileged(&self) -> bool;
+}
+
+///  Struct used for Request Guards validating the body according to the role `R` of the caller
+///
+///  The role is resolved with `R`'s own `FromRequest` impl and handed to the handler alongside the body
+#[derive(Clone, Debug)]
+pub struct ValidatedRole<T, R>(pub T, pub R);
+
+///  Impl to get type T of `Json`
+impl<T, R> ValidatedRole<Json<T>, R> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T
+impl<T, R> ValidatedRole<T, R> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    ///  Role of the caller
+    #[inline]
+    pub fn role(&self) -> &R {
+        &self.1
+    }
+}
+
+///  Errors of `ValidatedRole` besides validation failures
+#[derive(Debug)]
+pub enum RoleError<'r, E> {
+    ///  The role guard failed
+    Role(E),
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedRole` for `Json`
+///
+///  Privileged fields sent by unprivileged callers are stripped when `PrivilegedFields::STRIP` is set, otherwise they
+///  fail validation with a `privileged` error
+#[rocket::async_trait]
+impl<'r, D, R> FromData<'r> for ValidatedRole<Json<D>, R>
+where
+    D: Validate + PrivilegedFields + DeserializeOwned,
+    R: Privileged + FromRequest<'r> + Send,
+    R::Error: Send,
+{
+    type Error = ValidationGuardError<RoleError<'r, R::Error>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let role = match R::from_request(req).await {
+            Outcome::Success(role) => role,
+            Outcome::Error((status, err)) => {
+                return Outcome::Error((status, ValidationGuardError::Parse(RoleError::Role(err))))
+            }
+            Outcome::Forward(status) => return Outcome::Forward((data, status)),
+        };
+
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err((status, err)) => return Outcome::Error((status, err.map_parse(RoleError::Json))),
+        };
+
+        let mut value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err((status, err)) => return Outcome::Error((status, err.map_parse(RoleError::Json))),
+        };
+
+        if !role.is_privileged() {
+            if let Some(object) = value.as_object_mut() {
+                let mut err = ValidationErrors::new();
+
+                for field in D::FIELDS {
+                    if D::STRIP {
+                        object.remove(*field);
+                    } else if object.contains_key(*field) {
+                        err.add(*field, ValidationError::new("privileged"));
+                    }
+                }
+
+                if !err.is_empty() {
+                    return Outcome::Error(validation_failure::<D, _>(req, err));
+                }
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err((status, err)) => Outcome::Error((status, err.map_parse(RoleError::Json))),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedRole(data, role),
+            ),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Json Schema of the error responses of `validation_catcher`, derived from the type it renders so both can't drift
+
+use crate::Error;
+use rocket::{
+    serde::json::{json, Value},
+    Route,
+};
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{
+        InstanceType, ObjectValidation, RootSchema, Schema, SchemaObject, SubschemaValidation,
+    },
+    schema_for, JsonSchema,
+};
+use std::collections::BTreeMap;
+
+///  Version of the error response schema, bumped whenever the shape of the responses changes
+pub const ERROR_SCHEMA_VERSION: &str = "2";
+
+///  Failed rule of a field, as serialized by `validator`
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct FieldError {
+    ///  Code of the rule, e.g. `length`
+    code: String,
+    ///  Message of the rule, if it has one
+    message: Option<String>,
+    ///  Arguments of the rule and the invalid `value`
+    params: BTreeMap<String, Value>,
+}
+
+///  Schema of `ValidationErrors`, mapping fields to their failed rules, and nested structs or lists to their errors
+pub(crate) fn errors_schema(gen: &mut SchemaGenerator) -> Schema {
+    let field = gen.subschema_for::<Vec<FieldError>>();
+    let nested = SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        ..Default::default()
+    };
+
+    let errors = SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(vec![field, nested.into()]),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+
+    SchemaObject {
+        instance_type: Some(vec![InstanceType::Object, InstanceType::Null].into()),
+        object: Some(Box::new(ObjectValidation {
+            additional_properties: Some(Box::new(errors.into())),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+///  Schema of the errors of several failed guards, by the name of the type each validated
+pub(crate) fn by_type_schema(gen: &mut SchemaGenerator) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        object: Some(Box::new(ObjectValidation {
+            additional_properties: Some(Box::new(errors_schema(gen))),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+///  Json Schema of the bodies sent by `validation_catcher`, `validation_catchers` and `ValidationConfig::catcher`,
+///  for OpenAPI documents, its version given as `x-schema-version`
+///  ```rust
+///  let schema = rocket_validation::error_schema();
+///  let spec = rocket::serde::json::to_value(&schema).unwrap();
+///
+///  assert_eq!(spec["title"], "ValidationErrorResponse");
+///  ```
+pub fn error_schema() -> RootSchema {
+    let mut schema = schema_for!(Error<'static>);
+    schema
+        .schema
+        .extensions
+        .insert("x-schema-version".to_owned(), json!(ERROR_SCHEMA_VERSION));
+    schema
+}
+
+///  OpenAPI 3 response object of `422 Unprocessable Entity`, to put under `responses` of operations with validated
+///  guards, its `application/json` content referencing the `ValidationErrorResponse` schema
+///
+///  The schema and the ones it refers to are added to `components/schemas` of the OpenAPI document `spec`, e.g. the
+///  one generated by `rocket_okapi` serialized to a `Value`, keeping schemas it already holds
+///  ```rust
+///  use rocket::serde::json::json;
+///
+///  let mut spec = json!({ "openapi": "3.0.0", "paths": {} });
+///  let response = rocket_validation::validation_response(&mut spec);
+///
+///  assert_eq!(response["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/ValidationErrorResponse");
+///  assert!(spec["components"]["schemas"]["ValidationErrorResponse"].is_object());
+///  ```
+pub fn validation_response(spec: &mut Value) -> Value {
+    let mut gen = SchemaSettings::openapi3().into_generator();
+    let schema = gen.subschema_for::<Error<'static>>();
+
+    if !spec["components"]["schemas"].is_object() {
+        spec["components"]["schemas"] = json!({});
+    }
+
+    if let Some(schemas) = spec["components"]["schemas"].as_object_mut() {
+        for (name, definition) in gen.take_definitions() {
+            schemas.entry(name).or_insert_with(|| json!(definition));
+        }
+    }
+
+    json!({
+        "description": "The request was well-formed but failed validation",
+        "content": {
+            "application/json": {
+                "schema": schema,
+            },
+        },
+    })
+}
+
+///  OpenAPI path of `route`, its dynamic segments like `<id>` or `<path..>` turned into parameters like `{id}`
+fn openapi_path(route: &Route) -> String {
+    route
+        .uri
+        .path()
+        .split('/')
+        .map(
+            |segment| match segment.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+                Some(name) => format!("{{{}}}", name.trim_end_matches("..")),
+                None => segment.to_owned(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+///  Documents the `422 Unprocessable Entity` responses of `validation_response` on the operations of `routes` found in
+///  the OpenAPI document `spec`, leaving operations which already document a `422` response alone
+///
+///  The guards of a route aren't known to rocket at runtime, so they aren't detected: only the routes passed are
+///  documented, pass the ones using a `Validated*` guard, as mounted. This isn't tied to `rocket_okapi`, the document
+///  can come from any generator
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::json::{json, Json};
+///  use rocket_validation::{Validate, Validated};
+///
+///  # #[derive(Debug, rocket::serde::Deserialize, Validate)]
+///  # #[serde(crate = "rocket::serde")]
+///  # pub struct HelloData {
+///  #     #[validate(length(min = 1))]
+///  #     name: String,
+///  # }
+///  #[post("/hello", data = "<data>")]
+///  fn validated_hello(data: Validated<Json<HelloData>>) -> String {
+///      data.into_deep_inner().name
+///  }
+///
+///  let mut spec = json!({ "openapi": "3.0.0", "paths": { "/hello": { "post": { "responses": {} } } } });
+///  rocket_validation::document_validated_routes(&mut spec, &routes![validated_hello]);
+///
+///  assert!(spec["paths"]["/hello"]["post"]["responses"]["422"].is_object());
+///  ```
+pub fn document_validated_routes<'a>(
+    spec: &mut Value,
+    routes: impl IntoIterator<Item = &'a Route>,
+) {
+    let response = validation_response(spec);
+
+    for route in routes {
+        let method = route.method.as_str().to_ascii_lowercase();
+        let operation = match spec["paths"]
+            .get_mut(openapi_path(route))
+            .and_then(|path| path.get_mut(&method))
+        {
+            Some(operation) if operation.is_object() => operation,
+            _ => continue,
+        };
+
+        if !operation["responses"].is_object() {
+            operation["responses"] = json!({});
+        }
+
+        if let Some(responses) = operation["responses"].as_object_mut() {
+            responses.entry("422").or_insert_with(|| response.clone());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Guard telling the handler which of the validated fields must be encrypted at rest
+
+use crate::{
+    errors::{field_path, walk_mut},
+    provided, validate_json, Validate, Validated, ValidationConfig, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    request::Request,
+    serde::{
+        json::{self, Json, Value},
+        Deserialize,
+    },
+};
+use std::{borrow::Cow, collections::BTreeSet};
+
+///  Replacement of the values of sensitive fields echoed in errors
+const MASK: &str = "****";
+
+///  Sensitive fields of the body of the request, set by `ValidatedSensitive`
+struct CachedSensitive(Option<&'static [&'static str]>);
+
+///  Masks the `value` param of errors of sensitive fields, or removes the `value` param of every error if the managed
+///  `ValidationConfig` doesn't echo values
+pub(crate) fn mask<'r>(
+    req: &'r Request<'_>,
+    errors: Cow<'r, ValidationErrors>,
+) -> Cow<'r, ValidationErrors> {
+    let echo = req
+        .rocket()
+        .state::<ValidationConfig>()
+        .map_or(true, ValidationConfig::echoes_values);
+    let sensitive = req.local_cache(|| CachedSensitive(None)).0;
+
+    if echo && sensitive.is_none() {
+        return errors;
+    }
+
+    let sensitive = sensitive.unwrap_or(&[]);
+    let mut errors = errors.into_owned();
+    walk_mut(&mut errors, &mut |path, error| {
+        if !echo {
+            error.params.remove("value");
+        } else if error.params.contains_key("value")
+            && sensitive.iter().any(|field| *field == field_path(path))
+        {
+            error.params.insert(Cow::from("value"), Value::from(MASK));
+        }
+    });
+
+    Cow::Owned(errors)
+}
+
+///  Bodies with fields which must be encrypted before being stored
+///  ```rust
+///  use rocket_validation::Sensitive;
+///
+///  pub struct Patient {
+///      name: String,
+///      diagnosis: Option<String>,
+///  }
+///
+///  impl Sensitive for Patient {
+///      const SENSITIVE: &'static [&'static str] = &["diagnosis", "insurance.number"];
+///  }
+///  ```
+pub trait Sensitive {
+    ///  Sensitive fields, nested ones with their dotted path
+    const SENSITIVE: &'static [&'static str];
+}
+
+///  Struct used for Request Guards validating the body and collecting the `Sensitive` fields the client sent
+///
+///  Values of sensitive fields echoed in the `value` param of errors are masked as `****` in the responses of the
+///  catchers
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  # use rocket::serde::Deserialize;
+///  # use rocket_validation::{Sensitive, Validate};
+///  # #[derive(Debug, Deserialize, Validate)]
+///  # #[serde(crate = "rocket::serde")]
+///  # pub struct Patient {
+///  #     name: String,
+///  #     diagnosis: Option<String>,
+///  # }
+///  # impl Sensitive for Patient {
+///  #     const SENSITIVE: &'static [&'static str] = &["diagnosis"];
+///  # }
+///  use rocket::serde::json::Json;
+///  use rocket_validation::ValidatedSensitive;
+///
+///  #[post("/patients", data = "<data>")]
+///  fn admit(data: ValidatedSensitive<Json<Patient>>) -> String {
+///      if data.is_sensitive("diagnosis") {
+///          // encrypt the diagnosis before storing it
+///      }
+///      data.into_deep_inner().name
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct ValidatedSensitive<T>(pub T, pub BTreeSet<&'static str>);
+
+///  Impl to get type T of `Json`
+impl<T> ValidatedSensitive<Json<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T
+impl<T> ValidatedSensitive<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    ///  Sensitive fields the client sent, `null` values included
+    pub fn sensitive_fields(&self) -> &BTreeSet<&'static str> {
+        &self.1
+    }
+
+    pub fn is_sensitive(&self, field: &str) -> bool {
+        self.1.contains(field)
+    }
+}
+
+///  Implementation of `ValidatedSensitive` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + Sensitive + Deserialize<'r>> FromData<'r> for ValidatedSensitive<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        req.local_cache(|| CachedSensitive(Some(D::SENSITIVE)));
+        provided::track(req);
+
+        validate_json(req, data, provided::record)
+            .await
+            .map(|Validated(data)| {
+                let provided = provided::take(req);
+                let sensitive = D::SENSITIVE
+                    .iter()
+                    .copied()
+                    .filter(|field| provided.contains(*field))
+                    .collect();
+
+                ValidatedSensitive(data, sensitive)
+            })
+    }
+}
@@ -0,0 +1,116 @@
+//! Validated bodies cached in the request for guards evaluated later
+
+use crate::{Validate, Validated, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{de::DeserializeOwned, json::Json},
+};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+///  Request local storage of a shared body
+struct SharedBody<T>(Mutex<Option<Arc<T>>>);
+
+///  Storage of `T` in the request
+fn shared_body<'r, T: Send + Sync + 'static>(req: &'r Request<'_>) -> &'r Mutex<Option<Arc<T>>> {
+    &req.local_cache(|| SharedBody::<T>(Mutex::new(None))).0
+}
+
+///  Struct used for Request Guards validating the body and caching it in the request
+///
+///  Bodies can only be read once, so guards evaluated afterwards (guards invoked by other data guards, responders,
+///  ...) get the same validated value through `Shared::get` instead of parsing it again
+#[derive(Debug)]
+pub struct ValidatedShared<T>(pub Arc<T>);
+
+impl<T> Clone for ValidatedShared<T> {
+    fn clone(&self) -> Self {
+        ValidatedShared(self.0.clone())
+    }
+}
+
+impl<T> Deref for ValidatedShared<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+///  Implementation of `ValidatedShared` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + DeserializeOwned + Send + Sync + 'static> FromData<'r>
+    for ValidatedShared<Json<D>>
+{
+    type Error = ValidationGuardError<rocket::serde::json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(Validated(data)) => {
+                let shared = Arc::new(data);
+                *shared_body(req)
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner) = Some(shared.clone());
+
+                Outcome::Success(ValidatedShared(shared))
+            }
+        }
+    }
+}
+
+///  Body previously validated by `ValidatedShared`, retrieved with `Shared::get`
+///
+///  `Shared` isn't a request guard: rocket runs the request guards of a route before its data guard, so none of them
+///  could ever see the body. Retrieve it from code running once the body is validated instead, e.g. guards invoked by
+///  a data guard wrapping `ValidatedShared`, responders or fairings
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Shared, Validate};
+///
+///  #[derive(Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Order {
+///      #[validate(range(min = 1))]
+///      quantity: u32,
+///  }
+///
+///  fn quantity(req: &rocket::Request<'_>) -> Option<u32> {
+///      Shared::<Json<Order>>::get(req).map(|order| order.quantity)
+///  }
+///  ```
+#[derive(Debug)]
+pub struct Shared<T>(pub Arc<T>);
+
+impl<T: Send + Sync + 'static> Shared<T> {
+    ///  Body validated by `ValidatedShared` earlier during this request, if any
+    pub fn get(req: &Request<'_>) -> Option<Self> {
+        shared_body::<T>(req)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .map(Shared)
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Shared(self.0.clone())
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
@@ -0,0 +1,43 @@
+//! Client facing error codes independent of `validator`'s own codes
+
+use crate::{errors::walk_mut, ValidationErrors};
+use std::{borrow::Cow, collections::HashMap};
+
+///  Replacements for error codes (`length`, `email`, ...) applied by the catchers
+///
+///  Codes of `validator` may change between versions, managing this keeps the ones seen by clients stable.
+///  Codes without a replacement are sent unchanged
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::ErrorSlugs;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .register("/", catchers![rocket_validation::validation_catcher])
+///          .manage(ErrorSlugs::default().map("length", "invalid_length"))
+///  }
+///  ```
+#[derive(Clone, Debug, Default)]
+pub struct ErrorSlugs(pub HashMap<String, String>);
+
+impl ErrorSlugs {
+    ///  Replaces the error code `code` by `slug`
+    pub fn map(mut self, code: impl Into<String>, slug: impl Into<String>) -> Self {
+        self.0.insert(code.into(), slug.into());
+        self
+    }
+
+    ///  Copy of `errors` with their codes replaced
+    pub(crate) fn apply(&self, errors: &ValidationErrors) -> ValidationErrors {
+        let mut errors = errors.clone();
+
+        walk_mut(&mut errors, &mut |_, error| {
+            if let Some(slug) = self.0.get(&*error.code) {
+                error.code = Cow::Owned(slug.clone());
+            }
+        });
+
+        errors
+    }
+}
@@ -0,0 +1,139 @@
+//! Guard rejecting properties the Json Schema of the body doesn't declare
+
+use crate::{
+    body::{parse_body, read_body},
+    errors::{pointer, Segment},
+    instrument,
+    typed::resolve,
+    validation_failure, validation_outcome, Validate, ValidationErrors, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        json::{self, Json, Value},
+        Deserialize,
+    },
+};
+use schemars::{
+    schema::{RootSchema, Schema, SchemaObject, SingleOrVec},
+    schema_for, JsonSchema,
+};
+use validator::ValidationError;
+
+///  Nesting followed when checking properties, bounding recursive types
+const MAX_DEPTH: usize = 32;
+
+///  Adds an `additional_property` error for every key of `value` its schema doesn't declare
+fn check<'v>(
+    root: &RootSchema,
+    schema: &SchemaObject,
+    value: &'v Value,
+    path: &mut Vec<Segment<'v>>,
+    errors: &mut ValidationErrors,
+) {
+    if path.len() > MAX_DEPTH {
+        return;
+    }
+
+    let schema = resolve(root, schema, 0);
+
+    match value {
+        Value::Object(object) => {
+            let validation = match &schema.object {
+                Some(validation) => validation,
+                None => return,
+            };
+
+            // maps declare the schema of their values instead of properties
+            let closed = validation.additional_properties.is_none()
+                || matches!(
+                    validation.additional_properties.as_deref(),
+                    Some(Schema::Bool(false))
+                );
+
+            for (key, nested) in object {
+                path.push(Segment::Field(key));
+
+                match validation.properties.get(key) {
+                    Some(Schema::Object(property)) => check(root, property, nested, path, errors),
+                    Some(Schema::Bool(_)) => {}
+                    None if closed => {
+                        let mut error = ValidationError::new("additional_property");
+                        error.add_param("property".into(), key);
+                        error.add_param("pointer".into(), &pointer(path));
+                        errors.add("__all__", error);
+                    }
+                    None => {
+                        if let Some(Schema::Object(values)) =
+                            validation.additional_properties.as_deref()
+                        {
+                            check(root, values, nested, path, errors);
+                        }
+                    }
+                }
+
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            if let Some(SingleOrVec::Single(schema)) =
+                schema.array.as_ref().and_then(|a| a.items.as_ref())
+            {
+                if let Schema::Object(schema) = &**schema {
+                    for (index, item) in items.iter().enumerate() {
+                        path.push(Segment::Index(index));
+                        check(root, schema, item, path, errors);
+                        path.pop();
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+///  Struct used for Request Guards rejecting bodies with properties the Json Schema of `T` doesn't declare
+///
+///  Serde ignores unknown properties unless told otherwise, this enforces `additionalProperties: false` on every
+///  object of the body before deserializing it. Maps (`HashMap`, ...) keep accepting any key
+#[derive(Clone, Debug)]
+pub struct ValidatedStrict<T>(pub T);
+
+impl_into_inner!(ValidatedStrict);
+
+///  Implementation of `ValidatedStrict` for `Json`
+///
+///  Every unknown property fails with an `additional_property` error under `__all__`, with the `property` and its
+///  `pointer` as params
+#[rocket::async_trait]
+impl<'r, D: Validate + JsonSchema + Deserialize<'r>> FromData<'r> for ValidatedStrict<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        if let Ok(value) = json::from_str::<Value>(body) {
+            let root = schema_for!(D);
+            let mut err = ValidationErrors::new();
+            check(&root, &root.schema, &value, &mut Vec::new(), &mut err);
+
+            if !err.is_empty() {
+                return Outcome::Error(validation_failure::<D, _>(req, err));
+            }
+        }
+
+        match parse_body::<D>(req, body) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedStrict(data),
+            ),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Validation rule sets selected by the tenant of the request
+
+use crate::ValidationContext;
+use rocket::request::Request;
+use std::{collections::HashMap, ops::Deref};
+
+///  Rule set of a tenant, the `ValidationContext` handed to custom validators
+///
+///  Derefs to the rule set
+#[derive(Clone, Debug)]
+pub struct Tenant<R>(pub R);
+
+impl<R> Deref for Tenant<R> {
+    type Target = R;
+
+    fn deref(&self) -> &R {
+        &self.0
+    }
+}
+
+///  Managed rule sets of `Tenant<R>`, by the tenant id sent in a header
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Tenant, Tenants, Validate, ValidatedWith};
+///  use validator::ValidationError;
+///
+///  pub struct Limits {
+///      max_items: usize,
+///  }
+///
+///  fn within_limit(items: &[String], limits: &Tenant<Limits>) -> Result<(), ValidationError> {
+///      if items.len() > limits.max_items {
+///          return Err(ValidationError::new("max_items"));
+///      }
+///      Ok(())
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = "Tenant<Limits>")]
+///  pub struct Order {
+///      #[validate(custom(function = "within_limit", use_context))]
+///      items: Vec<String>,
+///  }
+///
+///  #[post("/orders", data = "<data>")]
+///  fn order(data: ValidatedWith<Json<Order>>) -> String {
+///      data.into_deep_inner().items.join(", ")
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().mount("/", routes![order]).manage(
+///          Tenants::new("X-Tenant-Id")
+///              .tenant("acme", Limits { max_items: 100 })
+///              .fallback(Limits { max_items: 10 }),
+///      )
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct Tenants<R> {
+    header: &'static str,
+    tenants: HashMap<String, Tenant<R>>,
+    fallback: Option<Tenant<R>>,
+}
+
+impl<R> Tenants<R> {
+    ///  Rule sets selected by the value of `header`
+    pub fn new(header: &'static str) -> Self {
+        Tenants {
+            header,
+            tenants: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    pub fn tenant(mut self, id: impl Into<String>, rules: R) -> Self {
+        self.tenants.insert(id.into(), Tenant(rules));
+        self
+    }
+
+    ///  Rule set of requests without or with an unknown tenant id, which otherwise fail with
+    ///  `500 Internal Server Error`
+    pub fn fallback(mut self, rules: R) -> Self {
+        self.fallback = Some(Tenant(rules));
+        self
+    }
+}
+
+impl<R: Send + Sync + 'static> ValidationContext for Tenant<R> {
+    fn from_request<'r>(req: &'r Request<'_>) -> Option<&'r Self> {
+        let tenants = req.rocket().state::<Tenants<R>>()?;
+
+        req.headers()
+            .get_one(tenants.header)
+            .and_then(|id| tenants.tenants.get(id))
+            .or(tenants.fallback.as_ref())
+    }
+}
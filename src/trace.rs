@@ -0,0 +1,117 @@
+//! Trace ids correlating the errors sent to clients with the log events of the failed validation
+
+use crate::{
+    errors::{dotted, walk},
+    ValidationErrors,
+};
+use rocket::request::Request;
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+///  Once managed, failed validations are logged along a `TraceId`, which the catchers add to their response as
+///  `trace_id`
+///
+///  The id is taken from the `X-Trace-Id` header, or generated if the client didn't send one
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::TraceIds;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .mount("/", routes![/*validated_hello*/])
+///          .register("/", catchers![rocket_validation::validation_catcher])
+///          .manage(TraceIds::default().header("X-Request-Id"))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct TraceIds {
+    header: &'static str,
+}
+
+impl Default for TraceIds {
+    fn default() -> Self {
+        TraceIds {
+            header: "X-Trace-Id",
+        }
+    }
+}
+
+impl TraceIds {
+    ///  Header the trace id of the client is read from
+    pub fn header(mut self, header: &'static str) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+///  Id of the request, the same in its log events and error response
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TraceId(pub String);
+
+impl TraceId {
+    ///  Trace id of `req`, `None` if `TraceIds` isn't managed
+    pub fn of<'r>(req: &'r Request<'_>) -> Option<&'r TraceId> {
+        let trace_ids = req.rocket().state::<TraceIds>()?;
+
+        Some(req.local_cache(
+            || match req.headers().get_one(trace_ids.header).map(str::trim) {
+                Some(id) if !id.is_empty() => TraceId(id.to_owned()),
+                _ => TraceId::generate(),
+            },
+        ))
+    }
+
+    ///  32 random hex digits
+    fn generate() -> Self {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+
+        let [high, low] = [0u8, 1].map(|half| {
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u8(half);
+            hasher.write_u64(count);
+            hasher.write_u128(nanos);
+            hasher.finish()
+        });
+
+        TraceId(format!("{high:016x}{low:016x}"))
+    }
+}
+
+///  Logs the fields of a failed validation with the trace id of `req`, if `TraceIds` is managed
+pub(crate) fn log_failure(req: &Request<'_>, errors: &ValidationErrors) {
+    let trace_id = match TraceId::of(req) {
+        Some(trace_id) => trace_id,
+        None => return,
+    };
+
+    log::warn!(
+        target: "rocket_validation",
+        "validation failed: trace_id={} fields={}",
+        trace_id.0,
+        failed_fields(errors).join(",")
+    );
+}
+
+///  Sorted dotted paths of the fields failing in `errors`, without their values
+pub(crate) fn failed_fields(errors: &ValidationErrors) -> Vec<String> {
+    let mut fields = Vec::new();
+    walk(errors, &mut |path, _| {
+        let field = dotted(path);
+
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    });
+    fields.sort();
+    fields
+}
@@ -0,0 +1,103 @@
+//! Transformations applied to fields of json bodies before validation
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    instrument, validation_outcome, Validate, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+
+///  Step of the pipeline applied to a string field
+#[derive(Clone, Copy, Debug)]
+pub enum Transform {
+    ///  Removes leading and trailing whitespace
+    Trim,
+    Lowercase,
+    Uppercase,
+    ///  Replaces every run of whitespace by a single space
+    CollapseWhitespace,
+    Custom(fn(&str) -> String),
+}
+
+impl Transform {
+    fn apply(self, value: &str) -> String {
+        match self {
+            Transform::Trim => value.trim().to_owned(),
+            Transform::Lowercase => value.to_lowercase(),
+            Transform::Uppercase => value.to_uppercase(),
+            Transform::CollapseWhitespace => value.split_whitespace().collect::<Vec<_>>().join(" "),
+            Transform::Custom(transform) => transform(value),
+        }
+    }
+}
+
+///  Bodies declaring the transformations of their fields
+///  ```rust
+///  use rocket_validation::{Transform, Transforms};
+///
+///  pub struct Signup {
+///      email: String,
+///  }
+///
+///  impl Transforms for Signup {
+///      fn transforms() -> Vec<(&'static str, Vec<Transform>)> {
+///          vec![("email", vec![Transform::Trim, Transform::Lowercase])]
+///      }
+///  }
+///  ```
+pub trait Transforms {
+    ///  Top level fields along with the steps applied to them, in order
+    fn transforms() -> Vec<(&'static str, Vec<Transform>)>;
+}
+
+///  Struct used for Request Guards applying `Transforms` to the body before validating it
+#[derive(Clone, Debug)]
+pub struct ValidatedTransform<T>(pub T);
+
+impl_into_inner!(ValidatedTransform);
+
+///  Implementation of `ValidatedTransform` for `Json`
+///
+///  Only string values are transformed, fields holding anything else are left to deserialization
+#[rocket::async_trait]
+impl<'r, D: Validate + Transforms + DeserializeOwned> FromData<'r> for ValidatedTransform<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let mut value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        if let Some(object) = value.as_object_mut() {
+            for (field, steps) in D::transforms() {
+                if let Some(Value::String(string)) = object.get_mut(field) {
+                    for step in steps {
+                        *string = step.apply(string);
+                    }
+                }
+            }
+        }
+
+        match body_from_value::<D>(req, body, value) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => validation_outcome::<D, _, _, _>(
+                req,
+                instrument::validate(&*data),
+                ValidatedTransform(data),
+            ),
+        }
+    }
+}
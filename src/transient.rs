@@ -0,0 +1,70 @@
+//! Transient validation failures, e.g. a service needed to validate being temporarily unavailable
+
+use crate::{errors::walk, ValidationErrors};
+use rocket::{
+    request::Request,
+    response::{self, Responder},
+};
+use validator::ValidationError;
+
+///  Code of the `ValidationError`s marking a validation as failed transiently
+pub const TRANSIENT_CODE: &str = "transient";
+
+///  Error for custom validators which couldn't decide because of a temporary failure
+///
+///  Guards fail with `503 Service Unavailable` instead of `422 Unprocessable Entity` when one of the errors is
+///  transient, `validation_catcher_unavailable` then asks clients to retry after `retry_after` seconds
+///  ```rust
+///  use rocket_validation::transient;
+///  use validator::ValidationError;
+///
+///  fn validate_coupon(code: &str) -> Result<(), ValidationError> {
+///      // the coupon service didn't answer
+///      Err(transient(30))
+///  }
+///  ```
+pub fn transient(retry_after: u64) -> ValidationError {
+    let mut error = ValidationError::new(TRANSIENT_CODE);
+    error.add_param("retry_after".into(), &retry_after);
+    error
+}
+
+///  Whether any of `errors` is transient
+pub(crate) fn is_transient(errors: &ValidationErrors) -> bool {
+    let mut transient = false;
+    walk(errors, &mut |_, error| {
+        transient |= error.code == TRANSIENT_CODE
+    });
+    transient
+}
+
+///  Shortest delay asked by the transient `errors`
+pub(crate) fn retry_after(errors: &ValidationErrors) -> Option<u64> {
+    let mut retry_after = None::<u64>;
+
+    walk(errors, &mut |_, error| {
+        if error.code == TRANSIENT_CODE {
+            if let Some(seconds) = error.params.get("retry_after").and_then(|v| v.as_u64()) {
+                retry_after = Some(retry_after.map_or(seconds, |current| current.min(seconds)));
+            }
+        }
+    });
+
+    retry_after
+}
+
+///  Responder adding a `Retry-After` header, in seconds, to the response of `R`
+#[derive(Debug)]
+pub struct RetryAfter<R>(pub R, pub Option<u64>);
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for RetryAfter<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.0.respond_to(req)?;
+
+        if let Some(seconds) = self.1 {
+            response.set_raw_header("Retry-After", seconds.to_string());
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,111 @@
+//! Transitions between the states of a resource, e.g. the status of an order
+
+use crate::ValidationContext;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
+use validator::ValidationError;
+
+///  Table of the allowed transitions handed to custom validators as a `ValidationContext`, with the lookup of the
+///  current state of a resource
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::{json::Json, Deserialize};
+///  use rocket_validation::{Transitions, Validate, ValidatedWith};
+///  use validator::ValidationError;
+///
+///  fn transition(update: &StatusUpdate, transitions: &Transitions) -> Result<(), ValidationError> {
+///      transitions.check(&update.order, &update.status)
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = Transitions, schema(function = "transition", use_context))]
+///  pub struct StatusUpdate {
+///      order: String,
+///      status: String,
+///  }
+///
+///  #[put("/orders/status", data = "<data>")]
+///  fn update(data: ValidatedWith<Json<StatusUpdate>>) -> String {
+///      data.into_deep_inner().status
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().mount("/", routes![update]).manage(
+///          Transitions::new(|_order: &str| Some("pending".to_string()))
+///              .allow("pending", ["paid", "cancelled"])
+///              .allow("paid", ["shipped"]),
+///      )
+///  }
+///  ```
+#[allow(clippy::type_complexity)]
+pub struct Transitions {
+    current: Box<dyn Fn(&str) -> Option<String> + Send + Sync>,
+    allowed: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Transitions {
+    ///  Transitions of resources whose current state is looked up by `current` from their id, `None` if the resource
+    ///  doesn't exist
+    pub fn new(current: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        Transitions {
+            current: Box::new(current),
+            allowed: BTreeMap::new(),
+        }
+    }
+
+    ///  Allows transitions from the state `from` to each of `to`
+    pub fn allow<S: Into<String>>(
+        mut self,
+        from: impl Into<String>,
+        to: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.allowed
+            .entry(from.into())
+            .or_default()
+            .extend(to.into_iter().map(Into::into));
+        self
+    }
+
+    ///  States reachable from `from`
+    pub fn allowed(&self, from: &str) -> impl Iterator<Item = &str> {
+        self.allowed
+            .get(from)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    ///  Checks the resource `id` may transition to `to`
+    ///
+    ///  Fails with an `invalid_transition` error with the params `from`, `to` and the `allowed` states, or an
+    ///  `unknown_resource` error with the param `id` if its current state can't be found
+    pub fn check(&self, id: &str, to: &str) -> Result<(), ValidationError> {
+        let from = match (self.current)(id) {
+            Some(from) => from,
+            None => {
+                let mut error = ValidationError::new("unknown_resource");
+                error.add_param(Cow::from("id"), &id);
+                return Err(error);
+            }
+        };
+
+        if self.allowed(&from).any(|allowed| allowed == to) {
+            return Ok(());
+        }
+
+        let mut error = ValidationError::new("invalid_transition");
+        error.add_param(Cow::from("from"), &from);
+        error.add_param(Cow::from("to"), &to);
+        error.add_param(
+            Cow::from("allowed"),
+            &self.allowed(&from).collect::<Vec<_>>(),
+        );
+        Err(error)
+    }
+}
+
+impl ValidationContext for Transitions {}
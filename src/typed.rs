@@ -0,0 +1,175 @@
+//! Json Schema types of failed fields, for clients rendering a widget per type
+
+use crate::{
+    errors::field_path, errors::walk_mut, Validate, Validated, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    request::Request,
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+use schemars::{
+    schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec},
+    schema_for, JsonSchema,
+};
+use std::{borrow::Cow, collections::HashMap};
+
+///  Nesting followed when collecting the types of fields, bounding recursive types
+const MAX_DEPTH: usize = 32;
+
+///  Types of the fields of the body of the request, by dotted path without indices
+struct CachedFieldTypes(Option<HashMap<String, &'static str>>);
+
+fn type_name(instance_type: InstanceType) -> &'static str {
+    match instance_type {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "boolean",
+        InstanceType::Object => "object",
+        InstanceType::Array => "array",
+        InstanceType::Number => "number",
+        InstanceType::String => "string",
+        InstanceType::Integer => "integer",
+    }
+}
+
+///  Schema behind references as well as the `anyOf` of `Option`s and the `allOf` of documented fields
+pub(crate) fn resolve<'s>(
+    root: &'s RootSchema,
+    schema: &'s SchemaObject,
+    depth: usize,
+) -> &'s SchemaObject {
+    if depth > MAX_DEPTH {
+        return schema;
+    }
+
+    if let Some(reference) = &schema.reference {
+        let definition = reference
+            .strip_prefix("#/definitions/")
+            .and_then(|name| root.definitions.get(name));
+
+        if let Some(Schema::Object(definition)) = definition {
+            return resolve(root, definition, depth + 1);
+        }
+    }
+
+    let subschemas = schema.subschemas.as_ref();
+    let candidates = subschemas
+        .and_then(|s| s.any_of.as_ref().or(s.all_of.as_ref()))
+        .into_iter()
+        .flatten();
+
+    for candidate in candidates {
+        if let Schema::Object(candidate) = candidate {
+            let candidate = resolve(root, candidate, depth + 1);
+            if instance_type(candidate) != Some(InstanceType::Null) {
+                return candidate;
+            }
+        }
+    }
+
+    schema
+}
+
+fn instance_type(schema: &SchemaObject) -> Option<InstanceType> {
+    match schema.instance_type.as_ref()? {
+        SingleOrVec::Single(single) => Some(**single),
+        SingleOrVec::Vec(types) => types
+            .iter()
+            .find(|t| **t != InstanceType::Null)
+            .or_else(|| types.first())
+            .copied(),
+    }
+}
+
+fn collect(
+    root: &RootSchema,
+    schema: &SchemaObject,
+    prefix: &str,
+    types: &mut HashMap<String, &'static str>,
+    depth: usize,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+
+    let schema = resolve(root, schema, depth);
+
+    if let Some(object) = &schema.object {
+        for (name, property) in &object.properties {
+            if let Schema::Object(property) = property {
+                let path = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{prefix}.{name}")
+                };
+
+                let property = resolve(root, property, depth);
+                if let Some(instance_type) = instance_type(property) {
+                    types.insert(path.clone(), type_name(instance_type));
+                }
+
+                collect(root, property, &path, types, depth + 1);
+            }
+        }
+    }
+
+    if let Some(SingleOrVec::Single(items)) = schema.array.as_ref().and_then(|a| a.items.as_ref()) {
+        if let Schema::Object(items) = &**items {
+            collect(root, items, prefix, types, depth + 1);
+        }
+    }
+}
+
+///  Types of the fields of `D`, nested fields by their dotted path, elements of arrays sharing the path of the array
+fn field_types<D: JsonSchema>() -> HashMap<String, &'static str> {
+    let root = schema_for!(D);
+    let mut types = HashMap::new();
+    collect(&root, &root.schema, "", &mut types, 0);
+    types
+}
+
+///  Adds the `field_type` param to `errors` of fields whose type is known for the request
+pub(crate) fn apply<'r>(
+    req: &'r Request<'_>,
+    errors: Cow<'r, ValidationErrors>,
+) -> Cow<'r, ValidationErrors> {
+    let types = match &req.local_cache(|| CachedFieldTypes(None)).0 {
+        Some(types) => types,
+        None => return errors,
+    };
+
+    let mut errors = errors.into_owned();
+    walk_mut(&mut errors, &mut |path, error| {
+        if let Some(field_type) = types.get(&field_path(path)) {
+            error.add_param("field_type".into(), field_type);
+        }
+    });
+
+    Cow::Owned(errors)
+}
+
+///  Struct used for Request Guards adding the Json Schema type of the failed fields to validation errors
+///
+///  The catchers render it as the `field_type` param of each error, e.g. `string` or `integer`
+#[derive(Clone, Debug)]
+pub struct ValidatedTyped<T>(pub T);
+
+impl_into_inner!(ValidatedTyped);
+
+///  Implementation of `ValidatedTyped` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + JsonSchema + Deserialize<'r>> FromData<'r> for ValidatedTyped<Json<D>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        req.local_cache(|| CachedFieldTypes(Some(field_types::<D>())));
+
+        <Validated<Json<D>> as FromData<'r>>::from_data(req, data)
+            .await
+            .map(|Validated(data)| ValidatedTyped(data))
+    }
+}
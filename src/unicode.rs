@@ -0,0 +1,114 @@
+//! Unicode normalization of json bodies before validation
+
+use crate::{body::LazyValue, ValidationErrors};
+use rocket::{
+    request::{local_cache, Request},
+    serde::json::Value,
+};
+use unicode_normalization::UnicodeNormalization;
+use validator::ValidationError;
+
+///  Once managed, every json guard normalizes every string (keys and values) of the body to NFC before
+///  deserializing and validating it
+///
+///  Bodies with keys only differing before normalization, e.g. a composed and a decomposed `é`, would lose one of them
+///  and are rejected instead, with a `nfc_key_collision` error under `__all__` holding the `pointer` to the second key
+///
+///  Bodies already in NFC are handed to the guard as sent. Others are re-serialized once normalized, so their objects
+///  come with their keys sorted and keys sent more than once keep their last value only: guards depending on the keys
+///  as sent, like `ValidatedOrdered`, check the body before normalizing it
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(rocket_validation::NormalizeNfc)
+///  }
+///  ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NormalizeNfc;
+
+///  Normalizes every string below `value`, at `location`, flagging whether any `changed`, returns the location of the
+///  first key colliding with another one once normalized, if any
+fn normalize_value(value: &mut Value, location: &mut String, changed: &mut bool) -> Option<String> {
+    match value {
+        Value::String(string) => {
+            let normalized: String = string.nfc().collect();
+            if normalized != *string {
+                *string = normalized;
+                *changed = true;
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                let len = location.len();
+                location.push('/');
+                location.push_str(&index.to_string());
+                if let Some(collision) = normalize_value(item, location, changed) {
+                    return Some(collision);
+                }
+                location.truncate(len);
+            }
+        }
+        Value::Object(object) => {
+            let mut normalized = serde_json::Map::new();
+
+            for (key, mut nested) in std::mem::take(object) {
+                let normalized_key: String = key.nfc().collect();
+                *changed |= normalized_key != key;
+                let key = normalized_key;
+
+                let len = location.len();
+                location.push('/');
+                location.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                if normalized.contains_key(&key) {
+                    return Some(location.clone());
+                }
+                if let Some(collision) = normalize_value(&mut nested, location, changed) {
+                    return Some(collision);
+                }
+                location.truncate(len);
+
+                normalized.insert(key, nested);
+            }
+
+            *object = normalized;
+        }
+        _ => {}
+    }
+
+    None
+}
+
+///  NFC normalized `body` if `NormalizeNfc` is managed, the body as sent if it already is, bodies which aren't valid
+///  json are left to the parser
+pub(crate) fn normalize<'r>(
+    req: &'r Request<'_>,
+    value: &mut LazyValue<'r>,
+) -> Result<&'r str, ValidationErrors> {
+    let body = value.body();
+    if req.rocket().state::<NormalizeNfc>().is_none() {
+        return Ok(body);
+    }
+
+    let value = match value.get() {
+        Some(value) => value,
+        None => return Ok(body),
+    };
+
+    let mut changed = false;
+    if let Some(pointer) = normalize_value(value, &mut String::new(), &mut changed) {
+        let mut error = ValidationError::new("nfc_key_collision");
+        error.add_param("pointer".into(), &pointer);
+
+        let mut errors = ValidationErrors::new();
+        errors.add("__all__", error);
+        return Err(errors);
+    }
+
+    if !changed {
+        return Ok(body);
+    }
+
+    let normalized: &'r String = local_cache!(req, value.to_string());
+    Ok(normalized)
+}
@@ -0,0 +1,136 @@
+//! Guard validating every element of a json array and rejecting duplicated elements
+
+use crate::{
+    body::{body_from_value, parse_body_value, read_body},
+    validation_outcome, Validate, ValidationErrors, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Json, Value},
+    },
+};
+use std::collections::{BTreeMap, HashMap};
+use validator::{ValidationError, ValidationErrorsKind};
+
+///  Struct used for Request Guards validating arrays whose elements must be unique
+///
+///  Elements are compared by their json value with the keys of objects sorted, so two elements sent with their keys in
+///  a different order are duplicates
+#[derive(Clone, Debug)]
+pub struct ValidatedUnique<T>(pub T);
+
+impl_into_inner!(ValidatedUnique);
+
+///  Writes `value` to `out` with the keys of its objects sorted, whatever order the json map keeps them in
+fn canonicalize(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            out.push('{');
+            for (index, (key, nested)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::to_string(key).unwrap_or_default());
+                out.push(':');
+                canonicalize(nested, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (index, nested) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                canonicalize(nested, out);
+            }
+            out.push(']');
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+///  Index of the first occurrence of every element which is a duplicate, by the index of the duplicate
+fn duplicates(elements: &[Value]) -> BTreeMap<usize, usize> {
+    let mut first = HashMap::new();
+    let mut duplicates = BTreeMap::new();
+
+    for (index, element) in elements.iter().enumerate() {
+        let mut canonical = String::new();
+        canonicalize(element, &mut canonical);
+
+        let first = *first.entry(canonical).or_insert(index);
+        if first != index {
+            duplicates.insert(index, first);
+        }
+    }
+
+    duplicates
+}
+
+///  Implementation of `ValidatedUnique` for `Json` arrays
+///
+///  Errors of the elements are listed by index under `__all__`, duplicates fail with a `duplicate` error whose param
+///  `first` is the index of the first occurrence
+#[rocket::async_trait]
+impl<'r, D: Validate + DeserializeOwned> FromData<'r> for ValidatedUnique<Json<Vec<D>>> {
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let value = match parse_body_value::<D>(req, body) {
+            Ok(value) => value,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let duplicates = value
+            .as_array()
+            .map(|elements| duplicates(elements))
+            .unwrap_or_default();
+
+        let data = match body_from_value::<Vec<D>>(req, body, value) {
+            Ok(data) => data,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        let mut items = BTreeMap::new();
+        for (index, element) in data.iter().enumerate() {
+            let mut errors = element
+                .validate()
+                .err()
+                .unwrap_or_else(ValidationErrors::new);
+
+            if let Some(first) = duplicates.get(&index) {
+                let mut error = ValidationError::new("duplicate");
+                error.add_param("first".into(), first);
+                errors.add("__all__", error);
+            }
+
+            if !errors.is_empty() {
+                items.insert(index, Box::new(errors));
+            }
+        }
+
+        let validated = if items.is_empty() {
+            Ok(())
+        } else {
+            let mut err = ValidationErrors::new();
+            err.errors_mut()
+                .insert("__all__", ValidationErrorsKind::List(items));
+            Err(err)
+        };
+
+        validation_outcome::<D, _, _, _>(req, validated, ValidatedUnique(data))
+    }
+}
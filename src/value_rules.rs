@@ -0,0 +1,135 @@
+//! Rules for free-form `Value` fields, addressed by JSON Pointer
+
+use rocket::serde::json::Value;
+use validator::ValidationError;
+
+///  Rule checked against the value found at a path
+#[derive(Clone, Copy, Debug)]
+pub enum ValueRule {
+    ///  The path must exist
+    Required,
+    Object,
+    Array,
+    String,
+    Number,
+    Bool,
+    ///  Objects have at most this many keys
+    MaxKeys(usize),
+    ///  Arrays have at most this many elements
+    MaxItems(usize),
+    ///  Strings have at most this many chars
+    MaxLength(usize),
+    ///  Objects and arrays nest at most this deep, scalars having a depth of 0
+    MaxDepth(usize),
+}
+
+fn depth(value: &Value) -> usize {
+    match value {
+        Value::Object(object) => 1 + object.values().map(depth).max().unwrap_or(0),
+        Value::Array(array) => 1 + array.iter().map(depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+impl ValueRule {
+    ///  Code of the error and, for limits, the limit along with the actual value
+    fn check(self, value: &Value) -> Result<(), (&'static str, Option<(usize, usize)>)> {
+        let (holds, code, limit) = match self {
+            ValueRule::Required => (true, "required", None),
+            ValueRule::Object => (value.is_object(), "object", None),
+            ValueRule::Array => (value.is_array(), "array", None),
+            ValueRule::String => (value.is_string(), "string", None),
+            ValueRule::Number => (value.is_number(), "number", None),
+            ValueRule::Bool => (value.is_boolean(), "bool", None),
+            ValueRule::MaxKeys(max) => {
+                let keys = value.as_object().map_or(0, |object| object.len());
+                (keys <= max, "max_keys", Some((max, keys)))
+            }
+            ValueRule::MaxItems(max) => {
+                let items = value.as_array().map_or(0, Vec::len);
+                (items <= max, "max_items", Some((max, items)))
+            }
+            ValueRule::MaxLength(max) => {
+                let length = value.as_str().map_or(0, |string| string.chars().count());
+                (length <= max, "max_length", Some((max, length)))
+            }
+            ValueRule::MaxDepth(max) => {
+                let depth = depth(value);
+                (depth <= max, "max_depth", Some((max, depth)))
+            }
+        };
+
+        if holds {
+            Ok(())
+        } else {
+            Err((code, limit))
+        }
+    }
+}
+
+///  Rules for a `Value` field, checked from a custom validation function
+///
+///  Paths are JSON Pointers relative to the field, `""` being the field itself. Paths which don't exist are only
+///  checked by `ValueRule::Required`
+///  ```rust
+///  use rocket::serde::{json::Value, Deserialize};
+///  use rocket_validation::{Validate, ValueRule, ValueRules};
+///  use validator::ValidationError;
+///
+///  fn check_metadata(metadata: &Value) -> Result<(), ValidationError> {
+///      ValueRules::new()
+///          .at("", ValueRule::Object)
+///          .at("", ValueRule::MaxKeys(20))
+///          .at("/labels", ValueRule::MaxItems(5))
+///          .check(metadata)
+///  }
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Upload {
+///      #[validate(custom(function = "check_metadata"))]
+///      metadata: Value,
+///  }
+///  ```
+#[derive(Clone, Debug, Default)]
+pub struct ValueRules(Vec<(String, ValueRule)>);
+
+impl ValueRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///  Adds `rule` for the value at `pointer`
+    pub fn at(mut self, pointer: impl Into<String>, rule: ValueRule) -> Self {
+        self.0.push((pointer.into(), rule));
+        self
+    }
+
+    ///  Checks the rules in the order they were added, failing on the first one which doesn't hold
+    ///
+    ///  The error's code is the rule's, e.g. `max_keys`, with the `path` it failed at and, for limits, the `max` and
+    ///  the `actual` value
+    pub fn check(&self, value: &Value) -> Result<(), ValidationError> {
+        for (path, rule) in &self.0 {
+            let result = match value.pointer(path) {
+                Some(found) => rule.check(found),
+                None if matches!(rule, ValueRule::Required) => Err(("required", None)),
+                None => Ok(()),
+            };
+
+            if let Err((code, limit)) = result {
+                let mut error = ValidationError::new(code);
+                error.add_param("path".into(), path);
+
+                if let Some((max, actual)) = limit {
+                    error.add_param("max".into(), &max);
+                    error.add_param("actual".into(), &actual);
+                }
+
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+//! Guard validating json bodies whose type is selected by a discriminator field
+
+use crate::{
+    body::{parse_body_value, parse_failure, read_body},
+    cache_success, instrument, validation_failure, Validate, ValidationErrors,
+    ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::{
+        de::DeserializeOwned,
+        json::{self, Value},
+    },
+};
+use std::{any::Any, collections::HashMap};
+use validator::ValidationError;
+
+///  Outcome of validating a registered type
+type Decoded = Result<Box<dyn Any + Send + Sync>, ValidationErrors>;
+
+///  Deserializes and validates one registered type
+type Decoder = fn(Value) -> Result<Decoded, serde_json::Error>;
+
+fn decode<T: Validate + DeserializeOwned + Send + Sync + 'static>(
+    value: Value,
+) -> Result<Decoded, serde_json::Error> {
+    let data = serde_json::from_value::<T>(value)?;
+
+    Ok(instrument::validate(&data).map(|_| Box::new(data) as Box<dyn Any + Send + Sync>))
+}
+
+///  Registry of the types `ValidatedVariant` dispatches to, keyed by the value of the discriminator field
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{Validate, Variants};
+///
+///  #[derive(Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Cat {
+///      #[validate(range(min = 1, max = 9))]
+///      lives: u8,
+///  }
+///
+///  #[derive(Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  pub struct Dog {
+///      #[validate(length(min = 1))]
+///      name: String,
+///  }
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(Variants::new("type").register::<Cat>("cat").register::<Dog>("dog"))
+///  }
+///  ```
+pub struct Variants {
+    field: &'static str,
+    decoders: HashMap<String, Decoder>,
+}
+
+impl Variants {
+    ///  Creates an empty registry reading the discriminator from `field`
+    pub fn new(field: &'static str) -> Self {
+        Variants {
+            field,
+            decoders: HashMap::new(),
+        }
+    }
+
+    ///  Registers `T` as the type used when the discriminator equals `tag`
+    pub fn register<T: Validate + DeserializeOwned + Send + Sync + 'static>(
+        mut self,
+        tag: impl Into<String>,
+    ) -> Self {
+        self.decoders.insert(tag.into(), decode::<T>);
+        self
+    }
+
+    fn unknown_variant(&self) -> ValidationErrors {
+        let mut tags = self.decoders.keys().collect::<Vec<_>>();
+        tags.sort();
+
+        let mut error = ValidationError::new("unknown_variant");
+        error.add_param("expected".into(), &tags);
+
+        let mut errors = ValidationErrors::new();
+        errors.add(self.field, error);
+        errors
+    }
+}
+
+///  Validated body of one of the types registered in `Variants`
+#[derive(Debug)]
+pub struct ValidatedVariant {
+    tag: String,
+    value: Box<dyn Any + Send + Sync>,
+}
+
+impl ValidatedVariant {
+    ///  Value of the discriminator which selected the type
+    #[inline]
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    ///  Reference to the body if it was deserialized as `T`
+    #[inline]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    ///  Takes the body if it was deserialized as `T`, otherwise gives `self` back
+    pub fn downcast<T: 'static>(self) -> Result<T, Self> {
+        let tag = self.tag;
+
+        match self.value.downcast::<T>() {
+            Ok(value) => Ok(*value),
+            Err(value) => Err(ValidatedVariant { tag, value }),
+        }
+    }
+}
+
+///  Errors of `ValidatedVariant` happening before validation
+#[derive(Debug)]
+pub enum VariantError<'r> {
+    ///  No `Variants` registry is managed by the application
+    Unmanaged,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Reads the discriminator from the raw json, then deserializes and validates the type registered for it
+///
+///  A missing or unregistered discriminator fails validation with an `unknown_variant` error on the discriminator field
+#[rocket::async_trait]
+impl<'r> FromData<'r> for ValidatedVariant {
+    type Error = ValidationGuardError<VariantError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let variants = match req.rocket().state::<Variants>() {
+            Some(variants) => variants,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(VariantError::Unmanaged),
+                ))
+            }
+        };
+
+        let body = match read_body::<Self>(req, data).await {
+            Ok(body) => body,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(VariantError::Json)))
+            }
+        };
+
+        let value = match parse_body_value::<Self>(req, body) {
+            Ok(value) => value,
+            Err((status, err)) => {
+                return Outcome::Error((status, err.map_parse(VariantError::Json)))
+            }
+        };
+
+        let tag = value
+            .get(variants.field)
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+        let decoder = tag
+            .as_deref()
+            .and_then(|tag| variants.decoders.get(tag).copied());
+
+        let (tag, decoder) = match (tag, decoder) {
+            (Some(tag), Some(decoder)) => (tag, decoder),
+            _ => {
+                let err = variants.unknown_variant();
+                return Outcome::Error(validation_failure::<Self, _>(req, err));
+            }
+        };
+
+        match decoder(value) {
+            Err(e) => {
+                let (status, err) = parse_failure::<Self>(req, json::Error::Parse(body, e), None);
+                Outcome::Error((status, err.map_parse(VariantError::Json)))
+            }
+            Ok(Ok(value)) => {
+                cache_success(req);
+                Outcome::Success(ValidatedVariant { tag, value })
+            }
+            Ok(Err(err)) => Outcome::Error(validation_failure::<Self, _>(req, err)),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Guard rejecting clients older than a minimum version before validating their body
+
+use crate::{cache_errors, Validate, Validated, ValidationErrors, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use std::borrow::Cow;
+use validator::ValidationError;
+
+///  Minimum version of the clients accepted by `ValidatedVersion`, read from the `X-Client-Version` header
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::MinClientVersion;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build()
+///          .register("/", catchers![rocket_validation::validation_catcher_upgrade_required])
+///          .manage(MinClientVersion::new("2.4"))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct MinClientVersion {
+    minimum: &'static str,
+    header: &'static str,
+}
+
+impl MinClientVersion {
+    ///  Accepts clients of version `minimum` and newer, e.g. `2.4` or `2.4.1`
+    pub fn new(minimum: &'static str) -> Self {
+        MinClientVersion {
+            minimum,
+            header: "X-Client-Version",
+        }
+    }
+
+    ///  Header the version of the client is read from
+    pub fn header(mut self, header: &'static str) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+///  Numeric parts of a version like `2.4.1`, a leading `v` and pre-release or build suffixes are ignored
+fn parse(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim().trim_start_matches(['v', 'V']);
+    let version = version.split(['-', '+']).next()?;
+
+    version.split('.').map(|part| part.parse().ok()).collect()
+}
+
+///  Whether `version` is older than `minimum`, missing parts counting as `0`
+fn older(version: &[u64], minimum: &[u64]) -> bool {
+    let len = version.len().max(minimum.len());
+    let part = |parts: &[u64], i: usize| parts.get(i).copied().unwrap_or(0);
+
+    (0..len)
+        .map(|i| (part(version, i), part(minimum, i)))
+        .find(|(version, minimum)| version != minimum)
+        .map_or(false, |(version, minimum)| version < minimum)
+}
+
+///  Struct used for Request Guards rejecting clients older than the managed `MinClientVersion` before validating
+///  the body
+///
+///  Clients which are too old or send no version fail with `426 Upgrade Required` and an `upgrade_required` error
+///  on `__all__` with the params `minimum` and `version`, rendered by `validation_catcher_upgrade_required`
+#[derive(Clone, Debug)]
+pub struct ValidatedVersion<T>(pub T);
+
+impl_into_inner!(ValidatedVersion);
+
+///  Errors of `ValidatedVersion` besides validation failures
+#[derive(Debug)]
+pub enum VersionError<'r> {
+    ///  No `MinClientVersion` is managed by the application
+    Unmanaged,
+    ///  The version header isn't a version, fails with `400 Bad Request`
+    Invalid,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedVersion` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for ValidatedVersion<Json<D>> {
+    type Error = ValidationGuardError<VersionError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let config = match req.rocket().state::<MinClientVersion>() {
+            Some(config) => config,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(VersionError::Unmanaged),
+                ))
+            }
+        };
+
+        let version = req.headers().get_one(config.header);
+        let outdated = match version.map(parse) {
+            Some(None) => {
+                return Outcome::Error((
+                    Status::BadRequest,
+                    ValidationGuardError::Parse(VersionError::Invalid),
+                ))
+            }
+            Some(Some(parts)) => {
+                parse(config.minimum).map_or(false, |minimum| older(&parts, &minimum))
+            }
+            None => true,
+        };
+
+        if outdated {
+            let mut error = ValidationError::new("upgrade_required");
+            error.message = Some(Cow::from(
+                "the client is outdated, upgrade it to send this request",
+            ));
+            error.add_param(Cow::from("minimum"), &config.minimum);
+            if let Some(version) = version {
+                error.add_param(Cow::from("version"), &version);
+            }
+
+            let mut err = ValidationErrors::new();
+            err.add("__all__", error);
+
+            cache_errors::<D>(req, &err);
+            return Outcome::Error((
+                Status::UpgradeRequired,
+                ValidationGuardError::Validation(err),
+            ));
+        }
+
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, ValidationGuardError::Validation(err))) => {
+                Outcome::Error((status, ValidationGuardError::Validation(err)))
+            }
+            Outcome::Error((status, ValidationGuardError::Parse(err))) => {
+                Outcome::Error((status, ValidationGuardError::Parse(VersionError::Json(err))))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(Validated(data)) => Outcome::Success(ValidatedVersion(data)),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Non-fatal validation warnings, accepted along with the body and reported in `Warning` headers
+
+use crate::{
+    body::{parse_body, read_body},
+    cache_success,
+    errors::{dotted, walk},
+    validation_failure, ValidationErrors, ValidationGuardError,
+};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    outcome::Outcome,
+    request::Request,
+    response::{self, Responder},
+    serde::{
+        json::{self, Json},
+        Deserialize,
+    },
+};
+
+///  Validation telling fatal errors apart from warnings about soft issues which don't reject the data
+///  ```rust
+///  use rocket_validation::{Validate, ValidateWithWarnings, ValidationErrors};
+///  use validator::ValidationError;
+///
+///  #[derive(Validate)]
+///  pub struct Signup {
+///      #[validate(email)]
+///      email: String,
+///      nickname: Option<String>,
+///  }
+///
+///  impl ValidateWithWarnings for Signup {
+///      fn validate_with_warnings(&self) -> Result<Option<ValidationErrors>, ValidationErrors> {
+///          self.validate()?;
+///
+///          if self.nickname.is_some() {
+///              return Ok(None);
+///          }
+///
+///          let mut warnings = ValidationErrors::new();
+///          warnings.add("nickname", ValidationError::new("recommended"));
+///          Ok(Some(warnings))
+///      }
+///  }
+///  ```
+pub trait ValidateWithWarnings {
+    ///  Errors rejecting the data, or the warnings of valid data, if any
+    fn validate_with_warnings(&self) -> Result<Option<ValidationErrors>, ValidationErrors>;
+}
+
+///  Warnings of the body of the request, set by `ValidatedWithWarnings`
+struct CachedWarnings(Option<ValidationErrors>);
+
+///  Struct used for Request Guards accepting data which passed validation with warnings
+///
+///  Warnings are handed to the handler and, with the `Warned` responder, sent back to the client
+#[derive(Clone, Debug)]
+pub struct ValidatedWithWarnings<T>(pub T, pub Option<ValidationErrors>);
+
+///  Impl to get type T of `Json`
+impl<T> ValidatedWithWarnings<Json<T>> {
+    ///  Consumes the guard, returning the validated data
+    #[inline]
+    pub fn into_deep_inner(self) -> T {
+        self.0 .0
+    }
+}
+
+///  Impl to get type T
+impl<T> ValidatedWithWarnings<T> {
+    ///  Consumes the guard, returning the data it wraps
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    ///  Validated data along with its warnings
+    #[inline]
+    pub fn into_parts(self) -> (T, Option<ValidationErrors>) {
+        (self.0, self.1)
+    }
+
+    pub fn warnings(&self) -> Option<&ValidationErrors> {
+        self.1.as_ref()
+    }
+}
+
+///  Implementation of `ValidatedWithWarnings` for `Json`
+#[rocket::async_trait]
+impl<'r, D: ValidateWithWarnings + Deserialize<'r>> FromData<'r>
+    for ValidatedWithWarnings<Json<D>>
+{
+    type Error = ValidationGuardError<json::Error<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body::<D>(req, data).await {
+            Ok(body) => body,
+            Err(failure) => return Outcome::Error(failure),
+        };
+
+        match parse_body::<D>(req, body) {
+            Err(failure) => Outcome::Error(failure),
+            Ok(data) => match data.validate_with_warnings() {
+                Ok(warnings) => {
+                    let warnings = warnings.filter(|warnings| !warnings.is_empty());
+                    req.local_cache(|| CachedWarnings(warnings.clone()));
+
+                    cache_success(req);
+                    Outcome::Success(ValidatedWithWarnings(data, warnings))
+                }
+                Err(err) => Outcome::Error(validation_failure::<D, _>(req, err)),
+            },
+        }
+    }
+}
+
+///  Responder adding a `Warning: 299 - "<field>: <code>"` header for every warning of the `ValidatedWithWarnings`
+///  guard of the route, the message of the warning taking the place of its code when it has one
+#[derive(Debug)]
+pub struct Warned<R>(pub R);
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Warned<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.0.respond_to(req)?;
+
+        if let Some(warnings) = &req.local_cache(|| CachedWarnings(None)).0 {
+            walk(warnings, &mut |path, warning| {
+                let text = warning.message.as_ref().unwrap_or(&warning.code);
+                let text = format!("{}: {}", dotted(path), text).replace('"', "'");
+
+                response.adjoin_raw_header("Warning", format!("299 - \"{text}\""));
+            });
+        }
+
+        Ok(response)
+    }
+}
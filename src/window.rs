@@ -0,0 +1,142 @@
+//! Guard accepting validated bodies only within a daily time window, e.g. trading hours
+
+use crate::{validation_failure, Validate, Validated, ValidationErrors, ValidationGuardError};
+use rocket::{
+    data::{Data, FromData, Outcome as DataOutcome},
+    http::Status,
+    outcome::Outcome,
+    request::Request,
+    serde::json::{self, Json},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use validator::ValidationError;
+
+///  Minutes in a day
+const DAY: i64 = 24 * 60;
+
+///  Daily window within which `ValidatedWindow` accepts requests
+///
+///  Times are given in hours and minutes at `utc_offset`, UTC by default. Windows whose close precedes their open
+///  span midnight, e.g. `22:00` to `06:00`
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::TimeWindow;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(TimeWindow::new((9, 30), (16, 0)).utc_offset(-5 * 60))
+///  }
+///  ```
+#[allow(clippy::type_complexity)]
+pub struct TimeWindow {
+    open: u32,
+    close: u32,
+    utc_offset: i32,
+    clock: Box<dyn Fn() -> SystemTime + Send + Sync>,
+}
+
+impl TimeWindow {
+    ///  Window from `open` (inclusive) to `close` (exclusive), as `(hours, minutes)`
+    pub fn new(open: (u32, u32), close: (u32, u32)) -> Self {
+        TimeWindow {
+            open: open.0 * 60 + open.1,
+            close: close.0 * 60 + close.1,
+            utc_offset: 0,
+            clock: Box::new(SystemTime::now),
+        }
+    }
+
+    ///  Offset from UTC, in minutes, of the times of the window
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.utc_offset = minutes;
+        self
+    }
+
+    ///  Source of the current time, `SystemTime::now` by default
+    pub fn clock(mut self, clock: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    ///  Whether the current time is within the window
+    pub fn is_open(&self) -> bool {
+        let now = (self.clock)()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64 / 60);
+        let minute = (now + i64::from(self.utc_offset)).rem_euclid(DAY) as u32;
+
+        if self.open <= self.close {
+            self.open <= minute && minute < self.close
+        } else {
+            minute >= self.open || minute < self.close
+        }
+    }
+
+    ///  Error for requests outside the window, with its `open` and `close` times as `HH:MM`
+    fn closed(&self) -> ValidationError {
+        let mut error = ValidationError::new("outside_window");
+        error.add_param("open".into(), &format_time(self.open));
+        error.add_param("close".into(), &format_time(self.close));
+        error
+    }
+}
+
+fn format_time(minutes: u32) -> String {
+    format!("{:02}:{:02}", minutes / 60 % 24, minutes % 60)
+}
+
+///  Struct used for Request Guards accepting validated bodies only while the managed `TimeWindow` is open
+///
+///  Outside the window, valid bodies fail with an `outside_window` error under `__all__`, with the `open` and `close`
+///  times as params
+#[derive(Clone, Debug)]
+pub struct ValidatedWindow<T>(pub T);
+
+impl_into_inner!(ValidatedWindow);
+
+///  Errors of `ValidatedWindow` besides validation failures
+#[derive(Debug)]
+pub enum WindowError<'r> {
+    ///  No `TimeWindow` is managed by the application
+    Unmanaged,
+    ///  The body couldn't be read or deserialized
+    Json(json::Error<'r>),
+}
+
+///  Implementation of `ValidatedWindow` for `Json`
+#[rocket::async_trait]
+impl<'r, D: Validate + rocket::serde::Deserialize<'r>> FromData<'r> for ValidatedWindow<Json<D>> {
+    type Error = ValidationGuardError<WindowError<'r>>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let window = match req.rocket().state::<TimeWindow>() {
+            Some(window) => window,
+            None => {
+                return Outcome::Error((
+                    Status::InternalServerError,
+                    ValidationGuardError::Parse(WindowError::Unmanaged),
+                ))
+            }
+        };
+
+        match <Validated<Json<D>> as FromData<'r>>::from_data(req, data).await {
+            Outcome::Error((status, ValidationGuardError::Validation(err))) => {
+                Outcome::Error((status, ValidationGuardError::Validation(err)))
+            }
+            Outcome::Error((status, ValidationGuardError::Parse(err))) => {
+                Outcome::Error((status, ValidationGuardError::Parse(WindowError::Json(err))))
+            }
+            Outcome::Forward(forward) => Outcome::Forward(forward),
+            Outcome::Success(Validated(data)) => {
+                if window.is_open() {
+                    return Outcome::Success(ValidatedWindow(data));
+                }
+
+                let mut err = ValidationErrors::new();
+                err.add("__all__", window.closed());
+
+                Outcome::Error(validation_failure::<D, _>(req, err))
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Filtering of user content against a list of words
+
+use crate::ValidationContext;
+use std::collections::{BTreeSet, HashSet};
+use validator::ValidationError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Block,
+    Allow,
+}
+
+///  Words strings are checked against by `filtered`, as a `ValidationContext`
+///
+///  Strings are split into words on anything but letters and digits, words are compared regardless of case
+///  ```rust
+///  # #[macro_use] extern crate rocket;
+///  use rocket_validation::Wordlist;
+///
+///  #[launch]
+///  fn rocket() -> _ {
+///      rocket::build().manage(Wordlist::blocklist(["darn", "heck"]).reveal(false))
+///  }
+///  ```
+#[derive(Clone, Debug)]
+pub struct Wordlist {
+    words: HashSet<String>,
+    mode: Mode,
+    reveal: bool,
+}
+
+impl Wordlist {
+    fn new<W: AsRef<str>>(words: impl IntoIterator<Item = W>, mode: Mode) -> Self {
+        Wordlist {
+            words: words
+                .into_iter()
+                .map(|word| word.as_ref().to_lowercase())
+                .collect(),
+            mode,
+            reveal: true,
+        }
+    }
+
+    ///  Strings fail with a `blocked_words` error when containing any of `words`
+    pub fn blocklist<W: AsRef<str>>(words: impl IntoIterator<Item = W>) -> Self {
+        Self::new(words, Mode::Block)
+    }
+
+    ///  Strings fail with an `unlisted_words` error when containing anything but `words`
+    pub fn allowlist<W: AsRef<str>>(words: impl IntoIterator<Item = W>) -> Self {
+        Self::new(words, Mode::Allow)
+    }
+
+    ///  Whether errors list the offending `words` in their params, the default, or only report the failure
+    pub fn reveal(mut self, reveal: bool) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    pub fn check(&self, value: &str) -> Result<(), ValidationError> {
+        let offending: BTreeSet<String> = value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .filter(|word| self.words.contains(word) == (self.mode == Mode::Block))
+            .collect();
+
+        if offending.is_empty() {
+            return Ok(());
+        }
+
+        let mut error = ValidationError::new(match self.mode {
+            Mode::Block => "blocked_words",
+            Mode::Allow => "unlisted_words",
+        });
+
+        if self.reveal {
+            error.add_param("words".into(), &offending);
+        }
+
+        Err(error)
+    }
+}
+
+impl ValidationContext for Wordlist {}
+
+///  Custom validator checking a string against the `Wordlist` context
+///  ```rust
+///  use rocket::serde::Deserialize;
+///  use rocket_validation::{Validate, Wordlist};
+///
+///  #[derive(Debug, Deserialize, Validate)]
+///  #[serde(crate = "rocket::serde")]
+///  #[validate(context = Wordlist)]
+///  pub struct Comment {
+///      #[validate(custom(function = "rocket_validation::filtered", use_context))]
+///      text: String,
+///  }
+///  ```
+pub fn filtered(value: &str, wordlist: &Wordlist) -> Result<(), ValidationError> {
+    wordlist.check(value)
+}
@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[validate(length(min = 4, max = 10))]
+    zip: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+    #[validate(email)]
+    email: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher_ajv])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn ajv_like_errors() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+        age: 101,
+        email: "nope".to_string(),
+        address: Address {
+            zip: "80000000000".to_string(),
+        },
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "errors": [
+                {
+                    "instancePath": "/address/zip",
+                    "keyword": "maxLength",
+                    "message": "must NOT have more than 10 characters",
+                    "params": { "limit": 10 }
+                },
+                {
+                    "instancePath": "/age",
+                    "keyword": "maximum",
+                    "message": "must be <= 100",
+                    "params": { "comparison": "<=", "limit": 100 }
+                },
+                {
+                    "instancePath": "/email",
+                    "keyword": "format",
+                    "message": "must match format \"email\"",
+                    "params": { "format": "email" }
+                },
+                {
+                    "instancePath": "/name",
+                    "keyword": "minLength",
+                    "message": "must NOT have fewer than 3 characters",
+                    "params": { "limit": 3 }
+                }
+            ]
+        })
+    );
+}
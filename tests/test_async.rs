@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+    tokio::time::{sleep, Duration},
+};
+use rocket_validation::{ValidateAsync, ValidatedAsync, ValidationErrors};
+use validator::ValidationError;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Redeem {
+    coupon: String,
+}
+
+#[rocket::async_trait]
+impl ValidateAsync for Redeem {
+    async fn validate_async(&self) -> Result<(), ValidationErrors> {
+        // the coupon service taking its time
+        sleep(Duration::from_millis(10)).await;
+
+        if self.coupon.starts_with("SUMMER") {
+            return Ok(());
+        }
+
+        let mut errors = ValidationErrors::new();
+        errors.add("coupon", ValidationError::new("unknown_coupon"));
+        Err(errors)
+    }
+}
+
+#[post("/redeem", data = "<data>")]
+fn redeem(data: ValidatedAsync<Json<Redeem>>) -> Json<Redeem> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![redeem])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn valid_coupon() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/redeem").json(&Redeem {
+        coupon: "SUMMER24".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn unknown_coupon() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/redeem").json(&Redeem {
+        coupon: "WINTER24".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["coupon"][0]["code"], "unknown_coupon");
+}
+
+#[test]
+pub fn malformed_body() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/redeem")
+        .header(rocket::http::ContentType::JSON)
+        .body("{");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "audit")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+    Build, Rocket,
+};
+use rocket_validation::{AuditEntry, AuditLog, Validate, Validated};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData<'a> {
+    #[validate(length(min = 3))]
+    name: &'a str,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[get("/ping")]
+fn ping() -> &'static str {
+    "pong"
+}
+
+fn rocket(entries: Arc<Mutex<Vec<AuditEntry>>>) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![validated_hello, ping])
+        .attach(
+            AuditLog::new(move |entry: AuditEntry| entries.lock().unwrap().push(entry))
+                .actor(|req| req.headers().get_one("X-User-Id").map(str::to_owned)),
+        )
+}
+
+use rocket::{
+    http::{Header, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn audit_success_and_failure() {
+    let entries = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::tracked(rocket(entries.clone())).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(Header::new("X-User-Id", "42"))
+        .json(&HelloData {
+            name: "Chris",
+            age: 18,
+        });
+    let response: LocalResponse = req.dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "CH",
+        age: 102,
+    });
+    let response: LocalResponse = req.dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let entries = entries.lock().unwrap();
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0].actor.as_deref(), Some("42"));
+    assert_eq!(entries[0].method, "POST");
+    assert_eq!(entries[0].route.as_deref(), Some("/hello"));
+    assert!(entries[0].valid);
+    assert!(entries[0].failed_fields.is_empty());
+    assert_eq!(entries[0].status, 200);
+
+    assert_eq!(entries[1].actor, None);
+    assert!(!entries[1].valid);
+    assert_eq!(entries[1].failed_fields, vec!["age", "name"]);
+    assert_eq!(entries[1].status, 422);
+}
+
+#[test]
+pub fn no_entry_without_validation() {
+    let entries = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::tracked(rocket(entries.clone())).unwrap();
+
+    let response: LocalResponse = client.get("/ping").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    assert!(entries.lock().unwrap().is_empty());
+}
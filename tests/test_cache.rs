@@ -0,0 +1,93 @@
+#![cfg(feature = "cache")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Validate, Validated, ValidationCache};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use validator::ValidationError;
+
+static VALIDATIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn counted(name: &str) -> Result<(), ValidationError> {
+    // only counts the body of `identical_payload_hits_cache`, tests run concurrently
+    if name == "Chris" {
+        VALIDATIONS.fetch_add(1, Ordering::SeqCst);
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3), custom(function = "counted"))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(ValidationCache::new(2))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn send(client: &Client, name: &str) -> Status {
+    let response: LocalResponse = client
+        .post("/hello")
+        .json(&HelloData {
+            name: name.to_string(),
+        })
+        .dispatch();
+
+    response.status()
+}
+
+#[test]
+pub fn identical_payload_hits_cache() {
+    let client = Client::tracked(rocket()).unwrap();
+    let cache = client.rocket().state::<ValidationCache>().unwrap();
+    let before = VALIDATIONS.load(Ordering::SeqCst);
+
+    assert_eq!(send(&client, "Chris"), Status::Ok);
+    assert_eq!(VALIDATIONS.load(Ordering::SeqCst), before + 1);
+    assert_eq!(cache.hits(), 0);
+
+    assert_eq!(send(&client, "Chris"), Status::Ok);
+    assert_eq!(VALIDATIONS.load(Ordering::SeqCst), before + 1);
+    assert_eq!(cache.hits(), 1);
+
+    // failures are cached as well
+    assert_eq!(send(&client, "C"), Status::UnprocessableEntity);
+    assert_eq!(send(&client, "C"), Status::UnprocessableEntity);
+    assert_eq!(cache.hits(), 2);
+}
+
+#[test]
+pub fn least_recently_used_is_evicted() {
+    let client = Client::tracked(rocket()).unwrap();
+    let cache = client.rocket().state::<ValidationCache>().unwrap();
+
+    send(&client, "Alice");
+    send(&client, "Bob");
+    send(&client, "Alice");
+    send(&client, "Carol");
+    assert_eq!(cache.hits(), 1);
+
+    // Bob was the least recently used of the two entries
+    send(&client, "Alice");
+    assert_eq!(cache.hits(), 2);
+    send(&client, "Bob");
+    assert_eq!(cache.hits(), 2);
+}
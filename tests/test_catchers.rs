@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated, ValidationConfig};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket(status: Option<Status>) -> rocket::Rocket<rocket::Build> {
+    let rocket = rocket::build()
+        .mount("/", routes![hello])
+        .register("/", rocket_validation::validation_catchers());
+
+    match status {
+        Some(status) => rocket.manage(ValidationConfig::default().failure_status(status)),
+        None => rocket,
+    }
+}
+
+use rocket::{http::ContentType, local::blocking::Client};
+
+fn post(client: &Client, body: &'static str) -> (Status, Value) {
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(body)
+        .dispatch();
+
+    (response.status(), response.into_json().unwrap())
+}
+
+#[test]
+pub fn validation_errors() {
+    let client = Client::tracked(rocket(None)).unwrap();
+
+    let (status, body) = post(&client, r#"{"name":""}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn parser_errors() {
+    let client = Client::tracked(rocket(None)).unwrap();
+
+    let (status, body) = post(&client, r#"{"name":"#);
+
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["code"], 400);
+    assert_eq!(body["message"], "Bad Request");
+    assert_eq!(body["errors"]["__all__"][0]["code"], "parse");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["line"], 1);
+}
+
+#[test]
+pub fn mismatching_types() {
+    let client = Client::tracked(rocket(None)).unwrap();
+
+    let (status, body) = post(&client, r#"{"name":5}"#);
+
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["code"], 400);
+    assert_eq!(body["message"], "Bad Request");
+    assert_eq!(body["errors"]["__all__"][0]["code"], "parse");
+}
+
+#[test]
+pub fn validation_errors_with_configured_status() {
+    let client = Client::tracked(rocket(Some(Status::BadRequest))).unwrap();
+
+    let (status, body) = post(&client, r#"{"name":""}"#);
+
+    assert_eq!(status, Status::BadRequest);
+    assert_eq!(body["code"], 400);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert_ne!(body["message"], "Bad Request");
+}
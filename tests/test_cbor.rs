@@ -0,0 +1,97 @@
+#![cfg(feature = "cbor")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Cbor, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 0, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Cbor<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+fn cbor(data: &HelloData) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(data, &mut bytes).unwrap();
+    bytes
+}
+
+fn content_type() -> ContentType {
+    ContentType::new("application", "cbor")
+}
+
+#[test]
+pub fn valid_cbor() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .header(content_type())
+        .body(cbor(&HelloData {
+            name: "Chris".to_string(),
+            age: 30,
+        }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["name"], "Chris");
+}
+
+#[test]
+pub fn invalid_cbor() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .header(content_type())
+        .body(cbor(&HelloData {
+            name: "C".to_string(),
+            age: 101,
+        }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert_eq!(body["errors"]["age"][0]["code"], "range");
+}
+
+#[test]
+pub fn malformed_cbor() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .header(content_type())
+        .body([0xff_u8])
+        .dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Coerced, Validate, ValidatedCoerced};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Post {
+    #[validate(length(min = 1))]
+    title: String,
+    #[validate(length(min = 1, max = 3))]
+    tags: Vec<String>,
+    ids: Option<Vec<u64>>,
+}
+
+impl Coerced for Post {
+    const ARRAYS: &'static [&'static str] = &["tags", "ids"];
+}
+
+#[post("/posts", data = "<data>")]
+fn create(data: ValidatedCoerced<Json<Post>>) -> Json<Post> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create])
+        .register("/", rocket_validation::validation_catchers())
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+fn post(client: &Client, body: Value) -> LocalResponse<'_> {
+    client
+        .post("/posts")
+        .header(ContentType::JSON)
+        .body(body.to_string())
+        .dispatch()
+}
+
+#[test]
+pub fn scalar_for_array_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = post(&client, json!({ "title": "Hello", "tags": "x", "ids": 7 }));
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["tags"], json!(["x"]));
+    assert_eq!(body["ids"], json!([7]));
+}
+
+#[test]
+pub fn arrays_and_null_untouched() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = post(
+        &client,
+        json!({ "title": "Hello", "tags": ["x", "y"], "ids": null }),
+    );
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["tags"], json!(["x", "y"]));
+    assert_eq!(body["ids"], Value::Null);
+}
+
+#[test]
+pub fn coerced_value_is_validated() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = post(&client, json!({ "title": "", "tags": "x" }));
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["title"][0]["code"], "length");
+}
+
+#[test]
+pub fn mismatching_type_is_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = post(&client, json!({ "title": "Hello", "tags": [1] }));
+
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["message"], "Bad Request");
+    assert_eq!(body["errors"]["__all__"][0]["code"], "parse");
+}
@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Source, Sourced, Validate, ValidatedCombined};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct NewPost {
+    #[validate(range(min = 1))]
+    user_id: u64,
+    #[validate(range(min = 1, max = 100))]
+    per_page: u16,
+    #[validate(length(min = 3))]
+    title: String,
+}
+
+impl Sourced for NewPost {
+    fn sources() -> Vec<(&'static str, Source)> {
+        vec![("user_id", Source::Path), ("per_page", Source::Query)]
+    }
+}
+
+#[post("/users/<user_id>/posts", data = "<data>")]
+fn new_post(user_id: u64, data: ValidatedCombined<Json<NewPost>>) -> Json<NewPost> {
+    assert_eq!(user_id, data.0.user_id);
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![new_post])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn merges_all_sources() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/users/7/posts?per_page=20")
+        .header(ContentType::JSON)
+        .body(r#"{"title":"Hello"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["user_id"], 7);
+    assert_eq!(body["per_page"], 20);
+    assert_eq!(body["title"], "Hello");
+}
+
+#[test]
+pub fn body_cannot_override_path() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/users/7/posts?per_page=20")
+        .header(ContentType::JSON)
+        .body(r#"{"title":"Hello","user_id":1}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["user_id"], 7);
+}
+
+#[test]
+pub fn failures_across_sources() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/users/0/posts?per_page=500")
+        .header(ContentType::JSON)
+        .body(r#"{"title":"Hi"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["user_id"][0]["code"], "range");
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+    assert_eq!(body["errors"]["title"][0]["code"], "length");
+}
@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{Header, Status},
+    local::blocking::{Client, LocalResponse},
+    request::{self, FromRequest, Request},
+    serde::{json::Value, Deserialize},
+};
+use rocket_validation::{
+    Composite, CompositeError, QueryParams, Validate, Validated, ValidatedHeaders,
+};
+
+#[derive(Debug, FromForm, Validate)]
+struct Paging {
+    #[validate(range(min = 1, max = 100))]
+    per_page: u8,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct ApiClient {
+    #[validate(length(min = 3))]
+    x_client_id: String,
+}
+
+struct Search {
+    paging: Validated<QueryParams<Paging>>,
+    client: ValidatedHeaders<ApiClient>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Search {
+    type Error = CompositeError;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let mut fields = Composite::new(req);
+        let paging = fields.guard().await;
+        let client = fields.guard().await;
+
+        fields.outcome(|| {
+            Some(Search {
+                paging: paging?,
+                client: client?,
+            })
+        })
+    }
+}
+
+#[get("/search")]
+fn search(search: Search) -> String {
+    format!(
+        "{} {}",
+        search.client.into_inner().x_client_id,
+        search.paging.into_deep_inner().per_page
+    )
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![search])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[test]
+pub fn valid_fields() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .get("/search?per_page=10")
+        .header(Header::new("X-Client-Id", "web"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "web 10");
+}
+
+#[test]
+pub fn every_field_reports_errors() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .get("/search?per_page=0")
+        .header(Header::new("X-Client-Id", "w"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+    assert_eq!(body["errors"]["x_client_id"][0]["code"], "length");
+    assert_eq!(body["by_type"].as_object().unwrap().len(), 2);
+}
+
+#[test]
+pub fn single_failing_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .get("/search?per_page=0")
+        .header(Header::new("X-Client-Id", "web"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+    assert!(body["errors"]["x_client_id"].is_null());
+}
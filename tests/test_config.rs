@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::{Client, LocalResponse},
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated, ValidationConfig};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    let config = ValidationConfig::default().failure_status(Status::BadRequest);
+
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", vec![config.catcher()])
+        .manage(config)
+}
+
+#[test]
+pub fn configured_failure_status() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 400);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn valid_request() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "Chris".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn default_failure_status() {
+    let client = Client::tracked(
+        rocket::build()
+            .mount("/", routes![hello])
+            .register("/", vec![ValidationConfig::default().catcher()]),
+    )
+    .unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{AllowedContentTypes, Validate, ValidatedContentType};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData<'a> {
+    #[validate(length(min = 3))]
+    name: &'a str,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: ValidatedContentType<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .manage(AllowedContentTypes(vec![
+            ContentType::JSON,
+            ContentType::parse_flexible("application/json; charset=utf-8").unwrap(),
+        ]))
+}
+
+use rocket::{
+    http::{ContentType, Header, Status},
+    local::blocking::Client,
+};
+
+fn post_as(content_type: &'static str, body: &'static str) -> Status {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(Header::new("Content-Type", content_type))
+        .body(body);
+
+    let response: LocalResponse = req.dispatch();
+
+    response.status()
+}
+
+#[test]
+pub fn exact_content_type() {
+    assert_eq!(
+        post_as("application/json", r#"{"name":"Chris","age":18}"#),
+        Status::Ok
+    );
+}
+
+#[test]
+pub fn allowed_charset_suffix() {
+    assert_eq!(
+        post_as(
+            "application/json; charset=utf-8",
+            r#"{"name":"Chris","age":18}"#
+        ),
+        Status::Ok
+    );
+}
+
+#[test]
+pub fn disallowed_charset_suffix() {
+    assert_eq!(
+        post_as(
+            "application/json; charset=iso-8859-1",
+            r#"{"name":"Chris","age":18}"#
+        ),
+        Status::UnsupportedMediaType
+    );
+}
+
+#[test]
+pub fn disallowed_content_type() {
+    assert_eq!(
+        post_as("text/plain", r#"{"name":"Chris","age":18}"#),
+        Status::UnsupportedMediaType
+    );
+}
+
+#[test]
+pub fn allowed_content_type_invalid_body() {
+    assert_eq!(
+        post_as("application/json", r#"{"name":"CH","age":18}"#),
+        Status::UnprocessableEntity
+    );
+}
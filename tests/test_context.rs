@@ -0,0 +1,105 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedWith, ValidationContext};
+use validator::ValidationError;
+
+#[derive(Clone)]
+struct Limits {
+    max_items: usize,
+}
+
+impl ValidationContext for Limits {}
+
+fn within_limit(items: &[String], limits: &Limits) -> Result<(), ValidationError> {
+    if items.len() > limits.max_items {
+        let mut error = ValidationError::new("too_many_items");
+        error.add_param("max".into(), &limits.max_items);
+        return Err(error);
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = Limits)]
+struct Cart {
+    #[validate(custom(function = "within_limit", use_context))]
+    items: Vec<String>,
+}
+
+#[post("/carts", data = "<data>")]
+fn create(data: ValidatedWith<Json<Cart>>) -> Json<Cart> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket(limits: Option<Limits>) -> rocket::Rocket<rocket::Build> {
+    let rocket = rocket::build()
+        .mount("/", routes![create])
+        .register("/", catchers![rocket_validation::validation_catcher]);
+
+    match limits {
+        Some(limits) => rocket.manage(limits),
+        None => rocket,
+    }
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn within_managed_context() {
+    let client = Client::tracked(rocket(Some(Limits { max_items: 2 }))).unwrap();
+
+    let req = client
+        .post("/carts")
+        .header(ContentType::JSON)
+        .body(r#"{"items":["apple","pear"]}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn failing_managed_context() {
+    let client = Client::tracked(rocket(Some(Limits { max_items: 2 }))).unwrap();
+
+    let req = client
+        .post("/carts")
+        .header(ContentType::JSON)
+        .body(r#"{"items":["apple","pear","plum"]}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let error = &body["errors"]["items"][0];
+
+    assert_eq!(error["code"], "too_many_items");
+    assert_eq!(error["params"]["max"], 2);
+}
+
+#[test]
+pub fn unmanaged_context() {
+    let client = Client::tracked(rocket(None)).unwrap();
+
+    let req = client
+        .post("/carts")
+        .header(ContentType::JSON)
+        .body(r#"{"items":["apple"]}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::InternalServerError);
+}
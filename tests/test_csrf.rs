@@ -0,0 +1,138 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    form::Form,
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{CsrfStore, CsrfTokens, Validate, ValidatedCsrf};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate, FromForm)]
+#[serde(crate = "rocket::serde")]
+struct Comment {
+    #[validate(length(min = 3))]
+    text: String,
+}
+
+struct Sessions;
+
+impl CsrfStore for Sessions {
+    fn token(&self, session: &str) -> Option<String> {
+        match session {
+            "session-1" => Some("token-1".to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[post("/comments", data = "<data>")]
+fn comment(data: ValidatedCsrf<Form<Comment>>) -> Json<Comment> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![comment])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(CsrfTokens::new(Sessions))
+}
+
+use rocket::{
+    http::{ContentType, Cookie, Header, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn matching_token() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::Form)
+        .cookie(Cookie::new("session", "session-1"))
+        .body("text=Hello&csrf_token=token-1");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn matching_token_in_header() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::Form)
+        .header(Header::new("X-CSRF-Token", "token-1"))
+        .cookie(Cookie::new("session", "session-1"))
+        .body("text=Hello");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn mismatching_token() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::Form)
+        .cookie(Cookie::new("session", "session-1"))
+        .body("text=Hello&csrf_token=token-2");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Forbidden);
+}
+
+#[test]
+pub fn missing_session() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::Form)
+        .body("text=Hello&csrf_token=token-1");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Forbidden);
+}
+
+#[test]
+pub fn token_checked_before_validation() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::Form)
+        .cookie(Cookie::new("session", "session-1"))
+        .body("text=Hi&csrf_token=token-2");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Forbidden);
+}
+
+#[test]
+pub fn validates_after_token() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::Form)
+        .cookie(Cookie::new("session", "session-1"))
+        .body("text=Hi&csrf_token=token-1");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<rocket::serde::json::Value>().unwrap();
+    assert_eq!(body["errors"]["text"][0]["code"], "length");
+}
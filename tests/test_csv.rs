@@ -0,0 +1,58 @@
+#![cfg(feature = "csv")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3), email(message = "must be an email, like \"a@b.ch\""))]
+    name: String,
+    #[validate(range(min = 0, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher_csv])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn errors_as_csv_rows() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+        age: 101,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.content_type(), Some(ContentType::CSV));
+    assert_eq!(
+        response.into_string().unwrap(),
+        "field,code,message\r\n\
+         age,range,\r\n\
+         name,email,\"must be an email, like \"\"a@b.ch\"\"\"\r\n\
+         name,length,\r\n"
+    );
+}
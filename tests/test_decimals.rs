@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{max_precision, max_scale, Validate, Validated};
+use validator::ValidationError;
+
+fn cents(amount: &f64) -> Result<(), ValidationError> {
+    max_scale(amount, 2)
+}
+
+fn rate(rate: &str) -> Result<(), ValidationError> {
+    max_precision(rate, 5, 4)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Payment {
+    #[validate(custom(function = "cents"))]
+    amount: f64,
+    #[validate(custom(function = "rate"))]
+    rate: String,
+}
+
+#[post("/payments", data = "<data>")]
+fn pay(data: Validated<Json<Payment>>) -> Json<Payment> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![pay])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn payment(amount: f64, rate: &str) -> Payment {
+    Payment {
+        amount,
+        rate: rate.to_string(),
+    }
+}
+
+#[test]
+pub fn within_scale() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").json(&payment(10.05, "1.2500"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn over_scale() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").json(&payment(10.005, "1.25"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["amount"][0];
+    assert_eq!(error["code"], "decimal_scale");
+    assert_eq!(error["params"]["max"], 2);
+    assert_eq!(error["params"]["scale"], 3);
+}
+
+#[test]
+pub fn over_precision() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").json(&payment(10.0, "12.5"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["rate"][0];
+    assert_eq!(error["code"], "decimal_precision");
+    assert_eq!(error["params"]["precision"], 5);
+    assert_eq!(error["params"]["scale"], 4);
+    assert_eq!(error["params"]["integer_digits"], 2);
+}
+
+#[test]
+pub fn not_a_decimal() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").json(&payment(1.0, "1,5"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["rate"][0]["code"], "decimal");
+}
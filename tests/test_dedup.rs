@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Deduplicated, Validate, ValidatedDedup};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Stock {
+    #[validate(length(min = 1))]
+    sku: String,
+    warehouse: String,
+    #[validate(range(max = 1000))]
+    quantity: u32,
+}
+
+impl Deduplicated for Stock {
+    fn key_components(&self) -> Vec<String> {
+        vec![self.sku.clone(), self.warehouse.clone()]
+    }
+}
+
+#[put("/stock", data = "<data>")]
+fn upsert(data: ValidatedDedup<Json<Stock>>) -> String {
+    data.key().0.clone()
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![upsert])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn stock(sku: &str, warehouse: &str, quantity: u32) -> Stock {
+    Stock {
+        sku: sku.to_string(),
+        warehouse: warehouse.to_string(),
+        quantity,
+    }
+}
+
+#[test]
+pub fn computed_key() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.put("/stock").json(&stock(" ABC-1 ", "Zürich", 5));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "abc-1|zürich");
+}
+
+#[test]
+pub fn separator_is_escaped() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.put("/stock").json(&stock("a|b", "c", 5));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "a\\|b|c");
+}
+
+#[test]
+pub fn invalid_body() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.put("/stock").json(&stock("abc-1", "Bern", 5000));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
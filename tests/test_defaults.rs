@@ -0,0 +1,108 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedWithDefaults};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Sorting {
+    field: String,
+    descending: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Search {
+    #[validate(length(min = 3))]
+    term: String,
+    #[validate(range(min = 1, max = 100))]
+    per_page: u8,
+    sorting: Sorting,
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Search {
+            term: String::new(),
+            per_page: 20,
+            sorting: Sorting {
+                field: "created_at".to_string(),
+                descending: true,
+            },
+        }
+    }
+}
+
+#[post("/search", data = "<search>")]
+fn search(search: ValidatedWithDefaults<Json<Search>>) -> Json<Search> {
+    Json(search.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![search])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[test]
+pub fn missing_fields_are_filled() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/search")
+        .header(ContentType::JSON)
+        .body(r#"{"term":"rocket","sorting":{"field":"name"}}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["term"], "rocket");
+    assert_eq!(body["per_page"], 20);
+    assert_eq!(body["sorting"]["field"], "name");
+    assert_eq!(body["sorting"]["descending"], true);
+}
+
+#[test]
+pub fn sent_fields_are_validated() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/search")
+        .header(ContentType::JSON)
+        .body(r#"{"term":"rocket","per_page":0}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+}
+
+#[test]
+pub fn defaults_are_validated() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/search")
+        .header(ContentType::JSON)
+        .body("{}")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["term"][0]["code"], "length");
+}
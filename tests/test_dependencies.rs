@@ -0,0 +1,122 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Dependent, Validate, ValidatedDependencies};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Checkout {
+    account: Option<String>,
+    billing_address: Option<String>,
+    #[validate(length(min = 5))]
+    shipping_address: Option<String>,
+}
+
+impl Dependent for Checkout {
+    const DEPENDENCIES: &'static [(&'static str, &'static [&'static str])] = &[
+        ("billing_address", &["account"]),
+        ("shipping_address", &["billing_address"]),
+    ];
+}
+
+#[post("/checkout", data = "<data>")]
+fn checkout(data: ValidatedDependencies<Json<Checkout>>) -> Json<Checkout> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![checkout])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+fn post(body: &'static str) -> (Status, Option<Value>) {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/checkout")
+        .header(ContentType::JSON)
+        .body(body)
+        .dispatch();
+
+    (response.status(), response.into_json())
+}
+
+#[test]
+pub fn satisfied_dependencies() {
+    let (status, _) =
+        post(r#"{"account":"alice","billing_address":"1 Main St","shipping_address":"2 Side St"}"#);
+
+    assert_eq!(status, Status::Ok);
+}
+
+#[test]
+pub fn missing_direct_prerequisite() {
+    let (status, body) = post(r#"{"account":"alice","shipping_address":"2 Side St"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let errors = &body.unwrap()["errors"];
+    let error = &errors["shipping_address"][0];
+
+    assert_eq!(error["code"], "missing_prerequisite");
+    assert_eq!(
+        error["params"]["missing"],
+        rocket::serde::json::json!(["billing_address"])
+    );
+    assert!(errors.get("billing_address").is_none());
+}
+
+#[test]
+pub fn missing_transitive_prerequisites() {
+    let (status, body) = post(r#"{"billing_address":null,"shipping_address":"2 Side St"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let error = &body.unwrap()["errors"]["shipping_address"][0];
+
+    assert_eq!(
+        error["params"]["missing"],
+        rocket::serde::json::json!(["billing_address", "account"])
+    );
+}
+
+#[test]
+pub fn broken_dependency_on_an_intermediate_field() {
+    let (status, body) = post(r#"{"billing_address":"1 Main St"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let error = &body.unwrap()["errors"]["billing_address"][0];
+
+    assert_eq!(error["code"], "missing_prerequisite");
+    assert_eq!(
+        error["params"]["missing"],
+        rocket::serde::json::json!(["account"])
+    );
+}
+
+#[test]
+pub fn validated_once_dependencies_hold() {
+    let (status, body) =
+        post(r#"{"account":"alice","billing_address":"1 Main St","shipping_address":"2"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+    assert_eq!(
+        body.unwrap()["errors"]["shipping_address"][0]["code"],
+        "length"
+    );
+}
@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Deprecated, DeprecatedFields, Validate, ValidatedDeprecated};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(length(min = 3))]
+    username: Option<String>,
+}
+
+impl DeprecatedFields for HelloData {
+    fn deprecated_fields(&self) -> Vec<&'static str> {
+        match self.username {
+            Some(_) => vec!["username"],
+            None => vec![],
+        }
+    }
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: ValidatedDeprecated<Json<HelloData>>) -> Deprecated<Json<HelloData>> {
+    Deprecated(Json(data.into_deep_inner()))
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn deprecated_field_sets_warning() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: None,
+        username: Some("Chris".to_string()),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.headers().get_one("Warning"),
+        Some("299 - \"Deprecated field: username\"")
+    );
+}
+
+#[test]
+pub fn current_fields_send_no_warning() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: Some("Chris".to_string()),
+        username: None,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Warning"), None);
+}
+
+#[test]
+pub fn invalid_deprecated_field_fails() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: None,
+        username: Some("C".to_string()),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.headers().get_one("Warning"), None);
+}
@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Deprecated, DeprecationNotice, Deprecations, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/v1/hello", data = "<data>")]
+fn hello_v1(data: Validated<Json<HelloData>>) -> Deprecated<Json<HelloData>> {
+    Deprecated(Json(data.into_deep_inner()))
+}
+
+#[post("/v2/hello", data = "<data>")]
+fn hello_v2(data: Validated<Json<HelloData>>) -> Deprecated<Json<HelloData>> {
+    Deprecated(Json(data.into_deep_inner()))
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello_v1, hello_v2])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(Deprecations::default().route(
+            "hello_v1",
+            DeprecationNotice::new("@1735689599").sunset("Wed, 31 Dec 2025 23:59:59 GMT"),
+        ))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn deprecated_route_sets_headers() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/v1/hello").json(&HelloData {
+        name: "Chris".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.headers().get_one("Deprecation"),
+        Some("@1735689599")
+    );
+    assert_eq!(
+        response.headers().get_one("Sunset"),
+        Some("Wed, 31 Dec 2025 23:59:59 GMT")
+    );
+}
+
+#[test]
+pub fn other_route_has_no_headers() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/v2/hello").json(&HelloData {
+        name: "Chris".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Deprecation"), None);
+    assert_eq!(response.headers().get_one("Sunset"), None);
+}
+
+#[test]
+pub fn still_validates() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/v1/hello").json(&HelloData {
+        name: "C".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct User {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+impl User {
+    fn greeting(&self) -> String {
+        format!("Hello {}", self.name)
+    }
+}
+
+#[post("/users", data = "<user>")]
+fn create(mut user: Validated<Json<User>>) -> String {
+    user.name.push('!');
+    format!("{} ({})", user.greeting(), user.name.len())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![create])
+}
+
+#[test]
+pub fn fields_and_methods_through_deref() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/users")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris"}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "Hello Chris! (6)");
+}
+
+#[test]
+pub fn single_deref_gives_wrapped_guard() {
+    let mut data = Validated(Json(User {
+        name: "Chris".to_string(),
+    }));
+
+    let json: &Json<User> = &data;
+    assert_eq!(json.0.name, "Chris");
+
+    data.name = "Alex".to_string();
+    assert_eq!(data.into_deep_inner().name, "Alex");
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "digest")]
+
+#[macro_use]
+extern crate rocket;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Validate, ValidatedDigest};
+use sha2::{Digest, Sha256};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData<'a> {
+    #[validate(length(min = 3))]
+    name: &'a str,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: ValidatedDigest<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![validated_hello])
+}
+
+use rocket::{
+    http::{ContentType, Header, Status},
+    local::blocking::Client,
+};
+
+fn digest_of(body: &str) -> Header<'static> {
+    Header::new(
+        "Digest",
+        format!(
+            "sha-256={}",
+            STANDARD.encode(Sha256::digest(body.as_bytes()))
+        ),
+    )
+}
+
+#[test]
+pub fn matching_digest() {
+    let client = Client::tracked(rocket()).unwrap();
+    let body = r#"{"name":"Chris","age":18}"#;
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .header(digest_of(body))
+        .body(body);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+}
+
+#[test]
+pub fn mismatching_digest() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .header(digest_of(r#"{"name":"Chris","age":18}"#))
+        .body(r#"{"name":"Chris","age":19}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[test]
+pub fn missing_digest() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","age":18}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[test]
+pub fn matching_digest_invalid_body() {
+    let client = Client::tracked(rocket()).unwrap();
+    let body = r#"{"name":"CH","age":18}"#;
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .header(digest_of(body))
+        .body(body);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,115 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedEach};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Item {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+#[post("/items", data = "<data>")]
+fn items(data: ValidatedEach<Json<Vec<Item>>>) -> Json<Vec<Item>> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![items])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+fn flat_rocket() -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![items])
+        .register("/", catchers![rocket_validation::validation_catcher_flat])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+const INVALID: &str = r#"[
+    {"name":"apples","quantity":3},
+    {"name":"pears","quantity":0},
+    {"name":"","quantity":1}
+]"#;
+
+#[test]
+pub fn valid_elements() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/items")
+        .header(ContentType::JSON)
+        .body(r#"[{"name":"apples","quantity":3},{"name":"pears","quantity":2}]"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn every_invalid_element_is_reported() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/items")
+        .header(ContentType::JSON)
+        .body(INVALID);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let items = body["errors"]["__all__"].as_object().unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items["1"]["quantity"][0]["code"], "range");
+    assert_eq!(items["2"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn paths_report_the_index() {
+    let client = Client::tracked(flat_rocket()).unwrap();
+
+    let req = client
+        .post("/items")
+        .header(ContentType::JSON)
+        .body(INVALID);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let errors = body["errors"].as_object().unwrap();
+
+    assert!(errors.contains_key("[1].quantity"));
+    assert!(errors.contains_key("[2].name"));
+}
+
+#[test]
+pub fn empty_array_is_valid() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/items").header(ContentType::JSON).body("[]");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "echo_input")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{EchoLimit, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Comment {
+    #[validate(length(min = 1, max = 16))]
+    text: String,
+}
+
+#[post("/comments", data = "<data>")]
+fn create(data: Validated<Json<Comment>>) -> Json<Comment> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create])
+        .register("/", catchers![rocket_validation::validation_catcher_echo])
+        .manage(EchoLimit(32))
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn echoes_the_body() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::JSON)
+        .body(r#"{"text":""}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["text"][0]["code"], "length");
+    assert_eq!(body["received"]["body"], r#"{"text":""}"#);
+    assert_eq!(body["received"]["size"], 11);
+    assert_eq!(body["received"]["truncated"], false);
+}
+
+#[test]
+pub fn truncates_long_bodies() {
+    let client = Client::tracked(rocket()).unwrap();
+    let sent = format!(r#"{{"text":"{}"}}"#, "a".repeat(64));
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::JSON)
+        .body(sent.clone());
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["received"]["body"], sent[..32]);
+    assert_eq!(body["received"]["size"], sent.len());
+    assert_eq!(body["received"]["truncated"], true);
+}
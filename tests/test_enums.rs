@@ -0,0 +1,90 @@
+#![cfg(feature = "enums")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{known_variant, CaseInsensitive, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+enum Status {
+    Active,
+    Suspended,
+    PendingReview,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Account {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(custom(function = "known_variant"))]
+    status: CaseInsensitive<Status>,
+}
+
+#[post("/accounts", data = "<data>")]
+fn create_account(data: Validated<Json<Account>>) -> Json<Account> {
+    let account = data.into_deep_inner();
+    assert!(account.status.known().is_some());
+    Json(account)
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create_account])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status as HttpStatus},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn wrong_case_matches() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/accounts")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"chris","status":"PENDING_REVIEW"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), HttpStatus::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["status"], "pending_review");
+}
+
+#[test]
+pub fn unknown_value_suggests_variants() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/accounts")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"chris","status":"Actve"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), HttpStatus::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["status"][0];
+    assert_eq!(error["code"], "unknown_variant");
+    assert_eq!(error["params"]["value"], "Actve");
+    assert_eq!(error["params"]["suggestions"], json!(["active"]));
+    assert_eq!(
+        error["params"]["expected"],
+        json!(["active", "suspended", "pending_review"])
+    );
+}
@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::LocalResponse,
+    response::Response,
+    serde::{json::Json, Deserialize, Serialize},
+    Build, Rocket,
+};
+use rocket_validation::{ErrorHandler, Validate, Validated};
+use std::io::Cursor;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket(with_handler: bool) -> Rocket<Build> {
+    let rocket = rocket::build()
+        .mount("/", routes![validated_hello])
+        .register(
+            "/",
+            catchers![rocket_validation::validation_catcher_handler],
+        );
+
+    if !with_handler {
+        return rocket;
+    }
+
+    let mut failures = 0;
+    rocket.manage(ErrorHandler::new(move |errors| {
+        failures += 1;
+
+        let mut fields: Vec<_> = errors.errors().keys().copied().collect();
+        fields.sort_unstable();
+        let body = format!("invalid: {}", fields.join(","));
+
+        Response::build()
+            .status(Status::BadRequest)
+            .raw_header("X-Failures", failures.to_string())
+            .sized_body(body.len(), Cursor::new(body))
+            .finalize()
+    }))
+}
+
+use rocket::local::blocking::Client;
+
+#[test]
+pub fn closure_builds_response() {
+    let client = Client::tracked(rocket(true)).unwrap();
+
+    for failures in 1..=2 {
+        let req = client.post("/hello").json(&HelloData {
+            name: "C".to_string(),
+            age: 0,
+        });
+
+        let response: LocalResponse = req.dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(
+            response.headers().get_one("X-Failures"),
+            Some(failures.to_string().as_str())
+        );
+        assert_eq!(response.into_string().unwrap(), "invalid: age,name");
+    }
+}
+
+#[test]
+pub fn falls_back_without_handler() {
+    let client = Client::tracked(rocket(false)).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+        age: 18,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<rocket::serde::json::Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
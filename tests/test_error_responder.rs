@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    request::Request,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+    Build, Rocket,
+};
+use rocket_validation::{
+    ErrorResponder, Validate, Validated, ValidationErrorResponder, ValidationErrors,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", format = "application/json", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+struct FieldList;
+
+impl ValidationErrorResponder for FieldList {
+    fn to_response(&self, errors: Option<&ValidationErrors>, req: &Request<'_>) -> Json<Value> {
+        let mut fields: Vec<_> = errors
+            .map(|errors| errors.errors().keys().copied().collect())
+            .unwrap_or_default();
+        fields.sort_unstable();
+
+        Json(json!({
+            "path": req.uri().path().as_str(),
+            "invalid": fields,
+        }))
+    }
+}
+
+fn rocket(with_responder: bool) -> Rocket<Build> {
+    let rocket = rocket::build()
+        .mount("/", routes![validated_hello])
+        .register(
+            "/",
+            catchers![rocket_validation::validation_catcher_responder],
+        );
+
+    if with_responder {
+        rocket.manage(ErrorResponder::new(FieldList))
+    } else {
+        rocket
+    }
+}
+
+#[test]
+pub fn responder_shapes_body() {
+    let client = Client::tracked(rocket(true)).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Ch","age":0}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["path"], "/hello");
+    assert_eq!(body["invalid"], json!(["age", "name"]));
+}
+
+#[test]
+pub fn parse_errors_are_bad_requests() {
+    let client = Client::tracked(rocket(true)).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","age":"old"}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[test]
+pub fn falls_back_to_validation_catcher() {
+    let client = Client::tracked(rocket(false)).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Ch","age":0}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
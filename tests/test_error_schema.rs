@@ -0,0 +1,200 @@
+#![cfg(feature = "okapi")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{self, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{error_schema, Validate, Validated, ERROR_SCHEMA_VERSION};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[validate(length(equal = 4, message = "zip must have 4 digits"))]
+    zip: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Person {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[post("/people", data = "<data>")]
+fn create(data: Validated<Json<Person>>) -> Json<Person> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+fn resolve<'s>(root: &'s Value, schema: &'s Value) -> &'s Value {
+    match schema["$ref"].as_str() {
+        Some(reference) => {
+            let name = reference.trim_start_matches("#/definitions/");
+            resolve(root, &root["definitions"][name])
+        }
+        None => schema,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_u64() || n.is_i64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+///  Checks `value` against the subset of Json Schema the generated schema uses
+fn conforms(root: &Value, schema: &Value, value: &Value) -> bool {
+    let schema = resolve(root, schema);
+
+    if let Some(any_of) = schema["anyOf"].as_array() {
+        return any_of.iter().any(|schema| conforms(root, schema, value));
+    }
+
+    let actual = type_name(value);
+    let allowed = match &schema["type"] {
+        Value::String(t) => vec![t.as_str()],
+        Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    };
+    if !allowed.is_empty()
+        && !allowed
+            .iter()
+            .any(|t| *t == actual || (*t == "number" && actual == "integer"))
+    {
+        return false;
+    }
+
+    match value {
+        Value::Object(object) => {
+            let properties = schema["properties"].as_object();
+            let required_present = schema["required"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .all(|key| object.contains_key(key));
+
+            required_present
+                && object.iter().all(|(key, value)| {
+                    match properties.and_then(|properties| properties.get(key)) {
+                        Some(property) => conforms(root, property, value),
+                        None => match &schema["additionalProperties"] {
+                            Value::Bool(false) => false,
+                            Value::Null | Value::Bool(true) => true,
+                            additional => conforms(root, additional, value),
+                        },
+                    }
+                })
+        }
+        Value::Array(items) => match &schema["items"] {
+            Value::Null => true,
+            item => items.iter().all(|value| conforms(root, item, value)),
+        },
+        _ => true,
+    }
+}
+
+#[test]
+pub fn schema_is_versioned() {
+    let spec = json::to_value(error_schema()).unwrap();
+
+    assert_eq!(spec["title"], "ValidationErrorResponse");
+    assert_eq!(spec["x-schema-version"], ERROR_SCHEMA_VERSION);
+
+    let properties = spec["properties"].as_object().unwrap();
+    for property in ["code", "message", "errors", "trace_id"] {
+        assert!(properties.contains_key(property), "missing {property}");
+    }
+}
+
+#[test]
+pub fn catcher_body_matches_schema() {
+    let spec = json::to_value(error_schema()).unwrap();
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/people")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Al","address":{"zip":"123"}}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert!(body["errors"]["address"].is_object());
+    assert!(conforms(&spec, &spec, &body), "{body} doesn't match {spec}");
+}
+
+#[test]
+pub fn schema_rejects_other_shapes() {
+    let spec = json::to_value(error_schema()).unwrap();
+    let body = rocket::serde::json::json!({
+        "code": "422",
+        "message": "Unprocessable Entity",
+        "errors": null
+    });
+
+    assert!(!conforms(&spec, &spec, &body));
+}
+
+#[test]
+pub fn validation_response_registers_schemas() {
+    let mut spec = rocket::serde::json::json!({
+        "openapi": "3.0.0",
+        "paths": {},
+        "components": { "schemas": { "Person": { "type": "object" } } }
+    });
+
+    let response = rocket_validation::validation_response(&mut spec);
+
+    assert_eq!(
+        response["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/ValidationErrorResponse"
+    );
+    assert!(response["description"].is_string());
+
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+    assert!(schemas.contains_key("Person"));
+    assert!(schemas["ValidationErrorResponse"]["properties"]
+        .as_object()
+        .unwrap()
+        .contains_key("errors"));
+
+    // every reference resolves within the document
+    let rendered = json::to_string(&spec).unwrap();
+    for reference in rendered.split("\"$ref\":\"").skip(1) {
+        let name = reference
+            .split('"')
+            .next()
+            .unwrap()
+            .trim_start_matches("#/components/schemas/");
+        assert!(schemas.contains_key(name), "unresolved {name}");
+    }
+}
@@ -0,0 +1,116 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Evolving, SchemaVersions, Validate, ValidatedCompatible};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct OrderPlaced {
+    #[validate(length(min = 1))]
+    id: String,
+    total: Option<f64>,
+    currency: String,
+}
+
+impl Evolving for OrderPlaced {
+    const SCHEMA: &'static str = "order_placed";
+}
+
+#[post("/events", data = "<data>")]
+fn publish(data: ValidatedCompatible<Json<OrderPlaced>>) -> Json<OrderPlaced> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![publish])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(SchemaVersions::default().schema(
+            "order_placed",
+            json!({
+                "type": "object",
+                "required": ["id", "total"],
+                "properties": {
+                    "id": { "type": "string" },
+                    "total": { "type": "number" }
+                }
+            }),
+        ))
+}
+
+fn publish_event(client: &Client, body: Value) -> LocalResponse<'_> {
+    client
+        .post("/events")
+        .header(ContentType::JSON)
+        .body(body.to_string())
+        .dispatch()
+}
+
+#[test]
+pub fn compatible_payload() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = publish_event(
+        &client,
+        json!({ "id": "o-1", "total": 12.5, "currency": "CHF" }),
+    );
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn incompatible_schema_change() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    // `total` became optional, readers of the previous version require it
+    let response = publish_event(&client, json!({ "id": "o-1", "currency": "CHF" }));
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["__all__"][0];
+    assert_eq!(error["code"], "incompatible_schema");
+    assert_eq!(error["params"]["pointer"], "/total");
+    assert_eq!(error["params"]["reason"], "missing");
+}
+
+#[test]
+pub fn incompatible_type() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = publish_event(
+        &client,
+        json!({ "id": "o-1", "total": null, "currency": "CHF" }),
+    );
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["__all__"][0];
+    assert_eq!(error["params"]["reason"], "type");
+    assert_eq!(error["params"]["expected"], "number");
+}
+
+#[test]
+pub fn compatible_but_invalid() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = publish_event(
+        &client,
+        json!({ "id": "", "total": 12.5, "currency": "CHF" }),
+    );
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["id"][0]["code"], "length");
+}
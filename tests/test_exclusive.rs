@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    serde::{json::Json, Deserialize, Serialize},
+    tokio::time::{sleep, Duration},
+};
+use rocket_validation::{Resource, ResourceLocks, Validate, ValidatedExclusive};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Transfer {
+    account: String,
+    #[validate(range(min = 1))]
+    amount: u64,
+}
+
+impl Resource for Transfer {
+    fn resource_id(&self) -> String {
+        self.account.clone()
+    }
+}
+
+#[post("/transfers", data = "<data>")]
+async fn transfer(data: ValidatedExclusive<Json<Transfer>>) -> Json<Transfer> {
+    sleep(Duration::from_millis(200)).await;
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![transfer])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(ResourceLocks::new(1))
+}
+
+use rocket::{http::Status, local::asynchronous::Client};
+
+fn transfer_of(account: &str, amount: u64) -> Transfer {
+    Transfer {
+        account: account.to_string(),
+        amount,
+    }
+}
+
+#[rocket::async_test]
+async fn concurrent_requests_for_same_id() {
+    let client = Client::tracked(rocket()).await.unwrap();
+
+    let first = client.post("/transfers").json(&transfer_of("a", 10));
+    let second = client.post("/transfers").json(&transfer_of("a", 20));
+
+    let (first, second) = rocket::tokio::join!(first.dispatch(), second.dispatch());
+
+    assert_eq!(first.status(), Status::Ok);
+    assert_eq!(second.status(), Status::Conflict);
+
+    let locks = client.rocket().state::<ResourceLocks>().unwrap();
+    assert_eq!(locks.in_flight("a"), 0);
+
+    let third = client
+        .post("/transfers")
+        .json(&transfer_of("a", 30))
+        .dispatch()
+        .await;
+    assert_eq!(third.status(), Status::Ok);
+}
+
+#[rocket::async_test]
+async fn concurrent_requests_for_other_ids() {
+    let client = Client::tracked(rocket()).await.unwrap();
+
+    let first = client.post("/transfers").json(&transfer_of("a", 10));
+    let second = client.post("/transfers").json(&transfer_of("b", 20));
+
+    let (first, second) = rocket::tokio::join!(first.dispatch(), second.dispatch());
+
+    assert_eq!(first.status(), Status::Ok);
+    assert_eq!(second.status(), Status::Ok);
+}
+
+#[rocket::async_test]
+async fn invalid_request_takes_no_slot() {
+    let client = Client::tracked(rocket()).await.unwrap();
+
+    let first = client.post("/transfers").json(&transfer_of("a", 0));
+    let second = client.post("/transfers").json(&transfer_of("a", 20));
+
+    let (first, second) = rocket::tokio::join!(first.dispatch(), second.dispatch());
+
+    assert_eq!(first.status(), Status::UnprocessableEntity);
+    assert_eq!(second.status(), Status::Ok);
+}
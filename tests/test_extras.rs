@@ -0,0 +1,105 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{ExtraRules, Validate, Validated, ValueRule, ValueRules};
+use std::collections::HashMap;
+use validator::ValidationError;
+
+fn check_extra(extra: &HashMap<String, Value>) -> Result<(), ValidationError> {
+    ExtraRules::new()
+        .max_count(3)
+        .key_pattern("x-*")
+        .values(ValueRules::new().at("", ValueRule::String))
+        .check(extra)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Event {
+    #[validate(length(min = 1))]
+    name: String,
+    #[serde(flatten)]
+    #[validate(custom(function = "check_extra"))]
+    extra: HashMap<String, Value>,
+}
+
+#[post("/events", data = "<data>")]
+fn create(data: Validated<Json<Event>>) -> Json<Event> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+fn post(body: &'static str) -> (Status, Option<Value>) {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/events")
+        .header(ContentType::JSON)
+        .body(body)
+        .dispatch();
+
+    (response.status(), response.into_json())
+}
+
+#[test]
+pub fn valid_extras() {
+    let (status, _) = post(r#"{"name":"signup","x-source":"web","x-campaign":"spring"}"#);
+
+    assert_eq!(status, Status::Ok);
+}
+
+#[test]
+pub fn key_not_matching_pattern() {
+    let (status, body) = post(r#"{"name":"signup","x-source":"web","source":"web"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let error = &body.unwrap()["errors"]["extra"][0];
+
+    assert_eq!(error["code"], "extra_field_key");
+    assert_eq!(error["params"]["key"], "source");
+    assert_eq!(error["params"]["patterns"][0], "x-*");
+}
+
+#[test]
+pub fn too_many_extras() {
+    let (status, body) = post(r#"{"name":"signup","x-a":"1","x-b":"2","x-c":"3","x-d":"4"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let error = &body.unwrap()["errors"]["extra"][0];
+
+    assert_eq!(error["code"], "max_extra_fields");
+    assert_eq!(error["params"]["max"], 3);
+    assert_eq!(error["params"]["actual"], 4);
+}
+
+#[test]
+pub fn invalid_extra_value() {
+    let (status, body) = post(r#"{"name":"signup","x-source":42}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let error = &body.unwrap()["errors"]["extra"][0];
+
+    assert_eq!(error["code"], "string");
+    assert_eq!(error["params"]["key"], "x-source");
+}
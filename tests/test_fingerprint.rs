@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    fairing::AdHoc,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Fingerprint, SchemaFingerprints, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+    tags: Option<Vec<Value>>,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(SchemaFingerprints)
+        .attach(AdHoc::on_response("Fingerprint", |req, res| {
+            Box::pin(async move {
+                if let Some(fingerprint) = Fingerprint::of(req) {
+                    res.set_raw_header("X-Fingerprint", fingerprint.0);
+                }
+            })
+        }))
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+fn fingerprint(client: &Client, body: &'static str) -> (Status, String) {
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(body)
+        .dispatch();
+
+    let fingerprint = response
+        .headers()
+        .get_one("X-Fingerprint")
+        .unwrap()
+        .to_string();
+    (response.status(), fingerprint)
+}
+
+#[test]
+pub fn stable_for_same_key_set() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let (status, first) = fingerprint(&client, r#"{"name":"Chris","age":18}"#);
+    assert_eq!(status, Status::Ok);
+    assert_eq!(first.len(), 16);
+
+    let (_, reordered) = fingerprint(&client, r#"{"age":42,"name":"Alex"}"#);
+    assert_eq!(first, reordered);
+
+    let (status, invalid) = fingerprint(&client, r#"{"name":"C","age":0}"#);
+    assert_eq!(status, Status::UnprocessableEntity);
+    assert_eq!(first, invalid);
+}
+
+#[test]
+pub fn differs_for_other_key_sets() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let (_, plain) = fingerprint(&client, r#"{"name":"Chris","age":18}"#);
+    let (_, tagged) = fingerprint(&client, r#"{"name":"Chris","age":18,"tags":[{"id":1}]}"#);
+    let (_, other_tags) = fingerprint(&client, r#"{"name":"Chris","age":18,"tags":[{"key":1}]}"#);
+
+    assert_ne!(plain, tagged);
+    assert_ne!(tagged, other_tags);
+}
@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{FeatureFlags, Validate, ValidatedWith};
+use validator::ValidationError;
+
+fn strict_name(name: &str, flags: &FeatureFlags) -> Result<(), ValidationError> {
+    if flags.is_enabled("strict_names") && !name.chars().all(char::is_alphanumeric) {
+        return Err(ValidationError::new("strict_name"));
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = FeatureFlags)]
+struct Signup {
+    #[validate(length(min = 3), custom(function = "strict_name", use_context))]
+    name: String,
+}
+
+#[post("/signup", data = "<data>")]
+fn signup(data: ValidatedWith<Json<Signup>>) -> Json<Signup> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![signup])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(FeatureFlags::default().with("strict_names", false))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn rule_toggled_by_flag() {
+    let client = Client::tracked(rocket()).unwrap();
+    let signup = Signup {
+        name: "chris-1".to_string(),
+    };
+
+    let response: LocalResponse = client.post("/signup").json(&signup).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let flags = client.rocket().state::<FeatureFlags>().unwrap();
+    flags.set("strict_names", true);
+
+    let response: LocalResponse = client.post("/signup").json(&signup).dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "strict_name");
+
+    flags.set("strict_names", false);
+
+    let response: LocalResponse = client.post("/signup").json(&signup).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{flatten_errors, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[validate(length(equal = 4, message = "zip must have 4 digits"))]
+    zip: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Child {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Person {
+    #[validate(length(min = 3), email(message = "name must be an email"))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    children: Vec<Child>,
+}
+
+#[post("/people", data = "<data>")]
+fn create(data: Validated<Json<Person>>) -> Json<Person> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create])
+        .register("/", catchers![rocket_validation::validation_catcher_flat])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn invalid_person() -> Person {
+    Person {
+        name: "C".to_string(),
+        address: Address {
+            zip: "80".to_string(),
+        },
+        children: vec![
+            Child {
+                name: "Alex".to_string(),
+            },
+            Child {
+                name: String::new(),
+            },
+        ],
+    }
+}
+
+#[test]
+pub fn flat_catcher() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/people").json(&invalid_person());
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let mut body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 422);
+
+    body["errors"]["name"]
+        .as_array_mut()
+        .unwrap()
+        .sort_by_key(|message| message.to_string());
+    assert_eq!(
+        body["errors"],
+        json!({
+            "name": ["name failed the length validation", "name must be an email"],
+            "address.zip": ["zip must have 4 digits"],
+            "children[1].name": ["children[1].name failed the length validation"]
+        })
+    );
+}
+
+#[test]
+pub fn flatten_errors_function() {
+    let errors = invalid_person().validate().unwrap_err();
+    let flat = flatten_errors(&errors);
+
+    assert_eq!(flat.len(), 3);
+    assert_eq!(flat["address.zip"], ["zip must have 4 digits"]);
+    assert_eq!(flat["children[1].name"].len(), 1);
+}
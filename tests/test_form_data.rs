@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    form::Form,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Validate, FromForm)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Form<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn valid_form() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::Form)
+        .body("name=Chris&age=18");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["name"], "Chris");
+    assert_eq!(body["age"], 18);
+}
+
+#[test]
+pub fn invalid_form() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::Form)
+        .body("name=Ch&age=0");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert_eq!(body["errors"]["age"][0]["code"], "range");
+}
+
+#[test]
+pub fn unparsable_form() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::Form)
+        .body("name=Chris&age=old");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["__all__"][0]["code"], "invalid");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["field"], "age");
+}
+
+#[test]
+pub fn missing_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::Form)
+        .body("name=Chris");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["__all__"][0]["code"], "required");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["field"], "age");
+}
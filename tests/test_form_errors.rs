@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    form::{Contextual, Form},
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Validate, FromForm)]
+#[serde(crate = "rocket::serde")]
+struct Signup {
+    #[validate(length(min = 3, message = "name is too short"))]
+    name: String,
+    #[validate(range(min = 18))]
+    age: u8,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+struct FieldError {
+    name: Option<String>,
+    message: String,
+    value: Option<String>,
+}
+
+#[post("/signup", data = "<form>")]
+fn signup(form: Form<Contextual<'_, Validated<Signup>>>) -> Json<Vec<FieldError>> {
+    let mut errors: Vec<FieldError> = form
+        .context
+        .errors()
+        .map(|error| FieldError {
+            name: error.name.as_ref().map(|name| name.to_string()),
+            message: error.kind.to_string(),
+            value: error.value.as_ref().map(|value| value.to_string()),
+        })
+        .collect();
+    errors.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Json(errors)
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![signup])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn errors_keep_their_message() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/signup")
+        .header(ContentType::Form)
+        .body("name=Al&age=12");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body[0]["name"], "age");
+    assert_eq!(body[0]["message"], "range");
+    assert_eq!(body[0]["value"], "12");
+    assert_eq!(body[1]["name"], "name");
+    assert_eq!(body[1]["message"], "name is too short");
+    assert_eq!(body[1]["value"], "Al");
+}
@@ -0,0 +1,126 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Freshness, Validate, ValidatedFresh};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Event {
+    #[validate(length(min = 1))]
+    kind: String,
+}
+
+#[post("/webhook", data = "<data>")]
+fn webhook(data: ValidatedFresh<Json<Event>>) -> Json<Event> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![webhook])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(Freshness::new(300))
+}
+
+use rocket::{
+    http::{Header, Status},
+    local::blocking::Client,
+};
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn send(client: &Client, timestamp: i64, nonce: &'static str, kind: &str) -> Status {
+    let response: LocalResponse = client
+        .post("/webhook")
+        .header(Header::new("X-Timestamp", timestamp.to_string()))
+        .header(Header::new("X-Nonce", nonce))
+        .json(&Event {
+            kind: kind.to_string(),
+        })
+        .dispatch();
+
+    response.status()
+}
+
+#[test]
+pub fn fresh_request() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(send(&client, now(), "nonce-1", "created"), Status::Ok);
+    assert_eq!(send(&client, now() - 60, "nonce-2", "created"), Status::Ok);
+}
+
+#[test]
+pub fn stale_request() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(
+        send(&client, now() - 3600, "nonce-1", "created"),
+        Status::Unauthorized
+    );
+    assert_eq!(
+        send(&client, now() + 3600, "nonce-2", "created"),
+        Status::Unauthorized
+    );
+}
+
+#[test]
+pub fn overflowing_timestamp() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(
+        send(&client, i64::MIN, "nonce-1", "created"),
+        Status::Unauthorized
+    );
+    assert_eq!(
+        send(&client, i64::MAX, "nonce-2", "created"),
+        Status::Unauthorized
+    );
+}
+
+#[test]
+pub fn replayed_request() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let timestamp = now();
+    assert_eq!(send(&client, timestamp, "nonce-1", "created"), Status::Ok);
+    assert_eq!(
+        send(&client, timestamp, "nonce-1", "created"),
+        Status::Conflict
+    );
+}
+
+#[test]
+pub fn missing_headers() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/webhook")
+        .json(&Event {
+            kind: "created".to_string(),
+        })
+        .dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
+
+#[test]
+pub fn validates_fresh_body() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(
+        send(&client, now(), "nonce-1", ""),
+        Status::UnprocessableEntity
+    );
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "garde")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    form::Form,
+    http::{ContentType, Header, Status},
+    local::blocking::{Client, LocalResponse},
+    request::{self, FromRequest, Request},
+    serde::{
+        json::{Json, Value},
+        Deserialize,
+    },
+    Build, Rocket,
+};
+use rocket_validation::ValidatedGarde;
+
+#[derive(Debug, Deserialize, garde::Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[garde(length(min = 4))]
+    zip: String,
+}
+
+#[derive(Debug, Deserialize, garde::Validate, FromForm)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[derive(Debug, Deserialize, garde::Validate)]
+#[serde(crate = "rocket::serde")]
+struct Signup {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(dive)]
+    address: Address,
+}
+
+#[derive(Debug, Default)]
+struct MinLength(usize);
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(MinLength))]
+struct ApiKey(#[garde(custom(min_length))] String);
+
+fn min_length(value: &str, context: &MinLength) -> garde::Result {
+    if value.len() < context.0 {
+        return Err(garde::Error::new(format!("shorter than {}", context.0)));
+    }
+    Ok(())
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ApiKey {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match req.headers().get_one("X-Api-Key") {
+            Some(key) => request::Outcome::Success(ApiKey(key.to_string())),
+            None => request::Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+#[post("/signup", data = "<data>")]
+fn signup(data: ValidatedGarde<Json<Signup>>) -> String {
+    data.into_deep_inner().name
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: ValidatedGarde<Form<HelloData>>) -> String {
+    data.into_deep_inner().name
+}
+
+#[get("/key")]
+fn key(key: ValidatedGarde<ApiKey>) -> String {
+    key.into_inner().0
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![signup, hello, key])
+        .register("/", rocket_validation::validation_catchers())
+        .manage(MinLength(8))
+}
+
+#[test]
+pub fn valid_json() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/signup")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","address":{"zip":"8000"}}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "Chris");
+}
+
+#[test]
+pub fn invalid_json_errors_hold_paths() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/signup")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Ch","address":{"zip":"80"}}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["name"][0]["code"], "garde");
+    assert_eq!(body["errors"]["name"][0]["params"]["path"], "name");
+    assert_eq!(body["errors"]["address"]["zip"][0]["code"], "garde");
+    assert_eq!(
+        body["errors"]["address"]["zip"][0]["params"]["path"],
+        "address.zip"
+    );
+    assert!(body["errors"].get("__all__").is_none());
+}
+
+#[test]
+pub fn invalid_form() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::Form)
+        .body("name=Chris&age=0")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["age"][0]["code"], "garde");
+    assert_eq!(body["errors"]["age"][0]["params"]["path"], "age");
+}
+
+#[test]
+pub fn request_guard_uses_managed_context() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .get("/key")
+        .header(Header::new("X-Api-Key", "abc"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    // the field of a tuple struct is keyed by its position
+    assert!(body["errors"]
+        .to_string()
+        .contains(r#""message":"shorter than 8""#));
+
+    let response: LocalResponse = client
+        .get("/key")
+        .header(Header::new("X-Api-Key", "abcdefgh"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -0,0 +1,80 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct ProfileData<'a> {
+    #[validate(length(min = 3))]
+    name: &'a str,
+    #[validate(length(min = 10))]
+    bio: &'a str,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/profile", data = "<data>")]
+fn validated_profile(data: Validated<Json<ProfileData>>) -> Json<ProfileData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_profile])
+        .register(
+            "/",
+            catchers![rocket_validation::validation_catcher_grouped],
+        )
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn errors_grouped_by_rule() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/profile").json(&ProfileData {
+        name: "CH",
+        bio: "short",
+        age: 102,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+
+    let body = response.into_json::<Value>().unwrap();
+
+    assert_eq!(
+        body["errors"],
+        json!({ "length": ["bio", "name"], "range": ["age"] })
+    );
+}
+
+#[test]
+pub fn valid_profile() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/profile").json(&ProfileData {
+        name: "Chris",
+        bio: "Writes rocket guards",
+        age: 18,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+    serde::{
+        json::{self, json, Json},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated, ValidationGuardError};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(
+    data: Result<Validated<Json<HelloData>>, ValidationGuardError<json::Error<'_>>>,
+) -> (Status, String) {
+    match data {
+        Ok(data) => (Status::Ok, data.into_deep_inner().name),
+        Err(ValidationGuardError::Parse(_)) => (Status::BadRequest, "parse".to_string()),
+        Err(ValidationGuardError::Validation(errors)) => {
+            let mut fields: Vec<_> = errors.errors().keys().copied().collect();
+            fields.sort_unstable();
+            (Status::UnprocessableEntity, fields.join(","))
+        }
+    }
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![hello])
+}
+
+#[test]
+pub fn valid_data() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "Chris" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "Chris");
+}
+
+#[test]
+pub fn validation_variant() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "C" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.into_string().unwrap(), "name");
+}
+
+#[test]
+pub fn parse_variant() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body("{")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+    assert_eq!(response.into_string().unwrap(), "parse");
+}
+
+#[test]
+pub fn validation_errors_accessor() {
+    let errors = HelloData {
+        name: String::new(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let err: ValidationGuardError<()> = ValidationGuardError::Validation(errors);
+    assert!(err
+        .validation_errors()
+        .unwrap()
+        .errors()
+        .contains_key("name"));
+
+    let err: ValidationGuardError<()> = ValidationGuardError::Parse(());
+    assert!(err.validation_errors().is_none());
+}
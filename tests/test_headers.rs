@@ -0,0 +1,105 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{Header, Status},
+    local::blocking::Client,
+    serde::{
+        json::{json, Value},
+        Deserialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedHeaders};
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct ClientHeaders {
+    #[validate(range(min = 1, max = 3))]
+    x_api_version: u8,
+    #[validate(length(min = 8))]
+    x_client_id: String,
+    x_debug: Option<bool>,
+}
+
+#[get("/")]
+fn index(headers: ValidatedHeaders<ClientHeaders>) -> String {
+    let headers = headers.into_inner();
+    format!(
+        "{} {} {:?}",
+        headers.x_api_version, headers.x_client_id, headers.x_debug
+    )
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![index])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[test]
+pub fn valid_headers() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .get("/")
+        .header(Header::new("X-Api-Version", "2"))
+        .header(Header::new("x-client-id", "client-1234"))
+        .header(Header::new("X-DEBUG", "true"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "2 client-1234 Some(true)");
+}
+
+#[test]
+pub fn invalid_headers() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .get("/")
+        .header(Header::new("X-Api-Version", "7"))
+        .header(Header::new("X-Client-Id", "short"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["x_api_version"][0]["code"], "range");
+    assert_eq!(body["errors"]["x_client_id"][0]["code"], "length");
+}
+
+#[test]
+pub fn missing_header() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .get("/")
+        .header(Header::new("X-Api-Version", "1"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["x_client_id"][0]["code"], "required");
+}
+
+#[test]
+pub fn unparsable_header() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .get("/")
+        .header(Header::new("X-Api-Version", "latest"))
+        .header(Header::new("X-Client-Id", "client-1234"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "invalid_header");
+    assert_eq!(
+        body["errors"]["__all__"][0]["params"]["header"],
+        json!("X-Api-Version")
+    );
+}
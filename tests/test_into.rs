@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedInto};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct NewUser {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(email)]
+    email: String,
+    password: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(crate = "rocket::serde")]
+struct User {
+    name: String,
+    email: String,
+    active: bool,
+}
+
+impl From<NewUser> for User {
+    fn from(new: NewUser) -> Self {
+        User {
+            name: new.name,
+            email: new.email.to_lowercase(),
+            active: true,
+        }
+    }
+}
+
+#[post("/users", data = "<data>")]
+fn create_user(data: ValidatedInto<Json<NewUser>, User>) -> Json<User> {
+    Json(data.into_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create_user])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn converts_validated_input() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/users").json(&NewUser {
+        name: "Chris".to_string(),
+        email: "Chris@Example.com".to_string(),
+        password: "hunter2".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.into_json::<Value>().unwrap(),
+        json!({"name": "Chris", "email": "chris@example.com", "active": true})
+    );
+}
+
+#[test]
+pub fn validates_input() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/users").json(&NewUser {
+        name: "C".to_string(),
+        email: "chris@example.com".to_string(),
+        password: "hunter2".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
@@ -16,6 +16,13 @@ struct HelloData<'a> {
     age: u8,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct SignupData<'a> {
+    #[validate(length(max = 12), email)]
+    email: &'a str,
+}
+
 #[get("/hello?<name>&<age>")]
 fn hello(name: &'_ str, age: u8) -> Json<HelloData> {
     Json(HelloData { name, age })
@@ -26,14 +33,20 @@ fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
     Json(data.into_deep_inner())
 }
 
+#[post("/signup", data = "<data>")]
+fn signup(data: Validated<Json<SignupData>>) -> Json<SignupData> {
+    Json(data.into_deep_inner())
+}
+
 #[launch]
 fn rocket() -> _ {
-    rocket::build().mount("/", routes![hello, validated_hello])
+    rocket::build().mount("/", routes![hello, validated_hello, signup])
 }
 
 use rocket::{
     http::{ContentType, Status},
     local::blocking::Client,
+    serde::json::Value,
 };
 
 #[test]
@@ -123,3 +136,29 @@ pub fn invalid_request_catcher_response() {
     assert_eq!(response.status(), Status::UnprocessableEntity);
     assert_eq!(response.content_type(), Some(ContentType::JSON));
 }
+
+#[test]
+pub fn multiple_errors_per_field() {
+    let rocket = rocket().register("/", catchers![rocket_validation::validation_catcher]);
+    let client = Client::tracked(rocket).unwrap();
+
+    let req = client.post("/signup").json(&SignupData {
+        email: "definitely-not-an-email",
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let codes = body["errors"]["email"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["code"].as_str().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(codes.len(), 2);
+    assert!(codes.contains(&"length"));
+    assert!(codes.contains(&"email"));
+}
@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address<'a> {
+    #[validate(length(equal = 4, message = "zip codes have 4 digits"))]
+    zip: &'a str,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData<'a> {
+    #[validate(length(min = 3))]
+    name: &'a str,
+    #[serde(borrow)]
+    #[validate(nested)]
+    address: Address<'a>,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register(
+            "/",
+            catchers![rocket_validation::validation_catcher_json_api],
+        )
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn json_api_errors() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "CH",
+        address: Address { zip: "123" },
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(
+        response.content_type(),
+        Some(ContentType::new("application", "vnd.api+json"))
+    );
+
+    let body = response.into_json::<Value>().unwrap();
+
+    assert_eq!(
+        body,
+        json!({
+            "errors": [
+                {
+                    "status": "422",
+                    "code": "length",
+                    "title": "Invalid Attribute",
+                    "detail": "zip codes have 4 digits",
+                    "source": { "pointer": "/address/zip" }
+                },
+                {
+                    "status": "422",
+                    "code": "length",
+                    "title": "Invalid Attribute",
+                    "detail": "name failed the length validation",
+                    "source": { "pointer": "/name" }
+                }
+            ]
+        })
+    );
+}
@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Header,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Languaged, Validate, ValidatedLanguage};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Article {
+    #[validate(length(min = 2))]
+    language: String,
+    #[validate(length(min = 1))]
+    title: String,
+}
+
+impl Languaged for Article {
+    fn language(&self) -> &str {
+        &self.language
+    }
+}
+
+#[post("/articles", data = "<data>")]
+fn publish(data: ValidatedLanguage<Json<Article>>) -> Json<Article> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![publish])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn article(language: &str) -> Article {
+    Article {
+        language: language.to_string(),
+        title: "Grüezi".to_string(),
+    }
+}
+
+#[test]
+pub fn matching_language() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/articles")
+        .header(Header::new("Content-Language", "en, de-CH"))
+        .json(&article("de-ch"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn mismatched_language() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/articles")
+        .header(Header::new("Content-Language", "fr"))
+        .json(&article("de-CH"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["language"][0];
+    assert_eq!(error["code"], "language_mismatch");
+    assert_eq!(error["params"]["value"], "de-CH");
+    assert_eq!(error["params"]["content_language"], "fr");
+}
+
+#[test]
+pub fn missing_content_language() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/articles").json(&article("de-CH"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
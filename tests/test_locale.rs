@@ -0,0 +1,99 @@
+#![cfg(feature = "locale")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Localized, Validate, ValidatedLocalized};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Invoice {
+    #[validate(range(min = 1.0, max = 10000.0))]
+    amount: f64,
+    due: String,
+}
+
+impl Localized for Invoice {
+    const NUMBERS: &'static [&'static str] = &["amount"];
+    const DATES: &'static [&'static str] = &["due"];
+}
+
+#[post("/invoices", data = "<data>")]
+fn create_invoice(data: ValidatedLocalized<Json<Invoice>>) -> Json<Invoice> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create_invoice])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Header, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn european_number_and_date() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/invoices")
+        .header(ContentType::JSON)
+        .header(Header::new("Accept-Language", "de-DE,de;q=0.9,en;q=0.8"))
+        .body(r#"{"amount":"1.234,56","due":"31.12.2024"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["amount"], 1234.56);
+    assert_eq!(body["due"], "2024-12-31");
+}
+
+#[test]
+pub fn us_number_and_date() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/invoices")
+        .header(ContentType::JSON)
+        .header(Header::new("Content-Language", "en-US"))
+        .body(r#"{"amount":"1,234.56","due":"12/31/2024"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["amount"], 1234.56);
+    assert_eq!(body["due"], "2024-12-31");
+}
+
+#[test]
+pub fn validates_parsed_number() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/invoices")
+        .header(ContentType::JSON)
+        .header(Header::new("Accept-Language", "fr-FR"))
+        .body(r#"{"amount":"12 345,00","due":"2024-12-31"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["amount"][0]["code"], "range");
+}
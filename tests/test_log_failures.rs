@@ -0,0 +1,90 @@
+#![cfg(feature = "log_failures")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::{Client, LocalResponse},
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Validate, Validated};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(length(min = 8))]
+    password: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+static EVENTS: Mutex<Vec<(log::Level, String)>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl log::Log for Capture {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "rocket_validation"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            EVENTS
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn capture_logs() {
+    // set before rocket, so rocket keeps it
+    let _ = log::set_logger(&Capture);
+    log::set_max_level(log::LevelFilter::Trace);
+}
+
+fn logged(needle: &str) -> Option<(log::Level, String)> {
+    EVENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(_, event)| event.contains(needle))
+        .cloned()
+}
+
+#[test]
+pub fn failure_logs_type_and_fields() {
+    capture_logs();
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .json(&HelloData {
+            name: "C".to_string(),
+            password: "s3cr3t!".to_string(),
+        })
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let (level, event) = logged("fields=name,password").unwrap();
+    assert_eq!(level, log::Level::Warn);
+    assert!(event.contains("test_log_failures::HelloData"));
+    assert!(!event.contains("s3cr3t!"));
+}
@@ -0,0 +1,84 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Sensitive, Validate, ValidatedSensitive, ValidationConfig};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Payment {
+    #[validate(length(min = 3))]
+    holder: String,
+    #[validate(length(equal = 16))]
+    card_number: String,
+}
+
+impl Sensitive for Payment {
+    const SENSITIVE: &'static [&'static str] = &["card_number"];
+}
+
+#[post("/payments", data = "<data>")]
+fn pay(data: ValidatedSensitive<Json<Payment>>) -> Json<Payment> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket_with(config: ValidationConfig) -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![pay])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(config)
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket_with(ValidationConfig::default())
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn invalid_payment() -> Payment {
+    Payment {
+        holder: "C".to_string(),
+        card_number: "4111 1111".to_string(),
+    }
+}
+
+#[test]
+pub fn sensitive_value_masked() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").json(&invalid_payment());
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["card_number"][0]["params"]["value"], "****");
+    assert_eq!(body["errors"]["holder"][0]["params"]["value"], "C");
+}
+
+#[test]
+pub fn values_not_echoed() {
+    let client =
+        Client::tracked(rocket_with(ValidationConfig::default().echo_values(false))).unwrap();
+
+    let req = client.post("/payments").json(&invalid_payment());
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert!(body["errors"]["card_number"][0]["params"]
+        .get("value")
+        .is_none());
+    assert!(body["errors"]["holder"][0]["params"].get("value").is_none());
+    assert_eq!(body["errors"]["holder"][0]["params"]["min"], 3);
+}
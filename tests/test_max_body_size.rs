@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{MaxBodySize, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct TagsData {
+    #[validate(length(min = 1, max = 10))]
+    owner: String,
+    #[validate(length(max = 20))]
+    tags: Vec<String>,
+}
+
+#[post("/tags", data = "<data>")]
+fn tags(data: Validated<Json<TagsData>>) -> Json<TagsData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![tags])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(MaxBodySize(64))
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn within_limit() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/tags").json(&TagsData {
+        owner: "chris".to_string(),
+        tags: vec!["rust".to_string(), "rocket".to_string()],
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn whitespace_does_not_count() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/tags").header(ContentType::JSON).body(format!(
+        r#"{{"owner": "chris", {}"tags": ["rust"]}}"#,
+        " ".repeat(128)
+    ));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn valid_fields_exceeding_total_size() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/tags").json(&TagsData {
+        owner: "chris".to_string(),
+        tags: (0..10).map(|i| format!("tag-{i}")).collect(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "max_body_size");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["max"], 64);
+    assert!(
+        body["errors"]["__all__"][0]["params"]["size"]
+            .as_u64()
+            .unwrap()
+            > 64
+    );
+}
@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{MaxErrors, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Item {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Batch {
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[post("/batch", data = "<data>")]
+fn batch(data: Validated<Json<Batch>>) -> Json<Batch> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![batch])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(MaxErrors(3))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn items(count: usize) -> Batch {
+    Batch {
+        items: (0..count)
+            .map(|_| Item {
+                name: "a".to_string(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+pub fn under_cap() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.post("/batch").json(&items(2)).dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["items"].as_object().unwrap().len(), 2);
+    assert!(body["errors"].get("__all__").is_none());
+}
+
+#[test]
+pub fn caps_retained_errors() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.post("/batch").json(&items(50)).dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let kept = body["errors"]["items"].as_object().unwrap();
+    assert_eq!(kept.len(), 3);
+    assert!(kept.contains_key("0") && kept.contains_key("1") && kept.contains_key("2"));
+
+    assert_eq!(body["errors"]["__all__"][0]["code"], "capped");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["max"], 3);
+    assert_eq!(body["errors"]["__all__"][0]["params"]["total"], 50);
+}
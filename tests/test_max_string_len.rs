@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{MaxStringLen, Validate, Validated, ValidatedOrdered};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[post("/ordered", data = "<data>")]
+fn ordered_hello(data: ValidatedOrdered<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello, ordered_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(MaxStringLen(16))
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn within_limit() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "Chris".to_string(),
+        age: 18,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+}
+
+#[test]
+pub fn oversized_string() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".repeat(17),
+        age: 18,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "max_string_len");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["max"], 16);
+}
+
+#[test]
+pub fn escapes_count_as_one_char() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"abcdefghijklmno\u00e9","age":18}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn applies_to_every_json_guard() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/ordered").json(&HelloData {
+        name: "C".repeat(17),
+        age: 18,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "max_string_len");
+}
@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::Client,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated, ValidationConfig, ValidationMessages};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+fn post(client: &Client) -> Value {
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    response.into_json::<Value>().unwrap()
+}
+
+#[test]
+pub fn default_message() {
+    let rocket = rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher]);
+    let client = Client::tracked(rocket).unwrap();
+
+    let body = post(&client);
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Unprocessable Entity."));
+}
+
+#[test]
+pub fn managed_message() {
+    let rocket = rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(ValidationMessages::default().unprocessable("Die Anfrage ist ungültig."));
+    let client = Client::tracked(rocket).unwrap();
+
+    let body = post(&client);
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["message"], "Die Anfrage ist ungültig.");
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn managed_message_for_configured_status() {
+    let config = ValidationConfig::default().failure_status(Status::BadRequest);
+    let rocket = rocket::build()
+        .mount("/", routes![hello])
+        .register("/", vec![config.catcher()])
+        .manage(config)
+        .manage(ValidationMessages::default().failed("La requête est invalide."));
+    let client = Client::tracked(rocket).unwrap();
+
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["message"], "La requête est invalide.");
+}
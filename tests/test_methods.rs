@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Method,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{MethodFields, Validate, ValidatedMethod};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Profile {
+    #[validate(length(min = 3))]
+    name: Option<String>,
+    #[validate(email)]
+    email: Option<String>,
+}
+
+impl MethodFields for Profile {
+    fn allowed_fields(method: Method) -> Option<&'static [&'static str]> {
+        match method {
+            Method::Patch => Some(&["name"]),
+            _ => None,
+        }
+    }
+}
+
+#[put("/profile", data = "<data>")]
+fn replace(data: ValidatedMethod<Json<Profile>>) -> Json<Profile> {
+    Json(data.into_deep_inner())
+}
+
+#[patch("/profile", data = "<data>")]
+fn update(data: ValidatedMethod<Json<Profile>>) -> Json<Profile> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![replace, update])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn put_allows_every_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .put("/profile")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Alice","email":"alice@example.com"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn patch_allows_listed_fields() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .patch("/profile")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Alice"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn patch_rejects_other_fields() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .patch("/profile")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Alice","email":"alice@example.com"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let error = &body["errors"]["__all__"][0];
+
+    assert_eq!(error["code"], "immutable");
+    assert_eq!(error["params"]["field"], "email");
+    assert_eq!(error["params"]["method"], "PATCH");
+}
+
+#[test]
+pub fn allowed_fields_are_still_validated() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .put("/profile")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Alice","email":"not an email"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["email"][0]["code"], "email");
+}
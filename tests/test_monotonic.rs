@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{TimestampStore, Timestamped, Timestamps, Validate, ValidatedMonotonic};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Event<'a> {
+    #[validate(length(min = 3))]
+    kind: &'a str,
+    ts: i64,
+}
+
+impl Timestamped for Event<'_> {
+    const FIELD: &'static str = "ts";
+
+    fn timestamp(&self) -> i64 {
+        self.ts
+    }
+}
+
+struct LastEvent(Mutex<Option<i64>>);
+
+impl TimestampStore for LastEvent {
+    fn advance(&self, timestamp: i64) -> Result<(), i64> {
+        let mut last = self.0.lock().unwrap();
+        match *last {
+            Some(last) if timestamp <= last => Err(last),
+            _ => {
+                *last = Some(timestamp);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[post("/events", data = "<data>")]
+fn ingest(data: ValidatedMonotonic<Json<Event>>) -> Json<Event> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![ingest])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(Timestamps::new(LastEvent(Mutex::new(None))))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn in_order_timestamps() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    for ts in [10, 20, 30] {
+        let req = client.post("/events").json(&Event { kind: "click", ts });
+        let response: LocalResponse = req.dispatch();
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
+
+#[test]
+pub fn out_of_order_timestamp() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/events").json(&Event {
+        kind: "click",
+        ts: 20,
+    });
+    assert_eq!(req.dispatch().status(), Status::Ok);
+
+    let req = client.post("/events").json(&Event {
+        kind: "click",
+        ts: 15,
+    });
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["ts"][0]["code"], "monotonic");
+    assert_eq!(body["errors"]["ts"][0]["params"]["last"], 20);
+}
+
+#[test]
+pub fn invalid_event_is_not_recorded() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/events").json(&Event { kind: "x", ts: 50 });
+    assert_eq!(req.dispatch().status(), Status::UnprocessableEntity);
+
+    let req = client.post("/events").json(&Event {
+        kind: "click",
+        ts: 40,
+    });
+    assert_eq!(req.dispatch().status(), Status::Ok);
+}
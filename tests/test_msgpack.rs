@@ -0,0 +1,86 @@
+#![cfg(feature = "msgpack")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        msgpack::MsgPack,
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 0, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<MsgPack<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn valid_msgpack() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").msgpack(&HelloData {
+        name: "Chris".to_string(),
+        age: 30,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["name"], "Chris");
+}
+
+#[test]
+pub fn invalid_msgpack() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").msgpack(&HelloData {
+        name: "C".to_string(),
+        age: 101,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert_eq!(body["errors"]["age"][0]["code"], "range");
+}
+
+#[test]
+pub fn malformed_msgpack() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").header(ContentType::MsgPack).body("");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{validate_each, MultiStatus, Validate};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct User {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(max = 150))]
+    age: u8,
+}
+
+#[post("/users/bulk", data = "<data>")]
+fn create_users(data: Json<Vec<User>>) -> MultiStatus<User> {
+    MultiStatus(validate_each(data.into_inner()))
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![create_users])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn user(name: &str, age: u8) -> User {
+    User {
+        name: name.to_string(),
+        age,
+    }
+}
+
+#[test]
+pub fn mixed_items() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req =
+        client
+            .post("/users/bulk")
+            .json(&vec![user("Chris", 30), user("C", 30), user("Alex", 200)]);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::MultiStatus);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 207);
+
+    let items = body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 3);
+
+    assert_eq!(items[0]["index"], 0);
+    assert_eq!(items[0]["status"], 200);
+    assert_eq!(items[0]["data"]["name"], "Chris");
+    assert!(items[0].get("errors").is_none());
+
+    assert_eq!(items[1]["index"], 1);
+    assert_eq!(items[1]["status"], 422);
+    assert_eq!(items[1]["errors"]["name"][0]["code"], "length");
+    assert!(items[1].get("data").is_none());
+
+    assert_eq!(items[2]["status"], 422);
+    assert_eq!(items[2]["errors"]["age"][0]["code"], "range");
+}
+
+#[test]
+pub fn all_valid_items() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/users/bulk")
+        .json(&vec![user("Chris", 30), user("Alex", 40)]);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::MultiStatus);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert!(body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|item| item["status"] == 200));
+}
@@ -0,0 +1,130 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    form::Form,
+    fs::TempFile,
+    http::{ContentType, Status},
+    local::blocking::Client,
+    serde::json::Value,
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Debug, Validate, FromForm)]
+struct Upload<'r> {
+    #[validate(length(min = 3))]
+    title: String,
+    #[validate(range(min = 1, max = 10))]
+    copies: u8,
+    file: TempFile<'r>,
+}
+
+#[post("/upload", data = "<data>")]
+fn upload(data: Validated<Form<Upload<'_>>>) -> String {
+    let upload = data.into_deep_inner();
+    format!("{} {} {}", upload.title, upload.copies, upload.file.len())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![upload])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+const BOUNDARY: &str = "X-VALIDATION-BOUNDARY";
+
+fn multipart(fields: &[(&str, &str)], file: Option<&str>) -> String {
+    let mut body = String::new();
+
+    for (name, value) in fields {
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+
+    if let Some(content) = file {
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"notes.txt\"\r\n\
+             Content-Type: text/plain\r\n\r\n{content}\r\n"
+        ));
+    }
+
+    body.push_str(&format!("--{BOUNDARY}--\r\n"));
+    body
+}
+
+fn content_type() -> ContentType {
+    ContentType::new("multipart", "form-data").with_params(("boundary", BOUNDARY))
+}
+
+#[test]
+pub fn valid_multipart() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/upload")
+        .header(content_type())
+        .body(multipart(
+            &[("title", "Report"), ("copies", "2")],
+            Some("hello"),
+        ))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "Report 2 5");
+}
+
+#[test]
+pub fn invalid_multipart() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/upload")
+        .header(content_type())
+        .body(multipart(
+            &[("title", "Re"), ("copies", "0")],
+            Some("hello"),
+        ))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["title"][0]["code"], "length");
+    assert_eq!(body["errors"]["copies"][0]["code"], "range");
+    assert!(body["errors"].get("file").is_none());
+}
+
+#[test]
+pub fn missing_file_is_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/upload")
+        .header(content_type())
+        .body(multipart(&[("title", "Report"), ("copies", "2")], None))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "required");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["field"], "file");
+}
+
+#[test]
+pub fn url_encoded_without_file() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/upload")
+        .header(ContentType::Form)
+        .body("title=Re&copies=2")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["params"]["field"], "file");
+}
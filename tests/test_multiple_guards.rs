@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::Client,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{QueryParams, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[derive(Clone, Debug, Validate, FromForm)]
+struct Paging {
+    #[validate(range(min = 1, max = 100))]
+    per_page: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(
+    _paging: Option<Validated<QueryParams<Paging>>>,
+    data: Validated<Json<HelloData>>,
+) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+fn type_key<'v>(by_type: &'v Value, suffix: &str) -> &'v Value {
+    by_type
+        .as_object()
+        .unwrap()
+        .iter()
+        .find(|(type_name, _)| type_name.ends_with(suffix))
+        .map(|(_, errors)| errors)
+        .unwrap_or_else(|| panic!("no errors of {suffix} in {by_type}"))
+}
+
+#[test]
+pub fn single_failure_omits_by_type() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello?per_page=10")
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert!(body.get("by_type").is_none());
+}
+
+#[test]
+pub fn errors_of_every_guard_by_type() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello?per_page=0")
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let by_type = &body["by_type"];
+    assert_eq!(by_type.as_object().unwrap().len(), 2);
+
+    assert_eq!(
+        type_key(by_type, "QueryParams<test_multiple_guards::Paging>")["per_page"][0]["code"],
+        "range"
+    );
+    assert_eq!(
+        type_key(by_type, "test_multiple_guards::HelloData")["name"][0]["code"],
+        "length"
+    );
+}
+
+#[test]
+pub fn errors_of_every_guard_are_merged() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello?per_page=0")
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
@@ -0,0 +1,122 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{flatten_errors, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Toy {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Child {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(nested)]
+    toys: Vec<Toy>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Family {
+    #[validate(nested)]
+    children: Vec<Child>,
+}
+
+#[post("/families", data = "<data>")]
+fn create(data: Validated<Json<Family>>) -> Json<Family> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket(grouped: bool) -> rocket::Rocket<rocket::Build> {
+    let rocket = rocket::build().mount("/", routes![create]);
+
+    if grouped {
+        rocket.register(
+            "/",
+            catchers![rocket_validation::validation_catcher_grouped],
+        )
+    } else {
+        rocket.register("/", catchers![rocket_validation::validation_catcher_flat])
+    }
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+const INVALID: &str = r#"{"children":[
+    {"name":"Alex","toys":[]},
+    {"name":"Sam","toys":[{"name":"ball"}]},
+    {"name":"","toys":[{"name":"kite"},{"name":""}]}
+]}"#;
+
+fn invalid_family() -> Family {
+    rocket::serde::json::from_str(INVALID).unwrap()
+}
+
+#[test]
+pub fn flattened_paths() {
+    let errors = invalid_family().validate().unwrap_err();
+    let flat = flatten_errors(&errors);
+
+    assert_eq!(flat.len(), 2);
+    assert!(flat.contains_key("children[2].name"));
+    assert!(flat.contains_key("children[2].toys[1].name"));
+}
+
+#[test]
+pub fn flat_catcher_paths() {
+    let client = Client::tracked(rocket(false)).unwrap();
+
+    let req = client
+        .post("/families")
+        .header(ContentType::JSON)
+        .body(INVALID);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let errors = body["errors"].as_object().unwrap();
+
+    assert!(errors.contains_key("children[2].name"));
+    assert!(errors.contains_key("children[2].toys[1].name"));
+}
+
+#[test]
+pub fn grouped_catcher_paths() {
+    let client = Client::tracked(rocket(true)).unwrap();
+
+    let req = client
+        .post("/families")
+        .header(ContentType::JSON)
+        .body(INVALID);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let mut fields: Vec<&str> = body["errors"]["length"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| field.as_str().unwrap())
+        .collect();
+    fields.sort_unstable();
+
+    assert_eq!(fields, ["children[2].name", "children[2].toys[1].name"]);
+}
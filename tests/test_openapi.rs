@@ -0,0 +1,116 @@
+#![cfg(feature = "okapi")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::Client,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+    State,
+};
+use rocket_validation::{document_validated_routes, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Person {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/people", data = "<data>")]
+fn create(data: Validated<Json<Person>>) -> Json<Person> {
+    Json(data.into_deep_inner())
+}
+
+#[put("/people/<_id>", data = "<data>")]
+fn update(_id: u32, data: Validated<Json<Person>>) -> Json<Person> {
+    Json(data.into_deep_inner())
+}
+
+#[get("/people")]
+fn list() -> Json<Vec<Person>> {
+    Json(Vec::new())
+}
+
+#[delete("/people/<_id>")]
+fn delete(_id: u32) -> Status {
+    Status::NoContent
+}
+
+#[get("/openapi.json")]
+fn openapi(spec: &State<Value>) -> Json<Value> {
+    Json(spec.inner().clone())
+}
+
+fn spec() -> Value {
+    json!({
+        "openapi": "3.0.0",
+        "info": { "title": "people", "version": "1" },
+        "paths": {
+            "/api/people": {
+                "get": { "responses": { "200": { "description": "people" } } },
+                "post": { "responses": { "200": { "description": "created" } } }
+            },
+            "/api/people/{_id}": {
+                "put": {
+                    "responses": {
+                        "200": { "description": "updated" },
+                        "422": { "description": "custom" }
+                    }
+                },
+                "delete": { "responses": { "204": { "description": "deleted" } } }
+            }
+        }
+    })
+}
+
+#[launch]
+fn rocket() -> _ {
+    let rocket = rocket::build()
+        .mount("/api", routes![create, update, list, delete])
+        .mount("/", routes![openapi]);
+
+    // the routes whose handlers take a `Validated` guard
+    let validated: Vec<_> = rocket
+        .routes()
+        .filter(|route| matches!(route.name.as_deref(), Some("create") | Some("update")))
+        .cloned()
+        .collect();
+
+    let mut spec = spec();
+    document_validated_routes(&mut spec, &validated);
+
+    rocket.manage(spec)
+}
+
+#[test]
+pub fn openapi_documents_validation_responses() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client.get("/openapi.json").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let spec = response.into_json::<Value>().unwrap();
+    let paths = &spec["paths"];
+
+    assert_eq!(
+        paths["/api/people"]["post"]["responses"]["422"]["content"]["application/json"]["schema"]
+            ["$ref"],
+        "#/components/schemas/ValidationErrorResponse"
+    );
+    assert!(paths["/api/people"]["get"]["responses"]
+        .get("422")
+        .is_none());
+    assert_eq!(
+        paths["/api/people/{_id}"]["put"]["responses"]["422"]["description"],
+        "custom"
+    );
+    assert!(paths["/api/people/{_id}"]["delete"]["responses"]
+        .get("422")
+        .is_none());
+    assert!(spec["components"]["schemas"]["ValidationErrorResponse"].is_object());
+}
@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::{Client, LocalResponse},
+    serde::json::Value,
+};
+use rocket_validation::{QueryParams, Validate, Validated, ValidatedOption};
+
+#[derive(Debug, FromForm, Validate)]
+struct Filter {
+    #[validate(length(min = 3))]
+    term: String,
+    #[validate(range(min = 1, max = 100))]
+    limit: u8,
+}
+
+#[get("/search?<filter..>")]
+fn search(filter: ValidatedOption<Filter>) -> String {
+    match filter.into_inner() {
+        Some(filter) => format!("{} {}", filter.term, filter.limit),
+        None => "everything".into(),
+    }
+}
+
+#[derive(Debug, FromForm, Validate)]
+struct Listing {
+    filter: ValidatedOption<Filter>,
+}
+
+#[get("/listing")]
+fn listing(params: Validated<QueryParams<Listing>>) -> String {
+    match params.into_deep_inner().filter.into_inner() {
+        Some(filter) => filter.term,
+        None => "everything".into(),
+    }
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![search, listing])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[test]
+pub fn empty_query() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/search").dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "everything");
+}
+
+#[test]
+pub fn partial_query() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/search?term=rocket").dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+pub fn invalid_query() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/search?term=ro&limit=10").dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+pub fn full_query() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/search?term=rocket&limit=10").dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "rocket 10");
+}
+
+#[test]
+pub fn invalid_query_params_errors_are_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .get("/listing?filter.term=ro&filter.limit=10")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["term"][0]["code"], "length");
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedOrdered};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Transfer {
+    #[validate(length(min = 1))]
+    from: String,
+    #[validate(length(min = 1))]
+    to: String,
+    #[validate(range(min = 1))]
+    amount: u64,
+    #[serde(rename = "sig")]
+    signature: Option<String>,
+}
+
+#[post("/transfers", data = "<data>")]
+fn transfer(data: ValidatedOrdered<Json<Transfer>>) -> Json<Transfer> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![transfer])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn keys_in_order() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/transfers")
+        .header(ContentType::JSON)
+        .body(r#"{"from":"alice","to":"bob","amount":10,"sig":"abc"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn omitted_and_unknown_keys_are_ignored() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/transfers")
+        .header(ContentType::JSON)
+        .body(r#"{"nonce":1,"from":"alice","to":"bob","amount":10}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn out_of_order_keys() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/transfers")
+        .header(ContentType::JSON)
+        .body(r#"{"from":"alice","amount":10,"to":"bob"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["to"][0]["code"], "field_order");
+    assert_eq!(body["errors"]["to"][0]["params"]["after"], "amount");
+}
+
+#[test]
+pub fn still_validates() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/transfers")
+        .header(ContentType::JSON)
+        .body(r#"{"from":"alice","to":"bob","amount":0}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["amount"][0]["code"], "range");
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "unicode")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{NormalizeNfc, Validate, ValidatedOrdered};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Transfer {
+    #[validate(length(min = 1))]
+    to: String,
+    #[validate(length(min = 1))]
+    from: String,
+    #[validate(range(min = 1))]
+    amount: u64,
+}
+
+#[post("/transfers", data = "<data>")]
+fn transfer(data: ValidatedOrdered<Json<Transfer>>) -> Json<Transfer> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![transfer])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(NormalizeNfc)
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn order_is_checked_before_normalization() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/transfers")
+        .header(ContentType::JSON)
+        .body(r#"{"to":"bob","from":"Rene\u0301","amount":10}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Transfer>().unwrap().from, "Ren\u{e9}");
+}
+
+#[test]
+pub fn keys_out_of_order() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/transfers")
+        .header(ContentType::JSON)
+        .body(r#"{"from":"alice","to":"bob","amount":10}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "parser_errors")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[validate(length(min = 4))]
+    zip: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[post("/hello", format = "application/json", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket() -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", rocket_validation::validation_catchers())
+}
+
+#[test]
+pub fn parse_error_holds_path() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","address":{"zip":5}}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["__all__"][0]["code"], "parse");
+    assert_eq!(
+        body["errors"]["__all__"][0]["params"]["path"],
+        "address.zip"
+    );
+    assert_eq!(body["errors"]["__all__"][0]["params"]["line"], 1);
+}
+
+#[test]
+pub fn validation_errors_unchanged() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Ch","address":{"zip":"123"}}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert!(body["errors"]["__all__"].is_null());
+}
+
+#[test]
+pub fn trailing_characters_have_no_path() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","address":{"zip":"1234"}} x"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::BadRequest);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["__all__"][0]["code"], "parse");
+    assert!(body["errors"]["__all__"][0]["params"]["path"].is_null());
+}
@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Patch, Validate, Validated};
+use validator::ValidationError;
+
+fn email(email: &Patch<String>) -> Result<(), ValidationError> {
+    email.not_null()?;
+    email.check_value(|email| match email.contains('@') {
+        true => Ok(()),
+        false => Err(ValidationError::new("email")),
+    })
+}
+
+fn nickname(nickname: &Patch<String>) -> Result<(), ValidationError> {
+    nickname.check_value(|nickname| match nickname.chars().count() >= 3 {
+        true => Ok(()),
+        false => Err(ValidationError::new("length")),
+    })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct AccountPatch {
+    #[serde(default)]
+    #[validate(custom(function = "email"))]
+    email: Patch<String>,
+    #[serde(default)]
+    #[validate(custom(function = "nickname"))]
+    nickname: Patch<String>,
+}
+
+fn state(patch: &Patch<String>) -> &'static str {
+    match patch {
+        Patch::Absent => "absent",
+        Patch::Null => "null",
+        Patch::Value(_) => "value",
+    }
+}
+
+#[patch("/account", data = "<data>")]
+fn update(data: Validated<Json<AccountPatch>>) -> Json<Value> {
+    let patch = data.into_deep_inner();
+    Json(rocket::serde::json::json!({
+        "email": state(&patch.email),
+        "nickname": state(&patch.nickname),
+    }))
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![update])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+fn patch(body: &'static str) -> (Status, Value) {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .patch("/account")
+        .header(ContentType::JSON)
+        .body(body)
+        .dispatch();
+
+    (response.status(), response.into_json().unwrap())
+}
+
+#[test]
+pub fn absent_fields_are_unchanged() {
+    let (status, body) = patch("{}");
+
+    assert_eq!(status, Status::Ok);
+    assert_eq!(body["email"], "absent");
+    assert_eq!(body["nickname"], "absent");
+}
+
+#[test]
+pub fn null_deletes_nullable_fields() {
+    let (status, body) = patch(r#"{"nickname":null}"#);
+
+    assert_eq!(status, Status::Ok);
+    assert_eq!(body["email"], "absent");
+    assert_eq!(body["nickname"], "null");
+}
+
+#[test]
+pub fn null_fails_for_fields_which_cannot_be_deleted() {
+    let (status, body) = patch(r#"{"email":null}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+    assert_eq!(body["errors"]["email"][0]["code"], "not_nullable");
+}
+
+#[test]
+pub fn values_are_validated() {
+    let (status, body) = patch(r#"{"email":"alice@example.com","nickname":"al"}"#);
+
+    assert_eq!(status, Status::UnprocessableEntity);
+    assert!(body["errors"].get("email").is_none());
+    assert_eq!(body["errors"]["nickname"][0]["code"], "length");
+}
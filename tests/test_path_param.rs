@@ -0,0 +1,153 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::Client,
+    serde::json::{json, Value},
+};
+use rocket_validation::{PathParam, Validate, Validated, ValidationErrors};
+use std::str::FromStr;
+use validator::ValidationError;
+
+struct Username(String);
+
+impl FromStr for Username {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Ok(Username(s.to_owned()))
+    }
+}
+
+impl Validate for Username {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.0.len() < 3 {
+            errors.add("username", ValidationError::new("length"));
+        }
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+struct Page(u32);
+
+impl FromStr for Page {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Page)
+    }
+}
+
+impl Validate for Page {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.0 == 0 || self.0 > 100 {
+            errors.add("page", ValidationError::new("range"));
+        }
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+#[get("/users/<_>")]
+fn user(username: Validated<PathParam<Username, 1>>) -> String {
+    username.into_deep_inner().0
+}
+
+#[get("/pages/<_>")]
+fn page(page: Validated<PathParam<Page, 1>>) -> String {
+    page.into_deep_inner().0.to_string()
+}
+
+#[get("/profile")]
+fn profile(username: Validated<PathParam<Username, 1>>) -> String {
+    username.into_deep_inner().0
+}
+
+#[get("/numbered/<page>")]
+fn numbered(page: Validated<Page>) -> String {
+    page.into_inner().0.to_string()
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![user, page, profile, numbered])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[test]
+pub fn valid_segment() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client.get("/users/alice").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "alice");
+
+    let response = client.get("/pages/7").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "7");
+}
+
+#[test]
+pub fn invalid_segment_is_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client.get("/users/al").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["username"][0]["code"], "length");
+
+    let response = client.get("/pages/0").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["page"][0]["code"], "range");
+}
+
+#[test]
+pub fn unparsable_segment() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client.get("/pages/first").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "parse");
+    assert_eq!(
+        body["errors"]["__all__"][0]["params"]["value"],
+        json!("first")
+    );
+}
+
+#[test]
+pub fn missing_segment_is_not_found() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client.get("/profile").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[test]
+pub fn validated_param() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client.get("/numbered/7").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "7");
+
+    let response = client.get("/numbered/0").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let response = client.get("/numbered/first").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
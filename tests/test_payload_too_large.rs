@@ -0,0 +1,67 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    data::{Limits, ToByteUnit},
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+    Build, Config, Rocket,
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket() -> Rocket<Build> {
+    let figment = Config::figment().merge(("limits", Limits::default().limit("json", 32.bytes())));
+
+    rocket::custom(figment)
+        .mount("/", routes![hello])
+        .register("/", rocket_validation::validation_catchers())
+}
+
+#[test]
+pub fn oversized_body_is_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(format!(r#"{{"name":"{}"}}"#, "C".repeat(64)))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::PayloadTooLarge);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["code"], 413);
+    assert_eq!(body["message"], "Payload Too Large");
+    assert_eq!(body["errors"]["__all__"][0]["code"], "payload_too_large");
+    assert_eq!(body["errors"]["__all__"][0]["params"]["limit"], 32);
+}
+
+#[test]
+pub fn body_within_limit() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris"}"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "problem_details")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{Accept, ContentType, MediaType, Status},
+    local::blocking::Client,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![hello]).register(
+        "/",
+        catchers![rocket_validation::validation_catcher_problem],
+    )
+}
+
+fn problem_json() -> MediaType {
+    MediaType::new("application", "problem+json")
+}
+
+#[test]
+pub fn problem_document() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .header(Accept::new([problem_json()]))
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(
+        response.content_type(),
+        Some(ContentType::new("application", "problem+json"))
+    );
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["type"], "about:blank");
+    assert_eq!(body["title"], "Unprocessable Entity");
+    assert_eq!(body["status"], 422);
+    assert_eq!(body["instance"], "/hello");
+    assert!(body["detail"].is_string());
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn json_by_default() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn preferred_media_type_wins() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .header(Accept::new([
+            MediaType::JSON,
+            problem_json().with_params(("q", "0.5")),
+        ]))
+        .json(&json!({ "name": "Al" }))
+        .dispatch();
+
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+}
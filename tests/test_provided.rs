@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedProvided};
+
+#[derive(Clone, Debug, Default, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    city: Option<String>,
+    zip: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct UserPatch {
+    #[validate(length(min = 3))]
+    name: Option<String>,
+    #[serde(default)]
+    newsletter: bool,
+    #[serde(default)]
+    address: Address,
+}
+
+#[patch("/users", data = "<data>")]
+fn patch_user(data: ValidatedProvided<Json<UserPatch>>) -> Json<Vec<String>> {
+    Json(data.provided_fields().iter().cloned().collect())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![patch_user])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn partial_payload() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .patch("/users")
+        .header(ContentType::JSON)
+        .body(r#"{"newsletter": false, "address": {"city": "Bern"}}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.into_json::<Value>().unwrap(),
+        rocket::serde::json::json!(["address", "address.city", "newsletter"])
+    );
+}
+
+#[test]
+pub fn null_counts_as_provided() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .patch("/users")
+        .header(ContentType::JSON)
+        .body(r#"{"name": null}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.into_json::<Value>().unwrap(),
+        rocket::serde::json::json!(["name"])
+    );
+}
+
+#[test]
+pub fn invalid_partial_payload() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .patch("/users")
+        .header(ContentType::JSON)
+        .body(r#"{"name": "C"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,157 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    form::Form,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Serialize,
+    },
+};
+use rocket_validation::{QueryParams, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Validate, FromForm)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[derive(Debug, Validate, FromForm)]
+struct Paging {
+    #[validate(range(min = 1, max = 100))]
+    per_page: u8,
+}
+
+#[derive(Debug, Validate, FromForm)]
+struct Search {
+    #[validate(length(min = 3))]
+    term: String,
+    paging: Validated<Paging>,
+}
+
+#[get("/hello")]
+fn query(params: Validated<QueryParams<HelloData>>) -> Json<HelloData> {
+    Json(params.into_deep_inner())
+}
+
+#[post("/hello", data = "<data>")]
+fn form(data: Validated<Form<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[get("/search")]
+fn search_query(params: Validated<QueryParams<Search>>) -> String {
+    params.into_deep_inner().term
+}
+
+#[post("/search", data = "<data>")]
+fn search_form(data: Validated<Form<Search>>) -> String {
+    data.into_deep_inner().term
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![query, form, search_query, search_form])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn valid_query() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/hello?name=Chris&age=18").dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["name"], "Chris");
+}
+
+#[test]
+pub fn invalid_query_errors_are_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/hello?name=Ch&age=0").dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert_eq!(body["errors"]["age"][0]["code"], "range");
+}
+
+#[test]
+pub fn unparsable_query() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client.get("/hello?name=Chris").dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert!(body["errors"].is_null());
+}
+
+#[test]
+pub fn invalid_form_errors_are_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::Form)
+        .body("name=Ch&age=18");
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn nested_query_errors_are_cached() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .get("/search?term=rocket&paging.per_page=500")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+}
+
+#[test]
+pub fn nested_form_errors_are_cached_once() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response: LocalResponse = client
+        .post("/search")
+        .header(ContentType::Form)
+        .body("term=rocket&paging.per_page=500")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["per_page"][0]["code"], "range");
+    assert!(body["errors"].get("__all__").is_none());
+}
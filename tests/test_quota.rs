@@ -0,0 +1,105 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{MemoryQuotaStore, Quota, Validate, ValidatedQuota};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData<'a> {
+    #[validate(length(min = 3))]
+    name: &'a str,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: ValidatedQuota<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .manage(Quota::new("X-Api-Key", 2))
+}
+
+use rocket::{
+    http::{Header, Status},
+    local::blocking::Client,
+};
+
+fn post(client: &Client, name: &str) -> Status {
+    let req = client
+        .post("/hello")
+        .header(Header::new("X-Api-Key", "key-1"))
+        .json(&HelloData { name, age: 18 });
+
+    let response: LocalResponse = req.dispatch();
+
+    response.status()
+}
+
+#[test]
+pub fn exhausted_quota() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(post(&client, "Chris"), Status::Ok);
+    assert_eq!(post(&client, "Chris"), Status::Ok);
+    assert_eq!(post(&client, "Chris"), Status::TooManyRequests);
+
+    let quota = client.rocket().state::<Quota>().unwrap();
+    assert_eq!(quota.remaining("key-1"), 0);
+    assert_eq!(quota.remaining("key-2"), 2);
+}
+
+#[test]
+pub fn invalid_body_keeps_quota() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(post(&client, "CH"), Status::UnprocessableEntity);
+
+    let quota = client.rocket().state::<Quota>().unwrap();
+    assert_eq!(quota.remaining("key-1"), 2);
+}
+
+#[test]
+pub fn missing_api_key() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "Chris",
+        age: 18,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Unauthorized);
+}
+
+#[test]
+pub fn bounded_keys() {
+    let rocket = rocket::build()
+        .mount("/", routes![validated_hello])
+        .manage(Quota::new("X-Api-Key", 2).store(MemoryQuotaStore::new(1)));
+    let client = Client::tracked(rocket).unwrap();
+
+    assert_eq!(post(&client, "Chris"), Status::Ok);
+
+    let req = client
+        .post("/hello")
+        .header(Header::new("X-Api-Key", "key-2"))
+        .json(&HelloData {
+            name: "Chris",
+            age: 18,
+        });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::TooManyRequests);
+    assert_eq!(post(&client, "Chris"), Status::Ok);
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "ref_cycles")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Tree {
+    #[validate(length(min = 1))]
+    name: String,
+    children: Vec<Value>,
+}
+
+#[post("/trees", data = "<data>")]
+fn create_tree(data: Validated<Json<Tree>>) -> Json<Tree> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create_tree])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn acyclic_refs() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/trees")
+        .header(ContentType::JSON)
+        .body(r##"{"name":"root","children":[{"name":"a"},{"$ref":"#/children/0"}]}"##);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn cyclic_ref() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/trees")
+        .header(ContentType::JSON)
+        .body(r##"{"name":"root","children":[{"name":"a","children":[{"$ref":"#"}]}]}"##);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "ref_cycle");
+    assert_eq!(
+        body["errors"]["__all__"][0]["params"]["pointer"],
+        "/children/0/children/0"
+    );
+}
+
+#[test]
+pub fn indirect_ref_cycle() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/trees")
+        .header(ContentType::JSON)
+        .body(
+            r##"{"name":"root","children":[{"next":{"$ref":"#/children/1"}},{"next":{"$ref":"#/children/0"}}]}"##,
+        );
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+pub fn long_ref_chain() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let links: Vec<String> = (0..20_000)
+        .map(|i| format!(r##"{{"next":{{"$ref":"#/children/{}"}}}}"##, i + 1))
+        .collect();
+    let body = format!(
+        r#"{{"name":"root","children":[{},{{"next":{{"$ref":"#/children/0"}}}}]}}"#,
+        links.join(",")
+    );
+
+    let req = client.post("/trees").header(ContentType::JSON).body(body);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{References, Validate, ValidatedWith};
+use validator::ValidationError;
+
+fn known_category(id: &u64, references: &References) -> Result<(), ValidationError> {
+    references.check("category", id)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = References)]
+struct Product {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(custom(function = "known_category", use_context))]
+    category_id: u64,
+}
+
+#[post("/products", data = "<data>")]
+fn create(data: ValidatedWith<Json<Product>>) -> Json<Product> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![create])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(References::new(|kind: &str, id: &str| {
+            kind == "category" && ["1", "2"].contains(&id)
+        }))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn existing_reference() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/products").json(&Product {
+        name: "Chair".to_string(),
+        category_id: 2,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn nonexistent_reference() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/products").json(&Product {
+        name: "Chair".to_string(),
+        category_id: 42,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["category_id"][0];
+    assert_eq!(error["code"], "unknown_reference");
+    assert_eq!(error["params"]["kind"], "category");
+    assert_eq!(error["params"]["id"], "42");
+}
@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{ClientRegion, Regions, Validate, ValidatedWith};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use validator::ValidationError;
+
+fn local_currency(currency: &str, client: &ClientRegion) -> Result<(), ValidationError> {
+    match (client.region(), currency) {
+        (Some("CH"), "CHF") | (Some("US"), "USD") => Ok(()),
+        _ => Err(ValidationError::new("currency_region")),
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = ClientRegion)]
+struct Payment {
+    #[validate(range(min = 1))]
+    amount: u64,
+    #[validate(custom(function = "local_currency", use_context))]
+    currency: String,
+}
+
+#[post("/payments", data = "<data>")]
+fn pay(data: ValidatedWith<Json<Payment>>) -> Json<Payment> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![pay])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(Regions::new(|ip| match ip {
+            IpAddr::V4(ip) if ip.octets()[0] == 10 => Some("CH".to_string()),
+            IpAddr::V4(ip) if ip.octets()[0] == 20 => Some("US".to_string()),
+            _ => None,
+        }))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn from(a: u8) -> SocketAddr {
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(a, 0, 0, 1)), 8000)
+}
+
+#[test]
+pub fn matching_region() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").remote(from(10)).json(&Payment {
+        amount: 10,
+        currency: "CHF".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn mismatched_region() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").remote(from(20)).json(&Payment {
+        amount: 10,
+        currency: "CHF".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["currency"][0]["code"], "currency_region");
+}
+
+#[test]
+pub fn unknown_region() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/payments").json(&Payment {
+        amount: 10,
+        currency: "USD".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn echo(data: Validated<Json<HelloData>>) -> Validated<Json<HelloData>> {
+    data
+}
+
+#[get("/hello")]
+fn constructed() -> Validated<Json<HelloData>> {
+    Validated(Json(HelloData {
+        name: "Chris".to_string(),
+    }))
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![echo, constructed])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[test]
+pub fn responds_with_inner() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "Chris" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+    assert_eq!(
+        response.into_json::<Value>().unwrap(),
+        json!({ "name": "Chris" })
+    );
+
+    let response = client.get("/hello").dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap()["name"], "Chris");
+}
+
+#[test]
+pub fn invalid_data_still_fails() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = client
+        .post("/hello")
+        .json(&json!({ "name": "C" }))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,147 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Privileged, PrivilegedFields, Validate, ValidatedRole};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Account {
+    #[validate(length(min = 3))]
+    name: String,
+    #[serde(default)]
+    verified: bool,
+}
+
+impl PrivilegedFields for Account {
+    const FIELDS: &'static [&'static str] = &["verified"];
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Comment {
+    #[validate(length(min = 1))]
+    text: String,
+    #[serde(default)]
+    pinned: bool,
+}
+
+impl PrivilegedFields for Comment {
+    const FIELDS: &'static [&'static str] = &["pinned"];
+    const STRIP: bool = true;
+}
+
+struct Role {
+    admin: bool,
+}
+
+impl Privileged for Role {
+    fn is_privileged(&self) -> bool {
+        self.admin
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Role {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+        Outcome::Success(Role {
+            admin: req.headers().get_one("X-Role") == Some("admin"),
+        })
+    }
+}
+
+#[post("/accounts", data = "<data>")]
+fn update_account(data: ValidatedRole<Json<Account>, Role>) -> Json<Account> {
+    Json(data.into_deep_inner())
+}
+
+#[post("/comments", data = "<data>")]
+fn create_comment(data: ValidatedRole<Json<Comment>, Role>) -> Json<Comment> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![update_account, create_comment])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Header, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn admin_sets_privileged_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/accounts")
+        .header(Header::new("X-Role", "admin"))
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","verified":true}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap()["verified"], true);
+}
+
+#[test]
+pub fn user_rejected_on_privileged_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/accounts")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","verified":true}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["verified"][0]["code"], "privileged");
+}
+
+#[test]
+pub fn admin_keeps_stripped_field() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(Header::new("X-Role", "admin"))
+        .header(ContentType::JSON)
+        .body(r#"{"text":"Hello","pinned":true}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap()["pinned"], true);
+}
+
+#[test]
+pub fn user_privileged_field_stripped() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/comments")
+        .header(ContentType::JSON)
+        .body(r#"{"text":"Hello","pinned":true}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap()["pinned"], false);
+}
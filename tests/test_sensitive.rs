@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::{Client, LocalResponse},
+    serde::{
+        json::{json, Json, Value},
+        Deserialize,
+    },
+};
+use rocket_validation::{Sensitive, Validate, ValidatedSensitive};
+
+#[derive(Clone, Debug, Default, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Insurance {
+    company: Option<String>,
+    number: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Patient {
+    #[validate(length(min = 1))]
+    name: String,
+    diagnosis: Option<String>,
+    #[serde(default)]
+    insurance: Insurance,
+}
+
+impl Sensitive for Patient {
+    const SENSITIVE: &'static [&'static str] = &["diagnosis", "insurance.number"];
+}
+
+#[post("/patients", data = "<data>")]
+fn admit(data: ValidatedSensitive<Json<Patient>>) -> Json<Vec<&'static str>> {
+    Json(data.sensitive_fields().iter().copied().collect())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![admit])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+fn admit_with(client: &Client, body: Value) -> LocalResponse<'_> {
+    client
+        .post("/patients")
+        .header(ContentType::JSON)
+        .body(body.to_string())
+        .dispatch()
+}
+
+#[test]
+pub fn sensitive_field_set() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = admit_with(
+        &client,
+        json!({
+            "name": "Chris",
+            "diagnosis": "flu",
+            "insurance": { "company": "Helsana", "number": "756.1234" }
+        }),
+    );
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.into_json::<Value>().unwrap(),
+        json!(["diagnosis", "insurance.number"])
+    );
+}
+
+#[test]
+pub fn absent_sensitive_fields() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = admit_with(
+        &client,
+        json!({ "name": "Chris", "insurance": { "company": "Helsana" } }),
+    );
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap(), json!([]));
+}
+
+#[test]
+pub fn invalid_body() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = admit_with(&client, json!({ "name": "", "diagnosis": "flu" }));
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,118 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    data::{self, Data, FromData},
+    local::blocking::LocalResponse,
+    outcome::Outcome,
+    request::{self, FromRequest, Request},
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Shared, Validate, ValidatedShared};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Order {
+    #[validate(length(min = 3))]
+    item: String,
+    #[validate(range(min = 1, max = 10))]
+    quantity: u32,
+}
+
+///  Second guard reading the order validated by `ValidatedShared`
+struct Audit {
+    item: String,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Audit {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+        match Shared::<Json<Order>>::get(req) {
+            Some(order) => Outcome::Success(Audit {
+                item: order.item.clone(),
+            }),
+            None => Outcome::Error((rocket::http::Status::InternalServerError, ())),
+        }
+    }
+}
+
+///  Data guard validating the order and then running the `Audit` guard on it
+struct AuditedOrder {
+    order: ValidatedShared<Json<Order>>,
+    audit: Audit,
+    same_body: bool,
+}
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for AuditedOrder {
+    type Error = ();
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let order = match ValidatedShared::<Json<Order>>::from_data(req, data).await {
+            Outcome::Success(order) => order,
+            Outcome::Error((status, _)) => return Outcome::Error((status, ())),
+            Outcome::Forward(forward) => return Outcome::Forward(forward),
+        };
+
+        let shared = Shared::<Json<Order>>::get(req).unwrap();
+
+        match req.guard::<Audit>().await {
+            Outcome::Success(audit) => Outcome::Success(AuditedOrder {
+                same_body: Arc::ptr_eq(&order.0, &shared.0),
+                order,
+                audit,
+            }),
+            _ => Outcome::Error((rocket::http::Status::InternalServerError, ())),
+        }
+    }
+}
+
+#[post("/orders", data = "<data>")]
+fn create_order(data: AuditedOrder) -> String {
+    format!(
+        "{} x{} audited as {} (shared: {})",
+        data.order.item, data.order.quantity, data.audit.item, data.same_body
+    )
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![create_order])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn guards_share_one_body() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/orders").json(&Order {
+        item: "Rocket".to_string(),
+        quantity: 2,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(
+        response.into_string().unwrap(),
+        "Rocket x2 audited as Rocket (shared: true)"
+    );
+}
+
+#[test]
+pub fn invalid_order_is_not_shared() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/orders").json(&Order {
+        item: "Rocket".to_string(),
+        quantity: 20,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "schemars")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedStrict};
+use schemars::JsonSchema;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[validate(length(min = 4))]
+    zip: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(nested)]
+    addresses: Vec<Address>,
+    labels: Option<HashMap<String, String>>,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: ValidatedStrict<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn declared_properties_only() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","addresses":[{"zip":"8000"}],"labels":{"any":"key"}}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn extra_property_rejected() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(ContentType::JSON)
+        .body(r#"{"name":"Chris","admin":true,"addresses":[{"zip":"8000","city":"Zurich"}]}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let errors = body["errors"]["__all__"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+
+    assert_eq!(errors[0]["code"], "additional_property");
+    assert_eq!(errors[0]["params"]["property"], "city");
+    assert_eq!(errors[0]["params"]["pointer"], "/addresses/0/city");
+    assert_eq!(errors[1]["code"], "additional_property");
+    assert_eq!(errors[1]["params"]["property"], "admin");
+    assert_eq!(errors[1]["params"]["pointer"], "/admin");
+}
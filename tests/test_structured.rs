@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 0, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![hello]).register(
+        "/",
+        catchers![rocket_validation::validation_catcher_structured],
+    )
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn errors_without_message() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+        age: 101,
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let keys: Vec<&String> = body.as_object().unwrap().keys().collect();
+    assert_eq!(keys, ["code", "errors"]);
+    assert_eq!(body["code"], 422);
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+    assert_eq!(body["errors"]["age"][0]["code"], "range");
+}
@@ -0,0 +1,117 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Header,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Tenant, Tenants, Validate, ValidatedWith};
+use validator::ValidationError;
+
+struct Limits {
+    max_items: usize,
+}
+
+fn within_limit(items: &[String], limits: &Tenant<Limits>) -> Result<(), ValidationError> {
+    if items.len() > limits.max_items {
+        let mut error = ValidationError::new("max_items");
+        error.add_param("max".into(), &limits.max_items);
+        return Err(error);
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = "Tenant<Limits>")]
+struct Order {
+    #[validate(custom(function = "within_limit", use_context))]
+    items: Vec<String>,
+}
+
+#[post("/orders", data = "<data>")]
+fn order(data: ValidatedWith<Json<Order>>) -> Json<Order> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket_with(tenants: Tenants<Limits>) -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![order])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(tenants)
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket_with(
+        Tenants::new("X-Tenant-Id")
+            .tenant("acme", Limits { max_items: 3 })
+            .tenant("initech", Limits { max_items: 1 }),
+    )
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn order_as<'c>(
+    client: &'c Client,
+    tenant: Option<&'static str>,
+    items: usize,
+) -> LocalResponse<'c> {
+    let mut req = client.post("/orders").json(&Order {
+        items: vec!["chair".to_string(); items],
+    });
+
+    if let Some(tenant) = tenant {
+        req = req.header(Header::new("X-Tenant-Id", tenant));
+    }
+
+    req.dispatch()
+}
+
+#[test]
+pub fn tenants_with_different_limits() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(order_as(&client, Some("acme"), 2).status(), Status::Ok);
+
+    let response = order_as(&client, Some("initech"), 2);
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["items"][0]["code"], "max_items");
+    assert_eq!(body["errors"]["items"][0]["params"]["max"], 1);
+}
+
+#[test]
+pub fn unknown_tenant_without_fallback() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    assert_eq!(
+        order_as(&client, Some("globex"), 1).status(),
+        Status::InternalServerError
+    );
+    assert_eq!(
+        order_as(&client, None, 1).status(),
+        Status::InternalServerError
+    );
+}
+
+#[test]
+pub fn unknown_tenant_with_fallback() {
+    let client = Client::tracked(rocket_with(
+        Tenants::new("X-Tenant-Id")
+            .tenant("acme", Limits { max_items: 3 })
+            .fallback(Limits { max_items: 2 }),
+    ))
+    .unwrap();
+
+    assert_eq!(order_as(&client, Some("globex"), 2).status(), Status::Ok);
+    assert_eq!(
+        order_as(&client, None, 3).status(),
+        Status::UnprocessableEntity
+    );
+}
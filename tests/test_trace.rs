@@ -0,0 +1,124 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{TraceIds, Validate, Validated};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(TraceIds::default())
+}
+
+static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl log::Log for Capture {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "rocket_validation"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            EVENTS.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn capture_logs() {
+    // set before rocket, so rocket keeps it
+    let _ = log::set_logger(&Capture);
+    log::set_max_level(log::LevelFilter::Trace);
+}
+
+fn logged(trace_id: &str) -> bool {
+    EVENTS
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|event| event.contains(&format!("trace_id={trace_id} ")))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn generated_trace_id_matches_log() {
+    capture_logs();
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let trace_id = body["trace_id"].as_str().unwrap();
+    assert_eq!(trace_id.len(), 32);
+    assert!(logged(trace_id));
+}
+
+#[test]
+pub fn provided_trace_id_is_kept() {
+    capture_logs();
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(rocket::http::Header::new("X-Trace-Id", "abc-123"))
+        .json(&HelloData {
+            name: "C".to_string(),
+        });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["trace_id"], "abc-123");
+    assert!(logged("abc-123"));
+}
+
+#[test]
+pub fn valid_request_logs_nothing() {
+    capture_logs();
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(rocket::http::Header::new("X-Trace-Id", "valid-1"))
+        .json(&HelloData {
+            name: "Chris".to_string(),
+        });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert!(!logged("valid-1"));
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "tracing")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Status,
+    local::blocking::Client,
+    serde::{json::Json, Deserialize, Serialize},
+};
+use rocket_validation::{Validate, Validated};
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Level, Metadata, Subscriber,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    spans: Vec<(String, String)>,
+    events: Vec<(Level, String, Option<u64>)>,
+}
+
+#[derive(Default)]
+struct Fields {
+    message: String,
+    r#type: String,
+    errors: Option<u64>,
+}
+
+impl Visit for Fields {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "errors" {
+            self.errors = Some(value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "type" {
+            self.r#type = value.to_owned();
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+    recorded: Arc<Mutex<Recorded>>,
+    ids: Arc<AtomicU64>,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "rocket_validation"
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+
+        self.recorded
+            .lock()
+            .unwrap()
+            .spans
+            .push((span.metadata().name().to_owned(), fields.r#type));
+
+        Id::from_u64(self.ids.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+
+        self.recorded.lock().unwrap().events.push((
+            *event.metadata().level(),
+            fields.message,
+            fields.errors,
+        ));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn dispatch(body: &str) -> (Status, Recorded) {
+    let recorder = Recorder::default();
+    let recorded = recorder.recorded.clone();
+
+    let status = tracing::subscriber::with_default(recorder, || {
+        let client = Client::tracked(rocket()).unwrap();
+        client
+            .post("/hello")
+            .header(rocket::http::ContentType::JSON)
+            .body(body)
+            .dispatch()
+            .status()
+    });
+
+    let recorded = std::mem::take(&mut *recorded.lock().unwrap());
+    (status, recorded)
+}
+
+#[test]
+pub fn span_carries_type_name() {
+    let (status, recorded) = dispatch(r#"{"name": "Alice", "age": 30}"#);
+    assert_eq!(status, Status::Ok);
+
+    assert_eq!(recorded.spans.len(), 1);
+    assert_eq!(recorded.spans[0].0, "rocket_validation::validate");
+    assert!(recorded.spans[0].1.ends_with("HelloData"));
+
+    assert!(recorded
+        .events
+        .iter()
+        .any(|(level, message, _)| *level == Level::DEBUG && message == "validation succeeded"));
+    assert!(recorded
+        .events
+        .iter()
+        .all(|(level, _, _)| *level != Level::WARN));
+}
+
+#[test]
+pub fn failure_warns_with_error_count() {
+    let (status, recorded) = dispatch(r#"{"name": "Al", "age": 0}"#);
+    assert_eq!(status, Status::UnprocessableEntity);
+
+    let warnings: Vec<_> = recorded
+        .events
+        .iter()
+        .filter(|(level, _, _)| *level == Level::WARN)
+        .collect();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].1, "validation failed");
+    assert_eq!(warnings[0].2, Some(2));
+}
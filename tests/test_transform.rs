@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Transform, Transforms, Validate, ValidatedTransform};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct SignupData {
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 3, max = 16))]
+    display_name: String,
+}
+
+fn strip_dots(value: &str) -> String {
+    value.replace('.', "")
+}
+
+impl Transforms for SignupData {
+    fn transforms() -> Vec<(&'static str, Vec<Transform>)> {
+        vec![
+            ("email", vec![Transform::Trim, Transform::Lowercase]),
+            (
+                "display_name",
+                vec![
+                    Transform::Custom(strip_dots),
+                    Transform::Trim,
+                    Transform::CollapseWhitespace,
+                ],
+            ),
+        ]
+    }
+}
+
+#[post("/signup", data = "<data>")]
+fn signup(data: ValidatedTransform<Json<SignupData>>) -> Json<SignupData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![signup])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn applies_pipeline_in_order() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/signup").json(&SignupData {
+        email: "  Chris@Example.COM ".to_string(),
+        display_name: " . Chris   the .  Dev ".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["email"], "chris@example.com");
+    assert_eq!(body["display_name"], "Chris the Dev");
+}
+
+#[test]
+pub fn validates_transformed_value() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/signup").json(&SignupData {
+        email: "chris@example.com".to_string(),
+        display_name: "  ...  a . ".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["display_name"][0]["code"], "length");
+}
@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{transient, Validate, Validated};
+use validator::ValidationError;
+
+fn check_coupon(coupon: &str) -> Result<(), ValidationError> {
+    match coupon {
+        // the coupon service is down
+        "UNAVAILABLE" => Err(transient(30)),
+        "INVALID" => Err(ValidationError::new("coupon")),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Order<'a> {
+    #[validate(custom(function = "check_coupon"))]
+    coupon: &'a str,
+}
+
+#[post("/orders", data = "<data>")]
+fn create_order(data: Validated<Json<Order>>) -> Json<Order> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![create_order]).register(
+        "/",
+        catchers![
+            rocket_validation::validation_catcher,
+            rocket_validation::validation_catcher_unavailable
+        ],
+    )
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn transient_failure() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/orders").json(&Order {
+        coupon: "UNAVAILABLE",
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::ServiceUnavailable);
+    assert_eq!(response.headers().get_one("Retry-After"), Some("30"));
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["coupon"][0]["code"], "transient");
+}
+
+#[test]
+pub fn permanent_failure() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/orders").json(&Order { coupon: "INVALID" });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.headers().get_one("Retry-After"), None);
+}
+
+#[test]
+pub fn valid_coupon() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/orders").json(&Order { coupon: "WELCOME" });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Transitions, Validate, ValidatedWith};
+use validator::ValidationError;
+
+fn transition(update: &StatusUpdate, transitions: &Transitions) -> Result<(), ValidationError> {
+    transitions.check(&update.order, &update.status)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = Transitions, schema(function = "transition", use_context))]
+struct StatusUpdate {
+    order: String,
+    status: String,
+}
+
+#[put("/orders/status", data = "<data>")]
+fn update(data: ValidatedWith<Json<StatusUpdate>>) -> Json<StatusUpdate> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![update])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(
+            Transitions::new(|order: &str| match order {
+                "1" => Some("pending".to_string()),
+                "2" => Some("shipped".to_string()),
+                _ => None,
+            })
+            .allow("pending", ["paid", "cancelled"])
+            .allow("paid", ["shipped"]),
+        )
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn status_update(order: &str, status: &str) -> StatusUpdate {
+    StatusUpdate {
+        order: order.to_string(),
+        status: status.to_string(),
+    }
+}
+
+#[test]
+pub fn valid_transition() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .put("/orders/status")
+        .json(&status_update("1", "paid"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn invalid_transition() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .put("/orders/status")
+        .json(&status_update("1", "shipped"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["__all__"][0];
+    assert_eq!(error["code"], "invalid_transition");
+    assert_eq!(error["params"]["from"], "pending");
+    assert_eq!(error["params"]["to"], "shipped");
+    assert_eq!(error["params"]["allowed"], json!(["cancelled", "paid"]));
+}
+
+#[test]
+pub fn final_state_allows_no_transition() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .put("/orders/status")
+        .json(&status_update("2", "pending"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["params"]["allowed"], json!([]));
+}
+
+#[test]
+pub fn unknown_resource() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .put("/orders/status")
+        .json(&status_update("3", "paid"));
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "unknown_resource");
+}
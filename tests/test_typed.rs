@@ -0,0 +1,76 @@
+#![cfg(feature = "schemars")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedTyped};
+use schemars::JsonSchema;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(crate = "rocket::serde")]
+struct Address {
+    #[validate(length(min = 4))]
+    zip: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate, JsonSchema)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(range(min = 1, max = 100))]
+    age: u8,
+    #[validate(range(min = 0.0))]
+    score: Option<f64>,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: ValidatedTyped<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn field_types_in_errors() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "C".to_string(),
+        age: 0,
+        score: Some(-1.0),
+        address: Address {
+            zip: "1".to_string(),
+        },
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let errors = &body["errors"];
+    assert_eq!(errors["name"][0]["params"]["field_type"], "string");
+    assert_eq!(errors["age"][0]["params"]["field_type"], "integer");
+    assert_eq!(errors["score"][0]["params"]["field_type"], "number");
+    assert_eq!(
+        errors["address"]["zip"][0]["params"]["field_type"],
+        "string"
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "unicode")]
+
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{NormalizeNfc, Validate, Validated};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(equal = 4))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn validated_hello(data: Validated<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_hello])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(NormalizeNfc)
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn decomposed_string_is_normalized() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/hello").json(&HelloData {
+        name: "Rene\u{301}".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap()["name"], "Ren\u{e9}");
+}
+
+#[test]
+pub fn escaped_combining_mark_is_normalized() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(rocket::http::ContentType::JSON)
+        .body(r#"{"name":"Rene\u0301"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<Value>().unwrap()["name"], "Ren\u{e9}");
+}
+
+#[test]
+pub fn colliding_keys_are_rejected() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(rocket::http::ContentType::JSON)
+        .body(r#"{"name":"Rene","caf\u00e9":1,"cafe\u0301":2}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["__all__"][0]["code"], "nfc_key_collision");
+    assert_eq!(
+        body["errors"]["__all__"][0]["params"]["pointer"],
+        "/caf\u{e9}"
+    );
+}
+
+#[test]
+pub fn normalized_body_is_left_as_sent() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/hello")
+        .header(rocket::http::ContentType::JSON)
+        .body(r#"{"name":"Rene","name":"Ren\u00e9"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    // the duplicated key reaches the deserializer instead of being collapsed by the normalization
+    assert_eq!(response.status(), Status::BadRequest);
+}
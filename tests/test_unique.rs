@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, ValidatedUnique};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Recipient {
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[post("/recipients", data = "<data>")]
+fn recipients(data: ValidatedUnique<Json<Vec<Recipient>>>) -> Json<Vec<Recipient>> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![recipients])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn unique_elements() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/recipients")
+        .header(ContentType::JSON)
+        .body(r#"[{"email":"a@example.com","name":"A"},{"email":"b@example.com","name":"B"}]"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn duplicate_elements() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/recipients").header(ContentType::JSON).body(
+        r#"[
+            {"email":"a@example.com","name":"A"},
+            {"email":"b@example.com","name":"B"},
+            {"name":"A","email":"a@example.com"},
+            {"email":"not-an-email","name":"C"}
+        ]"#,
+    );
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let items = body["errors"]["__all__"].as_object().unwrap();
+    assert_eq!(items.len(), 2);
+
+    assert_eq!(items["2"]["__all__"][0]["code"], "duplicate");
+    assert_eq!(items["2"]["__all__"][0]["params"]["first"], 0);
+    assert_eq!(items["3"]["email"][0]["code"], "email");
+}
@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{Validate, Validated, ValueRule, ValueRules};
+use validator::ValidationError;
+
+fn check_metadata(metadata: &Value) -> Result<(), ValidationError> {
+    ValueRules::new()
+        .at("", ValueRule::Object)
+        .at("", ValueRule::MaxKeys(20))
+        .at("/labels", ValueRule::Array)
+        .at("/labels", ValueRule::MaxItems(3))
+        .check(metadata)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Upload {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(custom(function = "check_metadata"))]
+    metadata: Value,
+}
+
+#[post("/uploads", data = "<data>")]
+fn upload(data: Validated<Json<Upload>>) -> Json<Upload> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![upload])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn metadata_within_rules() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/uploads").json(&Upload {
+        name: "report.pdf".to_string(),
+        metadata: json!({"owner": "chris", "labels": ["q1", "finance"]}),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn metadata_exceeding_key_limit() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let metadata: serde_json::Map<String, Value> =
+        (0..21).map(|i| (format!("key{i}"), json!(i))).collect();
+
+    let req = client.post("/uploads").json(&Upload {
+        name: "report.pdf".to_string(),
+        metadata: Value::Object(metadata),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    let error = &body["errors"]["metadata"][0];
+    assert_eq!(error["code"], "max_keys");
+    assert_eq!(error["params"]["path"], "");
+    assert_eq!(error["params"]["max"], 20);
+    assert_eq!(error["params"]["actual"], 21);
+}
+
+#[test]
+pub fn nested_path_rule() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/uploads").json(&Upload {
+        name: "report.pdf".to_string(),
+        metadata: json!({"labels": ["a", "b", "c", "d"]}),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["metadata"][0]["code"], "max_items");
+    assert_eq!(body["errors"]["metadata"][0]["params"]["path"], "/labels");
+}
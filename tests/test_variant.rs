@@ -0,0 +1,135 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{json::Value, Deserialize},
+};
+use rocket_validation::{Validate, ValidatedVariant, Variants};
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Cat {
+    #[validate(range(min = 1, max = 9))]
+    lives: u8,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Dog {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/pets", data = "<data>")]
+fn validated_pet(data: ValidatedVariant) -> String {
+    match data.tag() {
+        "cat" => format!(
+            "cat with {} lives",
+            data.downcast_ref::<Cat>().unwrap().lives
+        ),
+        _ => format!("dog named {}", data.downcast::<Dog>().unwrap().name),
+    }
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![validated_pet])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(
+            Variants::new("type")
+                .register::<Cat>("cat")
+                .register::<Dog>("dog"),
+        )
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn valid_cat() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/pets")
+        .header(ContentType::JSON)
+        .body(r#"{"type":"cat","lives":9}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "cat with 9 lives");
+}
+
+#[test]
+pub fn valid_dog() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/pets")
+        .header(ContentType::JSON)
+        .body(r#"{"type":"dog","name":"Rex"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "dog named Rex");
+}
+
+#[test]
+pub fn invalid_cat() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/pets")
+        .header(ContentType::JSON)
+        .body(r#"{"type":"cat","lives":10}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["lives"][0]["code"], "range");
+}
+
+#[test]
+pub fn invalid_dog() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/pets")
+        .header(ContentType::JSON)
+        .body(r#"{"type":"dog","name":"R"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["name"][0]["code"], "length");
+}
+
+#[test]
+pub fn unknown_discriminator() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/pets")
+        .header(ContentType::JSON)
+        .body(r#"{"type":"fish","name":"Nemo"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["type"][0]["code"], "unknown_variant");
+    assert_eq!(
+        body["errors"]["type"][0]["params"]["expected"],
+        rocket::serde::json::json!(["cat", "dog"])
+    );
+}
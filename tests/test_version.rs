@@ -0,0 +1,117 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    http::Header,
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{MinClientVersion, Validate, ValidatedVersion};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct HelloData {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+#[post("/hello", data = "<data>")]
+fn hello(data: ValidatedVersion<Json<HelloData>>) -> Json<HelloData> {
+    Json(data.into_deep_inner())
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![hello])
+        .register(
+            "/",
+            catchers![
+                rocket_validation::validation_catcher,
+                rocket_validation::validation_catcher_upgrade_required
+            ],
+        )
+        .manage(MinClientVersion::new("2.4"))
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+fn hello_from<'c>(
+    client: &'c Client,
+    version: Option<&'static str>,
+    name: &str,
+) -> LocalResponse<'c> {
+    let mut req = client.post("/hello").json(&HelloData {
+        name: name.to_string(),
+    });
+
+    if let Some(version) = version {
+        req = req.header(Header::new("X-Client-Version", version));
+    }
+
+    req.dispatch()
+}
+
+#[test]
+pub fn below_minimum() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = hello_from(&client, Some("2.3.9"), "Chris");
+
+    assert_eq!(response.status(), Status::UpgradeRequired);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["code"], 426);
+    let error = &body["errors"]["__all__"][0];
+    assert_eq!(error["code"], "upgrade_required");
+    assert_eq!(error["params"]["minimum"], "2.4");
+    assert_eq!(error["params"]["version"], "2.3.9");
+}
+
+#[test]
+pub fn at_minimum() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = hello_from(&client, Some("2.4.0"), "Chris");
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn above_minimum() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = hello_from(&client, Some("v2.10.1-beta"), "Chris");
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn above_minimum_still_validated() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = hello_from(&client, Some("3.0"), "C");
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+pub fn missing_version() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = hello_from(&client, None, "Chris");
+
+    assert_eq!(response.status(), Status::UpgradeRequired);
+}
+
+#[test]
+pub fn invalid_version() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let response = hello_from(&client, Some("latest"), "Chris");
+
+    assert_eq!(response.status(), Status::BadRequest);
+}
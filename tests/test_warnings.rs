@@ -0,0 +1,121 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{
+    Validate, ValidateWithWarnings, ValidatedWithWarnings, ValidationErrors, Warned,
+};
+use validator::ValidationError;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Signup {
+    #[validate(email)]
+    email: String,
+    nickname: Option<String>,
+    password: String,
+}
+
+impl ValidateWithWarnings for Signup {
+    fn validate_with_warnings(&self) -> Result<Option<ValidationErrors>, ValidationErrors> {
+        self.validate()?;
+
+        let mut warnings = ValidationErrors::new();
+        if self.nickname.is_none() {
+            warnings.add("nickname", ValidationError::new("recommended"));
+        }
+        if self.password.len() < 12 {
+            warnings.add(
+                "password",
+                ValidationError::new("weak").with_message("password is weak".into()),
+            );
+        }
+
+        Ok(Some(warnings))
+    }
+}
+
+#[post("/signup", data = "<data>")]
+fn signup(data: ValidatedWithWarnings<Json<Signup>>) -> Warned<Json<usize>> {
+    let count = data
+        .warnings()
+        .map_or(0, |warnings| warnings.errors().len());
+    Warned(Json(count))
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .mount("/", routes![signup])
+        .register("/", catchers![rocket_validation::validation_catcher])
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+#[test]
+pub fn without_warnings() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client.post("/signup").header(ContentType::JSON).body(
+        r#"{"email":"alice@example.com","nickname":"alice","password":"correct horse battery"}"#,
+    );
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert!(response.headers().get_one("Warning").is_none());
+    assert_eq!(response.into_json::<usize>(), Some(0));
+}
+
+#[test]
+pub fn accepted_with_warnings() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/signup")
+        .header(ContentType::JSON)
+        .body(r#"{"email":"alice@example.com","password":"hunter2"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    let mut warnings: Vec<&str> = response.headers().get("Warning").collect();
+    warnings.sort_unstable();
+
+    assert_eq!(
+        warnings,
+        [
+            "299 - \"nickname: recommended\"",
+            "299 - \"password: password is weak\""
+        ]
+    );
+    assert_eq!(response.into_json::<usize>(), Some(2));
+}
+
+#[test]
+pub fn errors_still_reject() {
+    let client = Client::tracked(rocket()).unwrap();
+
+    let req = client
+        .post("/signup")
+        .header(ContentType::JSON)
+        .body(r#"{"email":"alice","password":"hunter2"}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["email"][0]["code"], "email");
+}
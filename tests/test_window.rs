@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{Json, Value},
+        Deserialize, Serialize,
+    },
+};
+use rocket_validation::{TimeWindow, Validate, ValidatedWindow};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+struct Order {
+    #[validate(length(min = 1))]
+    symbol: String,
+    #[validate(range(min = 1))]
+    quantity: u32,
+}
+
+#[post("/orders", data = "<data>")]
+fn place(data: ValidatedWindow<Json<Order>>) -> Json<Order> {
+    Json(data.into_deep_inner())
+}
+
+fn at(hours: u64, minutes: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(hours * 3600 + minutes * 60)
+}
+
+fn rocket(window: TimeWindow) -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![place])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(window)
+}
+
+fn trading_hours(hours: u64, minutes: u64) -> TimeWindow {
+    TimeWindow::new((9, 30), (16, 0)).clock(move || at(hours, minutes))
+}
+
+use rocket::{
+    http::{ContentType, Status},
+    local::blocking::Client,
+};
+
+const ORDER: &str = r#"{"symbol":"ACME","quantity":10}"#;
+
+#[test]
+pub fn inside_window() {
+    let client = Client::tracked(rocket(trading_hours(10, 0))).unwrap();
+
+    let req = client.post("/orders").header(ContentType::JSON).body(ORDER);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn outside_window() {
+    let client = Client::tracked(rocket(trading_hours(16, 0))).unwrap();
+
+    let req = client.post("/orders").header(ContentType::JSON).body(ORDER);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+    let error = &body["errors"]["__all__"][0];
+
+    assert_eq!(error["code"], "outside_window");
+    assert_eq!(error["params"]["open"], "09:30");
+    assert_eq!(error["params"]["close"], "16:00");
+}
+
+#[test]
+pub fn invalid_body_inside_window() {
+    let client = Client::tracked(rocket(trading_hours(10, 0))).unwrap();
+
+    let req = client
+        .post("/orders")
+        .header(ContentType::JSON)
+        .body(r#"{"symbol":"ACME","quantity":0}"#);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body: Value = response.into_json().unwrap();
+
+    assert_eq!(body["errors"]["quantity"][0]["code"], "range");
+    assert!(body["errors"].get("__all__").is_none());
+}
+
+#[test]
+pub fn utc_offset() {
+    let window = TimeWindow::new((9, 30), (16, 0))
+        .utc_offset(-5 * 60)
+        .clock(|| at(15, 0));
+    let client = Client::tracked(rocket(window)).unwrap();
+
+    let req = client.post("/orders").header(ContentType::JSON).body(ORDER);
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn window_spanning_midnight() {
+    let night = |hours| TimeWindow::new((22, 0), (6, 0)).clock(move || at(hours, 0));
+
+    assert!(night(23).is_open());
+    assert!(night(2).is_open());
+    assert!(!night(12).is_open());
+}
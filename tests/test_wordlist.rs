@@ -0,0 +1,126 @@
+#[macro_use]
+extern crate rocket;
+
+use rocket::{
+    local::blocking::LocalResponse,
+    serde::{
+        json::{json, Json, Value},
+        Deserialize, Serialize,
+    },
+    Build, Rocket,
+};
+use rocket_validation::{Validate, ValidatedWith, Wordlist};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[serde(crate = "rocket::serde")]
+#[validate(context = Wordlist)]
+struct Comment {
+    #[validate(
+        length(min = 1),
+        custom(function = "rocket_validation::filtered", use_context)
+    )]
+    text: String,
+}
+
+#[post("/comments", data = "<data>")]
+fn comment(data: ValidatedWith<Json<Comment>>) -> Json<Comment> {
+    Json(data.into_deep_inner())
+}
+
+fn rocket(wordlist: Wordlist) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![comment])
+        .register("/", catchers![rocket_validation::validation_catcher])
+        .manage(wordlist)
+}
+
+use rocket::{http::Status, local::blocking::Client};
+
+#[test]
+pub fn clean_content() {
+    let client = Client::tracked(rocket(Wordlist::blocklist(["darn", "heck"]))).unwrap();
+
+    let req = client.post("/comments").json(&Comment {
+        text: "What a lovely day".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+pub fn filtered_content_reveals_words() {
+    let client = Client::tracked(rocket(Wordlist::blocklist(["darn", "heck"]))).unwrap();
+
+    let req = client.post("/comments").json(&Comment {
+        text: "Darn it, what the heck!".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["text"][0]["code"], "blocked_words");
+    assert_eq!(
+        body["errors"]["text"][0]["params"]["words"],
+        json!(["darn", "heck"])
+    );
+}
+
+#[test]
+pub fn filtered_content_concealed() {
+    let wordlist = Wordlist::blocklist(["darn", "heck"]).reveal(false);
+    let client = Client::tracked(rocket(wordlist)).unwrap();
+
+    let req = client.post("/comments").json(&Comment {
+        text: "Darn it".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["text"][0]["code"], "blocked_words");
+    assert!(body["errors"]["text"][0]["params"].get("words").is_none());
+}
+
+#[test]
+pub fn allowlist() {
+    let client = Client::tracked(rocket(Wordlist::allowlist(["yes", "no", "maybe"]))).unwrap();
+
+    let req = client.post("/comments").json(&Comment {
+        text: "yes, no, perhaps".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let body = response.into_json::<Value>().unwrap();
+    assert_eq!(body["errors"]["text"][0]["code"], "unlisted_words");
+    assert_eq!(
+        body["errors"]["text"][0]["params"]["words"],
+        json!(["perhaps"])
+    );
+}
+
+#[test]
+pub fn missing_context() {
+    let client = Client::tracked(
+        rocket::build()
+            .mount("/", routes![comment])
+            .register("/", catchers![rocket_validation::validation_catcher]),
+    )
+    .unwrap();
+
+    let req = client.post("/comments").json(&Comment {
+        text: "Hello".to_string(),
+    });
+
+    let response: LocalResponse = req.dispatch();
+
+    assert_eq!(response.status(), Status::InternalServerError);
+}